@@ -2941,7 +2941,123 @@ define_server_key_bench_unary_fn!(
     display_name: carry_propagation
 );
 
-criterion_group!(misc, full_propagate, full_propagate_parallelized);
+/// Compares the schoolbook and the Karatsuba multiplications on large ciphertexts
+fn karatsuba_mul(c: &mut Criterion) {
+    let bench_name = "integer::karatsuba_mul";
+    let mut bench_group = c.benchmark_group(bench_name);
+    bench_group
+        .sample_size(10)
+        .measurement_time(std::time::Duration::from_secs(60));
+    let mut rng = rand::thread_rng();
+
+    // Threshold low enough for the operands to be split
+    const KARATSUBA_THRESHOLD: usize = 16;
+
+    for (param, num_block, bit_size) in ParamsAndNumBlocksIter::default() {
+        if num_block != 32 && num_block != 64 {
+            continue;
+        }
+        let param_name = param.name();
+
+        for (algorithm, threshold) in [
+            ("schoolbook", usize::MAX),
+            ("karatsuba", KARATSUBA_THRESHOLD),
+        ] {
+            let bench_id = format!("{bench_name}::{algorithm}::{param_name}::{bit_size}_bits");
+            bench_group.bench_function(&bench_id, |b| {
+                let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+                let encrypt_two_values = || {
+                    let ct_0 = cks.encrypt_radix(gen_random_u256(&mut rng), num_block);
+                    let ct_1 = cks.encrypt_radix(gen_random_u256(&mut rng), num_block);
+                    (ct_0, ct_1)
+                };
+
+                b.iter_batched(
+                    encrypt_two_values,
+                    |(ct_0, ct_1)| {
+                        sks.unchecked_karatsuba_mul_parallelized(&ct_0, &ct_1, threshold);
+                    },
+                    criterion::BatchSize::SmallInput,
+                )
+            });
+
+            write_to_json::<u64, _>(
+                &bench_id,
+                param,
+                param.name(),
+                "mul",
+                &OperatorType::Atomic,
+                bit_size as u32,
+                vec![param.message_modulus().0.ilog2(); num_block],
+            );
+        }
+    }
+
+    bench_group.finish()
+}
+
+fn carry_propagation_64_blocks(c: &mut Criterion) {
+    let bench_name = "integer::carry_propagation";
+    let mut bench_group = c.benchmark_group(bench_name);
+    bench_group
+        .sample_size(10)
+        .measurement_time(std::time::Duration::from_secs(60));
+    let mut rng = rand::thread_rng();
+
+    for (param, num_block, bit_size) in ParamsAndNumBlocksIter::default() {
+        if num_block != 64 {
+            continue;
+        }
+        let param_name = param.name();
+
+        for algorithm in ["sequential", "parallel"] {
+            let bench_id = format!("{bench_name}::{algorithm}::{param_name}::{bit_size}_bits");
+            bench_group.bench_function(&bench_id, |b| {
+                let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+                // Every block may hold a carry
+                let encrypt_dirty_value = || {
+                    let ct_0 = cks.encrypt_radix(gen_random_u256(&mut rng), num_block);
+                    let ct_1 = cks.encrypt_radix(gen_random_u256(&mut rng), num_block);
+                    sks.unchecked_add(&ct_0, &ct_1)
+                };
+
+                b.iter_batched(
+                    encrypt_dirty_value,
+                    |mut ct| {
+                        if algorithm == "sequential" {
+                            sks.full_propagate(&mut ct);
+                        } else {
+                            sks.full_propagate_parallelized(&mut ct);
+                        }
+                    },
+                    criterion::BatchSize::SmallInput,
+                )
+            });
+
+            write_to_json::<u64, _>(
+                &bench_id,
+                param,
+                param.name(),
+                "carry_propagation",
+                &OperatorType::Atomic,
+                bit_size as u32,
+                vec![param.message_modulus().0.ilog2(); num_block],
+            );
+        }
+    }
+
+    bench_group.finish()
+}
+
+criterion_group!(
+    misc,
+    full_propagate,
+    full_propagate_parallelized,
+    karatsuba_mul,
+    carry_propagation_64_blocks
+);
 
 criterion_group!(oprf, oprf::unsigned_oprf);
 
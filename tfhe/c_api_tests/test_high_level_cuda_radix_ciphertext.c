@@ -0,0 +1,115 @@
+#if defined(WITH_FEATURE_GPU)
+#include "tfhe.h"
+
+#include <assert.h>
+#include <inttypes.h>
+#include <stdint.h>
+#include <stdio.h>
+
+int uint64_unchecked_add_on_gpu(const ClientKey *client_key, const CudaServerKey *server_key,
+                                const CudaStreams *streams) {
+  int ok;
+  FheUint64 *lhs = NULL;
+  FheUint64 *rhs = NULL;
+  FheUint64 *result = NULL;
+  FheUint8 *wrong_type_result = NULL;
+  CudaRadixCiphertext *d_lhs = NULL;
+  CudaRadixCiphertext *d_rhs = NULL;
+  CudaRadixCiphertext *d_result = NULL;
+
+  uint64_t lhs_clear = 123456789;
+  uint64_t rhs_clear = 987654321;
+
+  ok = fhe_uint64_try_encrypt_with_client_key_u64(lhs_clear, client_key, &lhs);
+  assert(ok == 0);
+
+  ok = fhe_uint64_try_encrypt_with_client_key_u64(rhs_clear, client_key, &rhs);
+  assert(ok == 0);
+
+  ok = fhe_uint64_upload_to_gpu(lhs, streams, &d_lhs);
+  assert(ok == 0);
+
+  ok = fhe_uint64_upload_to_gpu(rhs, streams, &d_rhs);
+  assert(ok == 0);
+
+  ok = cuda_radix_ciphertext_unchecked_add(d_lhs, d_rhs, server_key, streams, &d_result);
+  assert(ok == 0);
+
+  size_t num_blocks = 0;
+  ok = cuda_radix_ciphertext_num_blocks(d_result, &num_blocks);
+  assert(ok == 0);
+  assert(num_blocks == 32);
+
+  ok = cuda_radix_ciphertext_download_to_fhe_uint64(d_result, streams, &result);
+  assert(ok == 0);
+
+  uint64_t clear;
+  ok = fhe_uint64_decrypt(result, client_key, &clear);
+  assert(ok == 0);
+  assert(clear == lhs_clear + rhs_clear);
+
+  // Errors are reported, not panics crossing the FFI boundary
+  ok = cuda_radix_ciphertext_download_to_fhe_uint8(d_result, streams, &wrong_type_result);
+  assert(ok != 0);
+  assert(wrong_type_result == NULL);
+
+  fhe_uint64_destroy(lhs);
+  fhe_uint64_destroy(rhs);
+  fhe_uint64_destroy(result);
+  cuda_radix_ciphertext_destroy(d_lhs);
+  cuda_radix_ciphertext_destroy(d_rhs);
+  cuda_radix_ciphertext_destroy(d_result);
+  return 0;
+}
+
+int main(void) {
+  int ok = 0;
+  {
+    ConfigBuilder *builder;
+    Config *config;
+
+    ok = config_builder_default(&builder);
+    assert(ok == 0);
+    ok = config_builder_build(builder, &config);
+    assert(ok == 0);
+
+    ClientKey *client_key = NULL;
+    CompressedServerKey *compressed_sks = NULL;
+    CudaServerKey *cuda_server_key = NULL;
+    CudaStreams *streams = NULL;
+
+    ok = client_key_generate(config, &client_key);
+    assert(ok == 0);
+
+    ok = compressed_server_key_new(client_key, &compressed_sks);
+    assert(ok == 0);
+
+    ok = compressed_server_key_decompress_to_gpu(compressed_sks, &cuda_server_key);
+    assert(ok == 0);
+
+    ok = cuda_streams_new(0, &streams);
+    assert(ok == 0);
+
+    ok = uint64_unchecked_add_on_gpu(client_key, cuda_server_key, streams);
+    assert(ok == 0);
+
+    ok = cuda_streams_synchronize(streams);
+    assert(ok == 0);
+
+    client_key_destroy(client_key);
+    compressed_server_key_destroy(compressed_sks);
+    cuda_server_key_destroy(cuda_server_key);
+    cuda_streams_destroy(streams);
+  }
+
+  return ok;
+}
+
+#else
+#include <stdio.h>
+
+int main(void) {
+  fputs("tfhe-rs was not compiled with gpu support\n", stdout);
+  return 0;
+}
+#endif
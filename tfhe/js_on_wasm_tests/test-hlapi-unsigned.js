@@ -93,6 +93,35 @@ test("hlapi_key_gen_small", (t) => {
   let serializedPublicKey = publicKey.serialize();
 });
 
+// This test is run without a thread pool (no init_thread_pool),
+// so it checks that the client side flow does not need multiple threads
+test("hlapi_compressed_server_key_deserialize_then_encrypt_single_thread", (t) => {
+  let params_name_small =
+    ShortintParametersName.V1_0_PARAM_MESSAGE_2_CARRY_2_PBS_KS_GAUSSIAN_2M128;
+  let params_small = new ShortintParameters(params_name_small);
+  let config = TfheConfigBuilder.with_custom_parameters(params_small).build();
+
+  let clientKey = TfheClientKey.generate(config);
+  let serializedCompressedServerKey =
+    TfheCompressedServerKey.new(clientKey).safe_serialize(BigInt(1000000000));
+
+  // What a browser client receives
+  let compressedServerKey = TfheCompressedServerKey.safe_deserialize(
+    serializedCompressedServerKey,
+    BigInt(1000000000),
+  );
+  assert.deepStrictEqual(
+    compressedServerKey.safe_serialize(BigInt(1000000000)),
+    serializedCompressedServerKey,
+  );
+
+  let clear = 1234567;
+  let encrypted = FheUint32.encrypt_with_client_key(clear, clientKey);
+  let serialized = encrypted.safe_serialize(BigInt(10000000));
+  let deserialized = FheUint32.safe_deserialize(serialized, BigInt(10000000));
+  assert.deepStrictEqual(deserialized.decrypt(clientKey), clear);
+});
+
 test("hlapi_client_key_encrypt_decrypt_uint8_big", (t) => {
   let config = TfheConfigBuilder.default().build();
 
@@ -0,0 +1,196 @@
+//! Explicit handling of radix ciphertexts living on a Cuda GPU
+//!
+//! Contrary to the `FheUint` types, which are moved to the GPU implicitly when a
+//! `CudaServerKey` is set, ciphertexts here are uploaded, computed on, and downloaded
+//! explicitly, on the given streams.
+use super::integers::{FheUint16, FheUint32, FheUint64, FheUint8};
+use super::keys::CudaServerKey;
+use super::utils::*;
+use crate::c_api::utils::*;
+use crate::core_crypto::gpu::vec::GpuIndex;
+use crate::high_level_api::{IntegerId, Tag};
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
+use std::os::raw::c_int;
+
+/// Streams on a single Cuda GPU, on which GPU operations are scheduled
+pub struct CudaStreams(pub(crate) crate::core_crypto::gpu::CudaStreams);
+
+/// Unsigned radix ciphertext that lives on a Cuda GPU
+pub struct CudaRadixCiphertext {
+    pub(crate) inner: CudaUnsignedRadixCiphertext,
+    pub(crate) tag: Tag,
+}
+
+impl_destroy_on_type!(CudaStreams);
+impl_destroy_on_type!(CudaRadixCiphertext);
+
+/// Creates streams on the GPU with the given index
+///
+/// Returns an error if no GPU with this index is available.
+#[no_mangle]
+pub unsafe extern "C" fn cuda_streams_new(
+    gpu_index: u32,
+    result_streams: *mut *mut CudaStreams,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result_streams).unwrap();
+        *result_streams = std::ptr::null_mut();
+
+        let gpu_index = GpuIndex::try_new(gpu_index)
+            .ok_or_else(|| format!("No Cuda GPU with index {gpu_index}"))
+            .unwrap();
+        let streams = crate::core_crypto::gpu::CudaStreams::new_single_gpu(gpu_index);
+
+        *result_streams = Box::into_raw(Box::new(CudaStreams(streams)));
+    })
+}
+
+/// Blocks until all the work scheduled on the streams is done
+#[no_mangle]
+pub unsafe extern "C" fn cuda_streams_synchronize(streams: *const CudaStreams) -> c_int {
+    catch_panic(|| {
+        let streams = get_ref_checked(streams).unwrap();
+
+        streams.0.synchronize();
+    })
+}
+
+/// Returns the number of blocks of the ciphertext
+#[no_mangle]
+pub unsafe extern "C" fn cuda_radix_ciphertext_num_blocks(
+    ciphertext: *const CudaRadixCiphertext,
+    result: *mut usize,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+
+        let ciphertext = get_ref_checked(ciphertext).unwrap();
+
+        *result = ciphertext.inner.as_ref().d_blocks.lwe_ciphertext_count().0;
+    })
+}
+
+/// Adds `lhs` and `rhs` on the GPU, without propagating carries
+///
+/// Both ciphertexts must have the same number of blocks and enough carry space
+/// left to store the result, an error is returned otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn cuda_radix_ciphertext_unchecked_add(
+    lhs: *const CudaRadixCiphertext,
+    rhs: *const CudaRadixCiphertext,
+    server_key: *const CudaServerKey,
+    streams: *const CudaStreams,
+    result: *mut *mut CudaRadixCiphertext,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+        *result = std::ptr::null_mut();
+
+        let lhs = get_ref_checked(lhs).unwrap();
+        let rhs = get_ref_checked(rhs).unwrap();
+        let server_key = get_ref_checked(server_key).unwrap();
+        let streams = get_ref_checked(streams).unwrap();
+
+        let key = &server_key.0.key;
+        let (lhs_info, rhs_info) = (&lhs.inner.as_ref().info, &rhs.inner.as_ref().info);
+        assert_eq!(
+            lhs_info.blocks.len(),
+            rhs_info.blocks.len(),
+            "Both ciphertexts must have the same number of blocks"
+        );
+        let max_degree = key.key.max_degree.get();
+        assert!(
+            lhs_info
+                .blocks
+                .iter()
+                .zip(rhs_info.blocks.iter())
+                .all(|(l, r)| l.degree.get() + r.degree.get() <= max_degree),
+            "Not enough carry space left to add the ciphertexts"
+        );
+
+        let inner = key.key.unchecked_add(&lhs.inner, &rhs.inner, &streams.0);
+
+        *result = Box::into_raw(Box::new(CudaRadixCiphertext {
+            inner,
+            tag: server_key.0.tag.clone(),
+        }));
+    })
+}
+
+macro_rules! impl_cuda_radix_ciphertext_transfer_on_type {
+    ($($wrapper_type:ident),* $(,)?) => {
+        $(
+            ::paste::paste! {
+                /// Copies the ciphertext to the GPU the streams belong to
+                #[no_mangle]
+                pub unsafe extern "C" fn [<$wrapper_type:snake _upload_to_gpu>](
+                    ciphertext: *const $wrapper_type,
+                    streams: *const CudaStreams,
+                    result: *mut *mut CudaRadixCiphertext,
+                ) -> c_int {
+                    catch_panic(|| {
+                        check_ptr_is_non_null_and_aligned(result).unwrap();
+                        *result = std::ptr::null_mut();
+
+                        let ciphertext = get_ref_checked(ciphertext).unwrap();
+                        let streams = get_ref_checked(streams).unwrap();
+
+                        let (cpu_ciphertext, _, tag) = ciphertext.0.clone().into_raw_parts();
+                        let inner = CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+                            &cpu_ciphertext,
+                            &streams.0,
+                        );
+
+                        *result = Box::into_raw(Box::new(CudaRadixCiphertext { inner, tag }));
+                    })
+                }
+
+                /// Copies the ciphertext back from the GPU
+                ///
+                /// Returns an error if the number of blocks of the ciphertext does not match
+                /// the output type.
+                #[no_mangle]
+                pub unsafe extern "C" fn [<cuda_radix_ciphertext_download_to_ $wrapper_type:snake>](
+                    ciphertext: *const CudaRadixCiphertext,
+                    streams: *const CudaStreams,
+                    result: *mut *mut $wrapper_type,
+                ) -> c_int {
+                    catch_panic(|| {
+                        check_ptr_is_non_null_and_aligned(result).unwrap();
+                        *result = std::ptr::null_mut();
+
+                        let ciphertext = get_ref_checked(ciphertext).unwrap();
+                        let streams = get_ref_checked(streams).unwrap();
+
+                        let cpu_ciphertext = ciphertext.inner.to_radix_ciphertext(&streams.0);
+
+                        let message_modulus = cpu_ciphertext
+                            .blocks
+                            .first()
+                            .expect("Cannot download a ciphertext without blocks")
+                            .message_modulus;
+                        let expected_num_blocks =
+                            crate::high_level_api::[<$wrapper_type Id>]::num_blocks(message_modulus);
+                        assert_eq!(
+                            cpu_ciphertext.blocks.len(),
+                            expected_num_blocks,
+                            "The ciphertext has {} blocks, expected {} blocks",
+                            cpu_ciphertext.blocks.len(),
+                            expected_num_blocks,
+                        );
+
+                        let fhe_ciphertext = crate::high_level_api::$wrapper_type::from_raw_parts(
+                            cpu_ciphertext,
+                            Default::default(),
+                            ciphertext.tag.clone(),
+                        );
+
+                        *result = Box::into_raw(Box::new($wrapper_type(fhe_ciphertext)));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_cuda_radix_ciphertext_transfer_on_type!(FheUint8, FheUint16, FheUint32, FheUint64);
@@ -4,6 +4,8 @@ pub mod booleans;
 mod compact_list;
 pub mod compressed_ciphertext_list;
 pub mod config;
+#[cfg(feature = "gpu")]
+pub mod gpu;
 pub mod i128;
 pub mod i256;
 pub mod integers;
@@ -1,8 +1,8 @@
 use tfhe_versionable::VersionsDispatch;
 
-use crate::Config;
+use crate::high_level_api::config::VersionizableConfig;
 
 #[derive(VersionsDispatch)]
 pub enum ConfigVersions {
-    V0(Config),
+    V0(VersionizableConfig),
 }
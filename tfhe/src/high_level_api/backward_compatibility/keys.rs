@@ -27,17 +27,17 @@ impl Upgrade<ClientKey> for ClientKeyV0 {
     }
 }
 
-impl Deprecable for ServerKey {
+impl Deprecable for VersionizableServerKey {
     const TYPE_NAME: &'static str = "ServerKey";
     const MIN_SUPPORTED_APP_VERSION: &'static str = "TFHE-rs v0.10";
 }
 
 #[derive(VersionsDispatch)]
 pub enum ServerKeyVersions {
-    V0(Deprecated<ServerKey>),
-    V1(Deprecated<ServerKey>),
-    V2(Deprecated<ServerKey>),
-    V3(ServerKey),
+    V0(Deprecated<VersionizableServerKey>),
+    V1(Deprecated<VersionizableServerKey>),
+    V2(Deprecated<VersionizableServerKey>),
+    V3(VersionizableServerKey),
 }
 
 impl Deprecable for CompressedServerKey {
@@ -140,10 +140,36 @@ pub enum CompressedCompactPublicKeyVersions {
     V1(CompressedCompactPublicKey),
 }
 
+#[derive(Version)]
+#[allow(clippy::struct_field_names)]
+pub(crate) struct IntegerConfigV0 {
+    pub(crate) block_parameters: crate::shortint::PBSParameters,
+    pub(crate) dedicated_compact_public_key_parameters: Option<(
+        crate::shortint::parameters::CompactPublicKeyEncryptionParameters,
+        crate::shortint::parameters::ShortintKeySwitchingParameters,
+    )>,
+    pub(crate) compression_parameters:
+        Option<crate::shortint::parameters::list_compression::CompressionParameters>,
+}
+
+impl Upgrade<IntegerConfig> for IntegerConfigV0 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<IntegerConfig, Self::Error> {
+        Ok(IntegerConfig {
+            block_parameters: self.block_parameters,
+            dedicated_compact_public_key_parameters: self.dedicated_compact_public_key_parameters,
+            compression_parameters: self.compression_parameters,
+            only_additions: false,
+        })
+    }
+}
+
 #[derive(VersionsDispatch)]
 #[allow(unused)]
 pub(crate) enum IntegerConfigVersions {
-    V0(IntegerConfig),
+    V0(IntegerConfigV0),
+    V1(IntegerConfig),
 }
 
 impl Deprecable for IntegerClientKey {
@@ -158,11 +184,11 @@ pub(crate) struct IntegerClientKeyV2 {
     pub(crate) compression_key: Option<crate::shortint::list_compression::CompressionPrivateKeys>,
 }
 
-impl Upgrade<IntegerClientKey> for IntegerClientKeyV2 {
+impl Upgrade<IntegerClientKeyV3> for IntegerClientKeyV2 {
     type Error = Infallible;
 
-    fn upgrade(self) -> Result<IntegerClientKey, Self::Error> {
-        Ok(IntegerClientKey {
+    fn upgrade(self) -> Result<IntegerClientKeyV3, Self::Error> {
+        Ok(IntegerClientKeyV3 {
             key: self.key,
             dedicated_compact_private_key: self.dedicated_compact_private_key,
             compression_key: self
@@ -172,13 +198,34 @@ impl Upgrade<IntegerClientKey> for IntegerClientKeyV2 {
     }
 }
 
+#[derive(Version)]
+pub(crate) struct IntegerClientKeyV3 {
+    pub(crate) key: crate::integer::ClientKey,
+    pub(crate) dedicated_compact_private_key: Option<CompactPrivateKey>,
+    pub(crate) compression_key: Option<crate::integer::compression_keys::CompressionPrivateKeys>,
+}
+
+impl Upgrade<IntegerClientKey> for IntegerClientKeyV3 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<IntegerClientKey, Self::Error> {
+        Ok(IntegerClientKey {
+            key: self.key,
+            dedicated_compact_private_key: self.dedicated_compact_private_key,
+            compression_key: self.compression_key,
+            only_additions: false,
+        })
+    }
+}
+
 #[derive(VersionsDispatch)]
 #[allow(unused)]
 pub(crate) enum IntegerClientKeyVersions {
     V0(Deprecated<IntegerClientKey>),
     V1(Deprecated<IntegerClientKey>),
     V2(IntegerClientKeyV2),
-    V3(IntegerClientKey),
+    V3(IntegerClientKeyV3),
+    V4(IntegerClientKey),
 }
 
 impl Deprecable for IntegerServerKey {
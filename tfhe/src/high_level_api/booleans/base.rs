@@ -6,7 +6,7 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::{FheInt, FheIntId, FheUint, FheUintId};
 use crate::high_level_api::keys::InternalServerKey;
-use crate::high_level_api::traits::{FheEq, IfThenElse, Tagged};
+use crate::high_level_api::traits::{FheEq, FheTrivialEncrypt, IfThenElse, Tagged};
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
 #[cfg(feature = "gpu")]
@@ -18,6 +18,7 @@ use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::parameters::CiphertextConformanceParams;
 use crate::shortint::PBSParameters;
 use crate::{Device, ServerKey, Tag};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
 use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
@@ -178,6 +179,408 @@ impl FheBool {
     pub fn is_trivial(&self) -> bool {
         self.ciphertext.on_cpu().is_trivial()
     }
+
+    /// Returns the number of bytes `bincode` would produce when serializing `self`
+    ///
+    /// The size is computed without serializing into a buffer, which makes it
+    /// cheap to use to preallocate buffers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, ConfigBuilder, FheBool};
+    ///
+    /// let (client_key, _server_key) = generate_keys(ConfigBuilder::default());
+    ///
+    /// let a = FheBool::encrypt(true, &client_key);
+    ///
+    /// let serialized = bincode::serialize(&a).unwrap();
+    /// assert_eq!(a.serialized_size(), serialized.len());
+    /// ```
+    pub fn serialized_size(&self) -> usize {
+        bincode::serialized_size(self).unwrap() as usize
+    }
+
+    /// Reduces the flags with the binary operation `op`, using a balanced parallel tree
+    ///
+    /// At each level of the tree, consecutive pairs of flags are combined in parallel,
+    /// the left flag of the pair being the first operand of `op`. So `op` needs to be
+    /// associative (e.g. `&`, `|`, `^`), but it does not need to be commutative.
+    ///
+    /// - Returns `None` if `flags` is empty
+    /// - Returns a clone of the flag if `flags` has only one element
+    ///
+    /// On CPU, the server key of the calling thread is made available to the threads
+    /// executing `op`. On Cuda GPU, pairs are combined one after the other.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let clears = [false, false, true, false, false];
+    /// let flags = clears
+    ///     .iter()
+    ///     .map(|b| FheBool::encrypt(*b, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let any = FheBool::reduce(&flags, |a, b| a | b).unwrap();
+    /// assert!(any.decrypt(&client_key));
+    ///
+    /// let all = FheBool::reduce(&flags, |a, b| a & b).unwrap();
+    /// assert!(!all.decrypt(&client_key));
+    /// ```
+    pub fn reduce<F>(flags: &[Self], op: F) -> Option<Self>
+    where
+        F: Fn(&Self, &Self) -> Self + Sync,
+    {
+        // The key is cloned (which is cheap) so that it is not borrowed while
+        // other threads need it
+        let cpu_key = global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => Some(cpu_key.clone()),
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => None,
+        });
+
+        let mut items = flags.to_vec();
+        while items.len() > 1 {
+            // Group consecutive flags by pairs, if the number of flags is odd,
+            // the last one has no pair and is forwarded to the next level as is
+            let mut pairs = Vec::with_capacity(items.len().div_ceil(2));
+            let mut iter = items.into_iter();
+            while let Some(lhs) = iter.next() {
+                pairs.push((lhs, iter.next()));
+            }
+
+            items = match &cpu_key {
+                Some(cpu_key) => pairs
+                    .into_par_iter()
+                    .map(|(lhs, rhs)| match rhs {
+                        Some(rhs) => {
+                            global_state::with_thread_internal_keys(cpu_key.clone(), || {
+                                op(&lhs, &rhs)
+                            })
+                        }
+                        None => lhs,
+                    })
+                    .collect(),
+                None => pairs
+                    .into_iter()
+                    .map(|(lhs, rhs)| match rhs {
+                        Some(rhs) => op(&lhs, &rhs),
+                        None => lhs,
+                    })
+                    .collect(),
+            };
+        }
+
+        items.pop()
+    }
+
+    /// Returns an encryption of `true` if all the flags are `true`
+    ///
+    /// The flags are combined with `&` using the parallel tree of [Self::reduce].
+    ///
+    /// If `flags` is empty, a trivial encryption of `true` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let flags = [true, true, false]
+    ///     .iter()
+    ///     .map(|b| FheBool::encrypt(*b, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let all = FheBool::all(&flags);
+    /// assert!(!all.decrypt(&client_key));
+    ///
+    /// let all = FheBool::all(&flags[..2]);
+    /// assert!(all.decrypt(&client_key));
+    /// ```
+    pub fn all(flags: &[Self]) -> Self {
+        Self::reduce(flags, |lhs, rhs| lhs & rhs).unwrap_or_else(|| Self::encrypt_trivial(true))
+    }
+
+    /// Returns an encryption of `true` if at least one of the flags is `true`
+    ///
+    /// The flags are combined with `|` using the parallel tree of [Self::reduce].
+    ///
+    /// If `flags` is empty, a trivial encryption of `false` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let flags = [false, true, false]
+    ///     .iter()
+    ///     .map(|b| FheBool::encrypt(*b, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let any = FheBool::any(&flags);
+    /// assert!(any.decrypt(&client_key));
+    ///
+    /// let any = FheBool::any(&flags[2..]);
+    /// assert!(!any.decrypt(&client_key));
+    /// ```
+    pub fn any(flags: &[Self]) -> Self {
+        Self::reduce(flags, |lhs, rhs| lhs | rhs).unwrap_or_else(|| Self::encrypt_trivial(false))
+    }
+
+    /// Casts many boolean ciphertexts to unsigned ciphertexts
+    ///
+    /// The output is in the same order as the input, and each element
+    /// encrypts the same value as [FheUint::cast_from] would give.
+    ///
+    /// On CPU, casting a [FheBool] does not require any PBS.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool, FheUint16Id};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let clears = [true, false, true];
+    /// let bools = clears
+    ///     .iter()
+    ///     .map(|b| FheBool::encrypt(*b, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let casted = FheBool::cast_many_to::<FheUint16Id>(&bools);
+    ///
+    /// for (ct, clear) in casted.iter().zip(clears) {
+    ///     let decrypted: u16 = ct.decrypt(&client_key);
+    ///     assert_eq!(decrypted, u16::from(clear));
+    /// }
+    /// ```
+    pub fn cast_many_to<Id>(bools: &[Self]) -> Vec<FheUint<Id>>
+    where
+        Id: FheUintId + Send,
+    {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let num_blocks = Id::num_blocks(cpu_key.message_modulus());
+                bools
+                    .par_iter()
+                    .map(|b| {
+                        let ciphertext: crate::integer::RadixCiphertext = b
+                            .ciphertext
+                            .on_cpu()
+                            .into_owned()
+                            .into_radix(num_blocks, cpu_key.pbs_key());
+                        FheUint::new(ciphertext, cpu_key.tag.clone())
+                    })
+                    .collect()
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let num_blocks = Id::num_blocks(cuda_key.message_modulus());
+                bools
+                    .iter()
+                    .map(|b| {
+                        let inner = cuda_key.key.key.cast_to_unsigned(
+                            b.ciphertext.on_gpu(streams).duplicate(streams),
+                            num_blocks,
+                            streams,
+                        );
+                        FheUint::new(inner, cuda_key.tag.clone())
+                    })
+                    .collect()
+            }),
+        })
+    }
+
+    /// Conditional selection of a whole record, each record being made of several fields.
+    ///
+    /// - if `self` is true, the output will have the values of the fields of `a`
+    /// - if `self` is false, the output will have the values of the fields of `b`
+    ///
+    /// This gives the same result as calling [IfThenElse::if_then_else] on each pair of fields,
+    /// but on CPU, the fields are processed as a single wide integer so that the condition
+    /// is handled only once for the whole record.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` do not have the same number of fields
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = [1u32, 2, 3]
+    ///     .iter()
+    ///     .map(|v| FheUint32::encrypt(*v, &client_key))
+    ///     .collect::<Vec<_>>();
+    /// let b = [4u32, 5, 6]
+    ///     .iter()
+    ///     .map(|v| FheUint32::encrypt(*v, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let condition = FheBool::encrypt(false, &client_key);
+    ///
+    /// let selected = condition.select_record(&a, &b);
+    /// let decrypted = selected
+    ///     .iter()
+    ///     .map(|field| field.decrypt(&client_key))
+    ///     .collect::<Vec<u32>>();
+    /// assert_eq!(decrypted, vec![4, 5, 6]);
+    /// ```
+    pub fn select_record<Id>(&self, a: &[FheUint<Id>], b: &[FheUint<Id>]) -> Vec<FheUint<Id>>
+    where
+        Id: FheUintId + Sync,
+    {
+        assert_eq!(
+            a.len(),
+            b.len(),
+            "Cannot select between records of {} and {} fields",
+            a.len(),
+            b.len()
+        );
+
+        if a.is_empty() {
+            return Vec::new();
+        }
+
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+
+                let (record_a, record_b) = rayon::join(
+                    || {
+                        let fields = a
+                            .iter()
+                            .map(|field| field.ciphertext.on_cpu().into_owned())
+                            .collect::<Vec<_>>();
+                        sks.concat_radix_blocks(&fields)
+                    },
+                    || {
+                        let fields = b
+                            .iter()
+                            .map(|field| field.ciphertext.on_cpu().into_owned())
+                            .collect::<Vec<_>>();
+                        sks.concat_radix_blocks(&fields)
+                    },
+                );
+
+                let selected =
+                    sks.if_then_else_parallelized(&self.ciphertext.on_cpu(), &record_a, &record_b);
+
+                // All fields have the same type, so they all have the same number of blocks
+                let num_blocks = selected.blocks.len() / a.len();
+                selected
+                    .into_blocks()
+                    .chunks_exact(num_blocks)
+                    .map(|blocks| {
+                        let inner = crate::integer::RadixCiphertext::from(blocks.to_vec());
+                        FheUint::new(inner, cpu_key.tag.clone())
+                    })
+                    .collect()
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => a
+                .iter()
+                .zip(b.iter())
+                .map(|(field_a, field_b)| self.if_then_else(field_a, field_b))
+                .collect(),
+        })
+    }
+
+    /// Selects the value whose flag is set in a one-hot `selector`
+    ///
+    /// This computes `sum(selector[i] * values[i])`, each value being multiplied by its
+    /// flag with a single PBS per block, instead of a chain of `if_then_else`.
+    ///
+    /// - If no flag is set, an encryption of zero is returned
+    /// - At most one flag must be set, otherwise the result is unspecified
+    ///
+    /// # Panics
+    ///
+    /// Panics if `selector` and `values` do not have the same length
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let values = [10u16, 20, 30]
+    ///     .iter()
+    ///     .map(|v| FheUint16::encrypt(*v, &client_key))
+    ///     .collect::<Vec<_>>();
+    /// let selector = [false, true, false]
+    ///     .iter()
+    ///     .map(|b| FheBool::encrypt(*b, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let selected = FheBool::select_one_hot(&selector, &values);
+    /// let decrypted: u16 = selected.decrypt(&client_key);
+    /// assert_eq!(decrypted, 20);
+    /// ```
+    pub fn select_one_hot<Id>(selector: &[Self], values: &[FheUint<Id>]) -> FheUint<Id>
+    where
+        Id: FheUintId + Send + Sync,
+    {
+        assert_eq!(
+            selector.len(),
+            values.len(),
+            "The selector has {} flags for {} values",
+            selector.len(),
+            values.len()
+        );
+
+        let mut result = global_state::with_cpu_fallback_keys("select_one_hot", |cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let num_blocks = Id::num_blocks(sks.message_modulus());
+
+            let selected = selector
+                .par_iter()
+                .zip(values.par_iter())
+                .map(|(flag, value)| {
+                    let mut value = value.ciphertext.on_cpu().into_owned();
+                    if !value.block_carries_are_empty() {
+                        sks.full_propagate_parallelized(&mut value);
+                    }
+                    sks.zero_out_if_condition_is_false(&mut value, &flag.ciphertext.on_cpu().0);
+                    value
+                })
+                .collect::<Vec<_>>();
+            let result = if selected.is_empty() {
+                sks.create_trivial_zero_radix(num_blocks)
+            } else {
+                sks.aggregate_unpacked_one_hot_vector(selected)
+            };
+
+            FheUint::new(result, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
+    }
 }
 
 impl<Id> IfThenElse<FheUint<Id>> for FheBool
@@ -7,7 +7,7 @@
 use crate::prelude::*;
 use crate::{
     generate_keys, set_server_key, ClientKey, CompressedFheBool, CompressedPublicKey,
-    ConfigBuilder, Device, FheBool,
+    ConfigBuilder, Device, FheBool, FheUint8, FheUint8Id,
 };
 
 #[inline(always)]
@@ -643,6 +643,183 @@ mod cpu {
         assert!(!b.decrypt(&keys));
     }
 
+    #[test]
+    fn test_decrypt_bools() {
+        let keys = setup_default();
+
+        let clears = (0..100).map(|_| random::<bool>()).collect::<Vec<_>>();
+        let bools = clears
+            .iter()
+            .map(|b| FheBool::encrypt(*b, &keys))
+            .collect::<Vec<_>>();
+
+        let decrypted = keys.decrypt_bools(&bools);
+        assert_eq!(decrypted, clears);
+
+        let decrypted = keys.decrypt_bools(&[]);
+        assert!(decrypted.is_empty());
+    }
+
+    #[test]
+    fn test_reduce() {
+        let keys = setup_default();
+
+        for num_flags in [1, 2, 3, 17, 64] {
+            let mut clears = vec![false; num_flags];
+            let flags = clears
+                .iter()
+                .map(|b| FheBool::encrypt(*b, &keys))
+                .collect::<Vec<_>>();
+            let result = FheBool::reduce(&flags, |a, b| a | b).unwrap();
+            assert!(!result.decrypt(&keys));
+
+            let index = random::<usize>() % num_flags;
+            clears[index] = true;
+            let flags = clears
+                .iter()
+                .map(|b| FheBool::encrypt(*b, &keys))
+                .collect::<Vec<_>>();
+            let result = FheBool::reduce(&flags, |a, b| a | b).unwrap();
+            assert!(result.decrypt(&keys), "Invalid OR of {num_flags} flags");
+
+            let clears = (0..num_flags).map(|_| random::<bool>()).collect::<Vec<_>>();
+            let flags = clears
+                .iter()
+                .map(|b| FheBool::encrypt(*b, &keys))
+                .collect::<Vec<_>>();
+            let result = FheBool::reduce(&flags, |a, b| a ^ b).unwrap();
+            let expected = clears.iter().fold(false, |acc, b| acc ^ b);
+            assert_eq!(result.decrypt(&keys), expected);
+        }
+
+        assert!(FheBool::reduce(&[], |a, b| a | b).is_none());
+    }
+
+    #[test]
+    fn test_select_one_hot() {
+        let keys = setup_default();
+
+        let clears = (0..4).map(|_| random::<u8>()).collect::<Vec<_>>();
+        let values = clears
+            .iter()
+            .map(|v| FheUint8::encrypt(*v, &keys))
+            .collect::<Vec<_>>();
+
+        for (index, clear) in clears.iter().enumerate() {
+            let selector = (0..clears.len())
+                .map(|i| FheBool::encrypt(i == index, &keys))
+                .collect::<Vec<_>>();
+
+            let selected = FheBool::select_one_hot(&selector, &values);
+            let decrypted: u8 = selected.decrypt(&keys);
+            assert_eq!(decrypted, *clear, "Invalid value selected at index {index}");
+        }
+
+        let selector = (0..clears.len())
+            .map(|_| FheBool::encrypt(false, &keys))
+            .collect::<Vec<_>>();
+        let selected = FheBool::select_one_hot(&selector, &values);
+        let decrypted: u8 = selected.decrypt(&keys);
+        assert_eq!(decrypted, 0);
+
+        let selected = FheBool::select_one_hot::<FheUint8Id>(&[], &[]);
+        let decrypted: u8 = selected.decrypt(&keys);
+        assert_eq!(decrypted, 0);
+    }
+
+    #[test]
+    fn test_all_any() {
+        let keys = setup_default();
+
+        for num_flags in [1, 2, 5, 16] {
+            let clears = (0..num_flags).map(|_| random::<bool>()).collect::<Vec<_>>();
+            let flags = clears
+                .iter()
+                .map(|b| FheBool::encrypt(*b, &keys))
+                .collect::<Vec<_>>();
+
+            let all = FheBool::all(&flags);
+            assert_eq!(all.decrypt(&keys), clears.iter().all(|b| *b));
+            let any = FheBool::any(&flags);
+            assert_eq!(any.decrypt(&keys), clears.iter().any(|b| *b));
+        }
+
+        let all = FheBool::all(&[]);
+        assert!(all.is_trivial());
+        assert!(all.decrypt(&keys));
+        let any = FheBool::any(&[]);
+        assert!(any.is_trivial());
+        assert!(!any.decrypt(&keys));
+    }
+
+    #[test]
+    fn test_cast_many_to() {
+        let (keys, server_key) = generate_keys(ConfigBuilder::default());
+        set_server_key(server_key.clone());
+
+        let clears = (0..100).map(|_| random::<bool>()).collect::<Vec<_>>();
+        let bools = clears
+            .iter()
+            .map(|b| FheBool::encrypt(*b, &keys))
+            .collect::<Vec<_>>();
+
+        #[cfg(feature = "pbs-stats")]
+        let casted = crate::assert_pbs_count(
+            || {
+                set_server_key(server_key.clone());
+                FheBool::cast_many_to::<FheUint8Id>(&bools)
+            },
+            0,
+        );
+        #[cfg(not(feature = "pbs-stats"))]
+        let casted = FheBool::cast_many_to::<FheUint8Id>(&bools);
+
+        assert_eq!(casted.len(), bools.len());
+        for ((ct, b), clear) in casted.iter().zip(bools).zip(clears) {
+            let baseline = FheUint8::cast_from(b);
+            let decrypted: u8 = ct.decrypt(&keys);
+            let expected: u8 = baseline.decrypt(&keys);
+            assert_eq!(decrypted, expected);
+            assert_eq!(decrypted, u8::from(clear));
+        }
+    }
+
+    #[test]
+    fn test_select_record() {
+        let keys = setup_default();
+
+        for num_fields in [1, 4] {
+            let clears_a = (0..num_fields).map(|_| random::<u8>()).collect::<Vec<_>>();
+            let clears_b = (0..num_fields).map(|_| random::<u8>()).collect::<Vec<_>>();
+
+            let a = clears_a
+                .iter()
+                .map(|v| FheUint8::encrypt(*v, &keys))
+                .collect::<Vec<_>>();
+            let b = clears_b
+                .iter()
+                .map(|v| FheUint8::encrypt(*v, &keys))
+                .collect::<Vec<_>>();
+
+            for (cond, expected) in [(true, &clears_a), (false, &clears_b)] {
+                let condition = FheBool::encrypt(cond, &keys);
+
+                let selected = condition.select_record(&a, &b);
+                assert_eq!(selected.len(), num_fields);
+
+                let decrypted = selected
+                    .iter()
+                    .map(|field| field.decrypt(&keys))
+                    .collect::<Vec<u8>>();
+                assert_eq!(&decrypted, expected);
+            }
+        }
+
+        let condition = FheBool::encrypt(true, &keys);
+        let empty: [FheUint8; 0] = [];
+        assert!(condition.select_record(&empty, &empty).is_empty());
+    }
+
     #[test]
     fn test_compressed_public_key_encrypt() {
         let config = ConfigBuilder::default().build();
@@ -479,6 +479,25 @@ impl CompactCiphertextListBuilder {
             .expect("Internal error, invalid parameters should not have been allowed")
     }
 
+    /// Builds the list without packing, proving that each encrypted block fits in the
+    /// message modulus
+    ///
+    /// The `crs` must be built for this range, see [`CompactPkeCrs::from_config_unpacked`].
+    #[cfg(feature = "zk-pok")]
+    pub fn build_with_proof(
+        &self,
+        crs: &CompactPkeCrs,
+        metadata: &[u8],
+        compute_load: ZkComputeLoad,
+    ) -> crate::Result<ProvenCompactCiphertextList> {
+        self.inner
+            .build_with_proof(crs, metadata, compute_load)
+            .map(|proved_list| ProvenCompactCiphertextList {
+                inner: proved_list,
+                tag: self.tag.clone(),
+            })
+    }
+
     #[cfg(feature = "zk-pok")]
     pub fn build_with_proof_packed(
         &self,
@@ -526,7 +545,9 @@ mod tests {
     use super::*;
     use crate::prelude::*;
     use crate::shortint::parameters::*;
-    use crate::{set_server_key, FheBool, FheInt64, FheUint16, FheUint2, FheUint32};
+    use crate::{
+        set_server_key, FheBool, FheInt16, FheInt64, FheUint16, FheUint2, FheUint32, FheUint8,
+    };
 
     #[test]
     fn test_compact_list() {
@@ -581,6 +602,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_compact_list_mixed_widths_with_compressed_public_key() {
+        let config = crate::ConfigBuilder::default().build();
+
+        let ck = crate::ClientKey::generate(config);
+        let sk = crate::ServerKey::new(&ck);
+        let compressed_pk = crate::CompressedCompactPublicKey::new(&ck);
+
+        set_server_key(sk);
+
+        // The compressed key is what the client downloads, it is decompressed before encrypting
+        let serialized_pk = bincode::serialize(&compressed_pk).unwrap();
+        let compressed_pk: crate::CompressedCompactPublicKey =
+            bincode::deserialize(&serialized_pk).unwrap();
+        let pk = compressed_pk.decompress();
+
+        let compact_list = CompactCiphertextList::builder(&pk)
+            .push(u8::MAX)
+            .push(0xDEAD_BEEFu32)
+            .push(-1234i16)
+            .build_packed();
+
+        let serialized = bincode::serialize(&compact_list).unwrap();
+        let compact_list: CompactCiphertextList = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(compact_list.len(), 3);
+
+        let expander = compact_list.expand().unwrap();
+        assert_eq!(expander.len(), 3);
+
+        let a: FheUint8 = expander.get(0).unwrap().unwrap();
+        let b: FheUint32 = expander.get(1).unwrap().unwrap();
+        let c: FheInt16 = expander.get(2).unwrap().unwrap();
+        assert!(expander.get::<FheUint8>(3).unwrap().is_none());
+
+        let a: u8 = a.decrypt(&ck);
+        assert_eq!(a, u8::MAX);
+        let b: u32 = b.decrypt(&ck);
+        assert_eq!(b, 0xDEAD_BEEF);
+        let c: i16 = c.decrypt(&ck);
+        assert_eq!(c, -1234);
+
+        // Widths are checked when expanding
+        assert!(expander.get::<FheUint32>(0).is_err());
+        assert!(expander.get::<FheUint8>(1).is_err());
+        assert!(expander.get::<FheUint16>(2).is_err());
+    }
+
     #[test]
     fn test_compact_list_with_casting() {
         let config = crate::ConfigBuilder::with_custom_parameters(
@@ -724,6 +792,75 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "zk-pok")]
+    #[test]
+    fn test_proven_compact_list_rejects_tampered_ciphertext() {
+        let config = crate::ConfigBuilder::with_custom_parameters(
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        )
+        .use_dedicated_compact_public_key_parameters((
+            PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+            PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        ))
+        .build();
+
+        let ck = crate::ClientKey::generate(config);
+        let pk = crate::CompactPublicKey::new(&ck);
+        let sks = crate::ServerKey::new(&ck);
+
+        set_server_key(sks);
+
+        let crs = CompactPkeCrs::from_config_unpacked(config, 32).unwrap();
+
+        let metadata = [b'h', b'l', b'a', b'p', b'i'];
+
+        let compact_list = ProvenCompactCiphertextList::builder(&pk)
+            .push(17u8)
+            .push(true)
+            .build_with_proof(&crs, &metadata, ZkComputeLoad::Proof)
+            .unwrap();
+
+        // The packed crs does not prove that the blocks fit in the message modulus
+        let packed_crs = CompactPkeCrs::from_config(config, 32).unwrap();
+        assert!(ProvenCompactCiphertextList::builder(&pk)
+            .push(17u8)
+            .build_with_proof(&packed_crs, &metadata, ZkComputeLoad::Proof)
+            .is_err());
+
+        assert!(compact_list.verify(&crs, &pk, &metadata).is_valid());
+        {
+            let expander = compact_list
+                .verify_and_expand(&crs, &pk, &metadata)
+                .unwrap();
+            let a: FheUint8 = expander.get(0).unwrap().unwrap();
+            let b: FheBool = expander.get(1).unwrap().unwrap();
+
+            let a: u8 = a.decrypt(&ck);
+            assert_eq!(a, 17);
+            assert!(b.decrypt(&ck));
+        }
+
+        // The proof is bound to the metadata
+        assert!(compact_list.verify(&crs, &pk, b"other").is_invalid());
+        assert!(compact_list.verify_and_expand(&crs, &pk, b"other").is_err());
+
+        // Add 1 to the message of the first block, keeping it in the message space
+        let mut tampered_list = compact_list;
+        let (shortint_list, _) = &mut tampered_list.inner.ct_list.proved_lists[0];
+        let pke_params = PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+        // One bit of padding
+        let delta =
+            (1u64 << (u64::BITS - 1)) / (pke_params.message_modulus.0 * pke_params.carry_modulus.0);
+        let mut body_list = shortint_list.ct_list.get_mut_body_list();
+        let body = &mut body_list.as_mut()[0];
+        *body = body.wrapping_add(delta);
+
+        assert!(tampered_list.verify(&crs, &pk, &metadata).is_invalid());
+        assert!(tampered_list
+            .verify_and_expand(&crs, &pk, &metadata)
+            .is_err());
+    }
+
     #[cfg(feature = "strings")]
     #[test]
     fn test_compact_list_with_string_and_casting() {
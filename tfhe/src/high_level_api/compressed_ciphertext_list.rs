@@ -31,7 +31,15 @@ use crate::{FheBool, FheInt, FheUint, Tag};
 impl<Id: FheUintId> HlCompressible for FheUint<Id> {
     fn compress_into(self, messages: &mut Vec<(ToBeCompressed, DataKind)>) {
         match self.ciphertext {
-            crate::high_level_api::integers::unsigned::RadixCiphertext::Cpu(cpu_radix) => {
+            crate::high_level_api::integers::unsigned::RadixCiphertext::Cpu(mut cpu_radix) => {
+                // A lazy carry propagation may have left carries, they are not compressed
+                if !cpu_radix.block_carries_are_empty() {
+                    crate::high_level_api::global_state::with_cpu_internal_keys(|cpu_key| {
+                        cpu_key
+                            .pbs_key()
+                            .full_propagate_parallelized(&mut cpu_radix);
+                    });
+                }
                 let blocks = cpu_radix.blocks;
                 let kind = DataKind::Unsigned(blocks.len());
                 messages.push((ToBeCompressed::Cpu(blocks), kind));
@@ -714,7 +722,8 @@ mod tests {
     use crate::shortint::PBSParameters;
     use crate::{
         set_server_key, unset_server_key, ClientKey, CompressedCiphertextList,
-        CompressedCiphertextListBuilder, FheBool, FheInt64, FheUint16, FheUint2, FheUint32,
+        CompressedCiphertextListBuilder, FheBool, FheInt16, FheInt64, FheInt8, FheUint16, FheUint2,
+        FheUint32, FheUint64, FheUint8,
     };
 
     #[test]
@@ -886,6 +895,96 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_compressed_ct_list_mixed_widths_cpu() {
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128.into();
+        let config = crate::ConfigBuilder::with_custom_parameters::<PBSParameters>(params)
+            .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+            .build();
+
+        let ck = crate::ClientKey::generate(config);
+        let sk = crate::ServerKey::new(&ck);
+        set_server_key(sk);
+
+        let mut compressed_list_builder = CompressedCiphertextListBuilder::new();
+        compressed_list_builder
+            .push(FheUint8::encrypt(213u8, &ck))
+            .push(FheInt16::encrypt(-1234i16, &ck))
+            .push(FheBool::encrypt(true, &ck))
+            .push(FheUint64::encrypt(u64::MAX - 7, &ck))
+            .push(FheInt8::encrypt(i8::MIN, &ck))
+            .push(FheUint16::encrypt(4242u16, &ck));
+        let compressed_list = compressed_list_builder.build().unwrap();
+
+        let mut serialized = vec![];
+        safe_serialize(&compressed_list, &mut serialized, 1024 * 1024 * 16).unwrap();
+        let compressed_list: CompressedCiphertextList =
+            safe_deserialize(serialized.as_slice(), 1024 * 1024 * 16).unwrap();
+
+        assert_eq!(compressed_list.len(), 6);
+        assert_eq!(compressed_list.get_kind_of(0), Some(crate::FheTypes::Uint8));
+        assert_eq!(compressed_list.get_kind_of(1), Some(crate::FheTypes::Int16));
+        assert_eq!(compressed_list.get_kind_of(2), Some(crate::FheTypes::Bool));
+        assert_eq!(
+            compressed_list.get_kind_of(3),
+            Some(crate::FheTypes::Uint64)
+        );
+        assert_eq!(compressed_list.get_kind_of(4), Some(crate::FheTypes::Int8));
+        assert_eq!(
+            compressed_list.get_kind_of(5),
+            Some(crate::FheTypes::Uint16)
+        );
+        assert_eq!(compressed_list.get_kind_of(6), None);
+
+        let a: FheUint8 = compressed_list.get(0).unwrap().unwrap();
+        let b: FheInt16 = compressed_list.get(1).unwrap().unwrap();
+        let c: FheBool = compressed_list.get(2).unwrap().unwrap();
+        let d: FheUint64 = compressed_list.get(3).unwrap().unwrap();
+        let e: FheInt8 = compressed_list.get(4).unwrap().unwrap();
+        let f: FheUint16 = compressed_list.get(5).unwrap().unwrap();
+
+        let a: u8 = a.decrypt(&ck);
+        assert_eq!(a, 213);
+        let b: i16 = b.decrypt(&ck);
+        assert_eq!(b, -1234);
+        assert!(c.decrypt(&ck));
+        let d: u64 = d.decrypt(&ck);
+        assert_eq!(d, u64::MAX - 7);
+        let e: i8 = e.decrypt(&ck);
+        assert_eq!(e, i8::MIN);
+        let f: u16 = f.decrypt(&ck);
+        assert_eq!(f, 4242);
+
+        assert!(compressed_list.get::<FheUint8>(6).unwrap().is_none());
+
+        // Mismatches must be reported with an error describing what is stored in the slot
+        let err = compressed_list.get::<FheUint32>(0).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Tried to expand a FheUint32 while a FheUint8 is stored in this slot"
+        );
+        let err = compressed_list.get::<FheUint16>(1).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Tried to expand a FheUint16 while a FheInt16 is stored in this slot"
+        );
+        let err = compressed_list.get::<FheInt8>(2).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Tried to expand a FheInt8 while a FheBool is stored in this slot"
+        );
+        let err = compressed_list.get::<FheBool>(3).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Tried to expand a FheBool while a FheUint64 is stored in this slot"
+        );
+        let err = compressed_list.get::<FheInt16>(4).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Tried to expand a FheInt16 while a FheInt8 is stored in this slot"
+        );
+    }
+
     #[cfg(feature = "strings")]
     #[test]
     fn test_compressed_strings_cpu() {
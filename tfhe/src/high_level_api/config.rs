@@ -5,10 +5,42 @@ use crate::high_level_api::keys::IntegerConfig;
 use crate::shortint::parameters::list_compression::CompressionParameters;
 
 /// The config type
+///
+/// The [CarryPropagationStrategy] is not serialized, deserialized configs use
+/// the default strategy.
 #[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize, Versionize)]
-#[versionize(ConfigVersions)]
+#[versionize(convert = "VersionizableConfig")]
 pub struct Config {
     pub(crate) inner: IntegerConfig,
+    #[serde(skip)]
+    pub(crate) carry_propagation: CarryPropagationStrategy,
+}
+
+/// The versioned content of a [Config]
+#[derive(Copy, Clone, Versionize)]
+#[versionize(ConfigVersions)]
+pub struct VersionizableConfig {
+    pub(crate) inner: IntegerConfig,
+}
+
+impl From<Config> for VersionizableConfig {
+    fn from(value: Config) -> Self {
+        let Config {
+            inner,
+            carry_propagation: _,
+        } = value;
+        Self { inner }
+    }
+}
+
+impl From<VersionizableConfig> for Config {
+    fn from(value: VersionizableConfig) -> Self {
+        let VersionizableConfig { inner } = value;
+        Self {
+            inner,
+            carry_propagation: CarryPropagationStrategy::default(),
+        }
+    }
 }
 
 impl Config {
@@ -20,6 +52,28 @@ impl Config {
     }
 }
 
+/// When carries of the integer types are propagated
+///
+/// Propagating carries is what makes most operations cost PBS, the strategy
+/// allows to trade the number of PBS for noise and carry space, see
+/// [ConfigBuilder::carry_propagation] and
+/// [ServerKey::set_carry_propagation](crate::ServerKey::set_carry_propagation).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CarryPropagationStrategy {
+    /// Carries are propagated after every operation, so results always have empty carries
+    #[default]
+    Eager,
+    /// Carries are only propagated when required, that is when the carry space
+    /// (or noise budget) is exhausted, or when an operation needs empty carries.
+    ///
+    /// This saves PBS for workloads chaining many additions or subtractions.
+    ///
+    /// Only the `+` and `-` operators between two [FheUint](crate::FheUint) on the CPU are
+    /// lazy, other operations (including scalar additions/subtractions and negation)
+    /// propagate carries as with [CarryPropagationStrategy::Eager].
+    Lazy,
+}
+
 /// The builder to create your config
 ///
 /// The configuration is needed to select parameters you wish to use for these types
@@ -36,6 +90,7 @@ impl Default for ConfigBuilder {
         Self {
             config: Config {
                 inner: IntegerConfig::default(),
+                carry_propagation: CarryPropagationStrategy::default(),
             },
         }
     }
@@ -55,6 +110,7 @@ impl ConfigBuilder {
         Self {
             config: Config {
                 inner: IntegerConfig::new(block_parameters.into(), None),
+                carry_propagation: CarryPropagationStrategy::default(),
             },
         }
     }
@@ -71,6 +127,23 @@ impl ConfigBuilder {
         self
     }
 
+    /// Only generate the key material needed by additions
+    ///
+    /// The server key does not contain the keyswitching and bootstrapping keys (nor the
+    /// compression and casting keys), which makes it much smaller.
+    ///
+    /// With such a key, additions and subtractions between [FheUint](crate::FheUint) on the
+    /// CPU never propagate carries, so they are possible as long as the carry space and
+    /// noise budget of the blocks allow it. Other operations require a PBS: fallible
+    /// operations like [FheUint::try_mul](crate::FheUint::try_mul) return an error,
+    /// the others panic.
+    ///
+    /// Requires parameters that encrypt under the big key (KS_PBS).
+    pub fn use_only_additions(mut self) -> Self {
+        self.config.inner.only_additions = true;
+        self
+    }
+
     pub fn use_custom_parameters<P>(mut self, block_parameters: P) -> Self
     where
         P: Into<crate::shortint::PBSParameters>,
@@ -79,6 +152,38 @@ impl ConfigBuilder {
         self
     }
 
+    /// Selects when the carries of [FheUint](crate::FheUint) additions and subtractions
+    /// are propagated
+    ///
+    /// The strategy is set on the server key returned by [generate_keys](crate::generate_keys),
+    /// keys created otherwise use the default strategy, which can be changed with
+    /// [ServerKey::set_carry_propagation](crate::ServerKey::set_carry_propagation).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, CarryPropagationStrategy, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::default()
+    ///     .carry_propagation(CarryPropagationStrategy::Lazy)
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(200u8, &client_key);
+    /// let b = FheUint8::encrypt(100u8, &client_key);
+    /// let c = FheUint8::encrypt(7u8, &client_key);
+    ///
+    /// let result = &(&a + &b) + &c;
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 200u8.wrapping_add(100).wrapping_add(7));
+    /// ```
+    pub fn carry_propagation(mut self, strategy: CarryPropagationStrategy) -> Self {
+        self.config.carry_propagation = strategy;
+        self
+    }
+
     pub fn build(self) -> Config {
         self.config
     }
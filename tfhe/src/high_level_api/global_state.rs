@@ -94,6 +94,23 @@ where
     result
 }
 
+/// Runs `f` with `keys` set as the internal keys of the current thread
+///
+/// The keys the thread had before are restored once `f` returns, this allows
+/// to use the high level API in threads that do not own keys (e.g. rayon's threads)
+pub(in crate::high_level_api) fn with_thread_internal_keys<T, F>(
+    keys: impl Into<InternalServerKey>,
+    f: F,
+) -> T
+where
+    F: FnOnce() -> T,
+{
+    let old = replace_server_key(Some(keys));
+    let result = f();
+    INTERNAL_KEYS.replace(old);
+    result
+}
+
 /// Convenience function that allows to write functions that needs to access the internal keys
 ///
 /// # Panics
@@ -172,6 +189,35 @@ where
     })
 }
 
+/// Calls `func` with the CPU server key, for operations that are only implemented on CPU
+///
+/// If the current key is a Cuda key set from a [HybridServerKey](crate::HybridServerKey),
+/// its CPU key is used, the caller is then responsible for moving the result back to the GPU.
+///
+/// # Panics
+///
+/// Panics if the server key is not set, or if it is a Cuda key without a CPU key
+#[track_caller]
+#[inline]
+#[cfg_attr(not(feature = "gpu"), allow(unused_variables))]
+pub(in crate::high_level_api) fn with_cpu_fallback_keys<T, F>(op_name: &str, func: F) -> T
+where
+    F: FnOnce(&ServerKey) -> T,
+{
+    with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => func(cpu_key),
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(cuda_key) => {
+            let Some(cpu_key) = cuda_key.cpu_fallback.as_ref() else {
+                panic!("Cuda devices do not support {op_name} yet")
+            };
+            #[cfg(feature = "gpu-fallback-log")]
+            log::info!("{op_name} is not implemented on Cuda devices, running it on CPU");
+            func(cpu_key)
+        }
+    })
+}
+
 #[inline]
 #[cfg(feature = "gpu")]
 pub(crate) fn with_cuda_internal_keys<T, F>(func: F) -> T
@@ -0,0 +1,102 @@
+//! Expressions of [FheUint] with chains of clear scalar operations
+
+use crate::high_level_api::global_state;
+#[cfg(feature = "gpu")]
+use crate::high_level_api::global_state::with_thread_local_cuda_streams;
+use crate::high_level_api::integers::FheUintId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::integer::bigint::U2048;
+use crate::FheUint;
+
+/// A [FheUint] with pending clear scalar additions and subtractions
+///
+/// Scalar operations are not computed as they are added to the expression,
+/// consecutive constants are instead folded in the clear into a single net offset,
+/// so that [FheExpr::eval] only does one homomorphic scalar addition
+/// (and none if the offset is zero).
+///
+/// The offset wraps around the same way the operations on the [FheUint] would,
+/// i.e. it is computed modulo `2^N` where `N` is the number of bits of the type.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheExpr, FheUint16};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let x = FheUint16::encrypt(1000u16, &client_key);
+///
+/// // Only a single scalar addition of 6 is done
+/// let result = FheExpr::from(x)
+///     .add_scalar(3)
+///     .add_scalar(5)
+///     .sub_scalar(2)
+///     .eval();
+///
+/// let decrypted: u16 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, 1000 + 3 + 5 - 2);
+/// ```
+#[derive(Clone)]
+pub struct FheExpr<Id: FheUintId> {
+    ciphertext: FheUint<Id>,
+    // 2^2048 is a multiple of 2^N for all the FheUint types,
+    // so wrapping at 2048 bits preserves the wrapping at the type width
+    offset: U2048,
+}
+
+impl<Id: FheUintId> From<FheUint<Id>> for FheExpr<Id> {
+    fn from(ciphertext: FheUint<Id>) -> Self {
+        Self {
+            ciphertext,
+            offset: U2048::ZERO,
+        }
+    }
+}
+
+impl<Id: FheUintId> FheExpr<Id> {
+    /// Adds a clear scalar to the expression, the addition wraps around on overflow
+    pub fn add_scalar(mut self, scalar: u64) -> Self {
+        self.offset += U2048::from(scalar);
+        self
+    }
+
+    /// Subtracts a clear scalar from the expression, the subtraction wraps around on overflow
+    pub fn sub_scalar(mut self, scalar: u64) -> Self {
+        self.offset -= U2048::from(scalar);
+        self
+    }
+
+    /// Computes the expression, with a single homomorphic scalar addition
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offset is not zero and the server key is not set
+    pub fn eval(self) -> FheUint<Id> {
+        let Self { ciphertext, offset } = self;
+        if offset == U2048::ZERO {
+            return ciphertext;
+        }
+
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                // The blocks of the offset above the type width are ignored
+                let inner_result = cpu_key
+                    .pbs_key()
+                    .scalar_add_parallelized(&*ciphertext.ciphertext.on_cpu(), offset);
+                FheUint::new(inner_result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let inner_result = cuda_key.key.key.scalar_add(
+                    &*ciphertext.ciphertext.on_gpu(streams),
+                    offset,
+                    streams,
+                );
+                FheUint::new(inner_result, cuda_key.tag.clone())
+            }),
+        })
+    }
+}
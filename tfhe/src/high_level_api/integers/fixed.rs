@@ -0,0 +1,355 @@
+//! Fixed-point numbers built on top of [FheUint64] and [FheInt64]
+
+use crate::high_level_api::traits::{FheDecrypt, FheTryEncrypt, WideningMul};
+use crate::prelude::CastFrom;
+use crate::{ClientKey, FheInt64, FheUint128, FheUint64};
+use std::ops::{Add, Mul, Sub};
+
+/// An encrypted unsigned fixed-point number, with `FRAC` fractional bits
+///
+/// The number is stored as a [FheUint64] holding the value scaled by `2^FRAC`,
+/// so it can represent values in `[0, 2^(64 - FRAC))` with a resolution of `2^-FRAC`.
+///
+/// * Additions and subtractions are exact and wrap around on overflow, as for [FheUint64].
+/// * Multiplications compute the full 128 bits product before shifting it back, the result is
+///   truncated (rounded towards zero) to `FRAC` fractional bits, and the integer part wraps around
+///   modulo `2^(64 - FRAC)` on overflow.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFix64};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheFix64::<16>::try_encrypt(12.5f64, &client_key).unwrap();
+/// let b = FheFix64::<16>::try_encrypt(0.25f64, &client_key).unwrap();
+///
+/// let c = &(&a * &b) + &a;
+///
+/// let decrypted: f64 = c.decrypt(&client_key);
+/// assert_eq!(decrypted, 12.5 * 0.25 + 12.5);
+/// ```
+#[derive(Clone)]
+pub struct FheFix64<const FRAC: u32> {
+    inner: FheUint64,
+}
+
+impl<const FRAC: u32> FheFix64<FRAC> {
+    const SCALE: f64 = (1u128 << FRAC) as f64;
+
+    /// Creates a fixed-point number from its scaled representation,
+    /// i.e. `raw` encrypts the value multiplied by `2^FRAC`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `FRAC` is greater than 64
+    pub fn from_raw(raw: FheUint64) -> Self {
+        assert!(FRAC <= 64, "FheFix64 can have at most 64 fractional bits");
+        Self { inner: raw }
+    }
+
+    /// Returns the scaled representation of the number
+    pub fn into_raw(self) -> FheUint64 {
+        self.inner
+    }
+}
+
+impl<const FRAC: u32> FheTryEncrypt<f64, ClientKey> for FheFix64<FRAC> {
+    type Error = crate::Error;
+
+    /// Encrypts the value, rounded to the nearest multiple of `2^-FRAC`
+    ///
+    /// Returns an error if the value is not in the representable range.
+    fn try_encrypt(value: f64, key: &ClientKey) -> Result<Self, Self::Error> {
+        let scaled = (value * Self::SCALE).round();
+        if !(0.0..18_446_744_073_709_551_616.0).contains(&scaled) {
+            return Err(crate::error!(
+                "{value} cannot be represented with {FRAC} fractional bits in 64 bits"
+            ));
+        }
+        let inner = FheUint64::try_encrypt(scaled as u64, key)?;
+        Ok(Self::from_raw(inner))
+    }
+}
+
+impl<const FRAC: u32> FheDecrypt<f64> for FheFix64<FRAC> {
+    fn decrypt(&self, key: &ClientKey) -> f64 {
+        let raw: u64 = self.inner.decrypt(key);
+        raw as f64 / Self::SCALE
+    }
+}
+
+impl<const FRAC: u32> FheDecrypt<u64> for FheFix64<FRAC> {
+    /// Decrypts the scaled representation of the number
+    fn decrypt(&self, key: &ClientKey) -> u64 {
+        self.inner.decrypt(key)
+    }
+}
+
+impl<const FRAC: u32> Add<&FheFix64<FRAC>> for &FheFix64<FRAC> {
+    type Output = FheFix64<FRAC>;
+
+    fn add(self, rhs: &FheFix64<FRAC>) -> Self::Output {
+        FheFix64::from_raw(&self.inner + &rhs.inner)
+    }
+}
+
+impl<const FRAC: u32> Sub<&FheFix64<FRAC>> for &FheFix64<FRAC> {
+    type Output = FheFix64<FRAC>;
+
+    fn sub(self, rhs: &FheFix64<FRAC>) -> Self::Output {
+        FheFix64::from_raw(&self.inner - &rhs.inner)
+    }
+}
+
+impl<const FRAC: u32> Mul<&FheFix64<FRAC>> for &FheFix64<FRAC> {
+    type Output = FheFix64<FRAC>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: &FheFix64<FRAC>) -> Self::Output {
+        // The product of the scaled values is scaled by 2^(2 * FRAC)
+        let product: FheUint128 = (&self.inner).widening_mul(&rhs.inner);
+        let product = &product >> FRAC;
+        FheFix64::from_raw(FheUint64::cast_from(product))
+    }
+}
+
+/// An encrypted signed fixed-point number, with `FRAC` fractional bits
+///
+/// The number is stored as a [FheInt64] holding the value scaled by `2^FRAC`,
+/// so it can represent values in `[-2^(63 - FRAC), 2^(63 - FRAC))` with a resolution of
+/// `2^-FRAC`.
+///
+/// * Additions and subtractions are exact and wrap around on overflow, as for [FheInt64].
+/// * Multiplications compute the 128 bits product (as an unsigned widening multiplication of the 64
+///   bits values, corrected for their signs) before shifting it back, the result is rounded towards
+///   negative infinity to `FRAC` fractional bits, and wraps around on overflow.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheIFix64};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheIFix64::<16>::try_encrypt(-12.5f64, &client_key).unwrap();
+/// let b = FheIFix64::<16>::try_encrypt(0.25f64, &client_key).unwrap();
+///
+/// let c = &(&a * &b) + &a;
+///
+/// let decrypted: f64 = c.decrypt(&client_key);
+/// assert_eq!(decrypted, -12.5 * 0.25 - 12.5);
+/// ```
+#[derive(Clone)]
+pub struct FheIFix64<const FRAC: u32> {
+    inner: FheInt64,
+}
+
+impl<const FRAC: u32> FheIFix64<FRAC> {
+    const SCALE: f64 = (1u128 << FRAC) as f64;
+
+    /// Creates a fixed-point number from its scaled representation,
+    /// i.e. `raw` encrypts the value multiplied by `2^FRAC`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `FRAC` is greater than 63
+    pub fn from_raw(raw: FheInt64) -> Self {
+        assert!(FRAC <= 63, "FheIFix64 can have at most 63 fractional bits");
+        Self { inner: raw }
+    }
+
+    /// Returns the scaled representation of the number
+    pub fn into_raw(self) -> FheInt64 {
+        self.inner
+    }
+}
+
+impl<const FRAC: u32> FheTryEncrypt<f64, ClientKey> for FheIFix64<FRAC> {
+    type Error = crate::Error;
+
+    /// Encrypts the value, rounded to the nearest multiple of `2^-FRAC`
+    ///
+    /// Returns an error if the value is not in the representable range.
+    fn try_encrypt(value: f64, key: &ClientKey) -> Result<Self, Self::Error> {
+        let scaled = (value * Self::SCALE).round();
+        if !(-9_223_372_036_854_775_808.0..9_223_372_036_854_775_808.0).contains(&scaled) {
+            return Err(crate::error!(
+                "{value} cannot be represented with {FRAC} fractional bits in 64 bits"
+            ));
+        }
+        let inner = FheInt64::try_encrypt(scaled as i64, key)?;
+        Ok(Self::from_raw(inner))
+    }
+}
+
+impl<const FRAC: u32> FheDecrypt<f64> for FheIFix64<FRAC> {
+    fn decrypt(&self, key: &ClientKey) -> f64 {
+        let raw: i64 = self.inner.decrypt(key);
+        raw as f64 / Self::SCALE
+    }
+}
+
+impl<const FRAC: u32> FheDecrypt<i64> for FheIFix64<FRAC> {
+    /// Decrypts the scaled representation of the number
+    fn decrypt(&self, key: &ClientKey) -> i64 {
+        self.inner.decrypt(key)
+    }
+}
+
+impl<const FRAC: u32> Add<&FheIFix64<FRAC>> for &FheIFix64<FRAC> {
+    type Output = FheIFix64<FRAC>;
+
+    fn add(self, rhs: &FheIFix64<FRAC>) -> Self::Output {
+        FheIFix64::from_raw(&self.inner + &rhs.inner)
+    }
+}
+
+impl<const FRAC: u32> Sub<&FheIFix64<FRAC>> for &FheIFix64<FRAC> {
+    type Output = FheIFix64<FRAC>;
+
+    fn sub(self, rhs: &FheIFix64<FRAC>) -> Self::Output {
+        FheIFix64::from_raw(&self.inner - &rhs.inner)
+    }
+}
+
+impl<const FRAC: u32> Mul<&FheIFix64<FRAC>> for &FheIFix64<FRAC> {
+    type Output = FheIFix64<FRAC>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: &FheIFix64<FRAC>) -> Self::Output {
+        // The operands are multiplied as unsigned values, which is cheaper than a product of
+        // sign extended 128 bits values. A negative operand is seen as its value plus 2^64,
+        // so for each negative operand, the other one times 2^64 is removed from the product.
+        let (lhs, rhs_inner) = (&self.inner, &rhs.inner);
+        let unsigned_lhs = FheUint64::cast_from(lhs.clone());
+        let unsigned_rhs = FheUint64::cast_from(rhs_inner.clone());
+        // All ones if the operand is negative, zero otherwise
+        let lhs_sign_mask = FheUint64::cast_from(lhs >> 63u32);
+        let rhs_sign_mask = FheUint64::cast_from(rhs_inner >> 63u32);
+
+        let correction = &(&unsigned_rhs & &lhs_sign_mask) + &(&unsigned_lhs & &rhs_sign_mask);
+        let correction = &FheUint128::cast_from(correction) << 64u32;
+
+        // The product of the scaled values is scaled by 2^(2 * FRAC),
+        // its low 128 bits are the two's complement of the signed product
+        let product: FheUint128 = (&unsigned_lhs).widening_mul(&unsigned_rhs);
+        let product = &(&product - &correction) >> FRAC;
+        FheIFix64::from_raw(FheInt64::cast_from(product))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FheFix64, FheIFix64};
+    use crate::high_level_api::tests::setup_default_cpu;
+    use crate::prelude::*;
+    use rand::{thread_rng, Rng};
+
+    const FRAC: u32 = 16;
+
+    #[test]
+    fn test_fix64_add_mul() {
+        let cks = setup_default_cpu();
+        let mut rng = thread_rng();
+        let resolution = 1.0 / (1u64 << FRAC) as f64;
+
+        for _ in 0..3 {
+            // Keep the product in range
+            let clear_a = rng.gen_range(0.0..1_000_000.0f64);
+            let clear_b = rng.gen_range(0.0..1_000.0f64);
+
+            let a = FheFix64::<FRAC>::try_encrypt(clear_a, &cks).unwrap();
+            let b = FheFix64::<FRAC>::try_encrypt(clear_b, &cks).unwrap();
+
+            // Reference fixed-point arithmetic on the scaled values
+            let raw_a: u64 = a.decrypt(&cks);
+            let raw_b: u64 = b.decrypt(&cks);
+
+            let sum = &a + &b;
+            let raw_sum: u64 = sum.decrypt(&cks);
+            assert_eq!(raw_sum, raw_a.wrapping_add(raw_b));
+            let decrypted: f64 = sum.decrypt(&cks);
+            assert!((decrypted - (clear_a + clear_b)).abs() <= resolution);
+
+            let product = &a * &b;
+            let raw_product: u64 = product.decrypt(&cks);
+            assert_eq!(
+                raw_product,
+                ((raw_a as u128 * raw_b as u128) >> FRAC) as u64
+            );
+            let decrypted: f64 = product.decrypt(&cks);
+            // Rounding of the inputs is amplified by the other operand
+            let tolerance = resolution * (1.0 + clear_a + clear_b);
+            assert!(
+                (decrypted - clear_a * clear_b).abs() <= tolerance,
+                "{decrypted} != {clear_a} * {clear_b}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_fix64_encrypt_out_of_range() {
+        let cks = setup_default_cpu();
+
+        assert!(FheFix64::<FRAC>::try_encrypt(-1.0, &cks).is_err());
+        assert!(FheFix64::<FRAC>::try_encrypt(2f64.powi(64 - FRAC as i32), &cks).is_err());
+        assert!(FheFix64::<FRAC>::try_encrypt(f64::NAN, &cks).is_err());
+    }
+
+    #[test]
+    fn test_ifix64_add_mul() {
+        let cks = setup_default_cpu();
+        let mut rng = thread_rng();
+        let resolution = 1.0 / (1u64 << FRAC) as f64;
+
+        for _ in 0..3 {
+            // Keep the product in range
+            let clear_a = rng.gen_range(-1_000_000.0..1_000_000.0f64);
+            let clear_b = rng.gen_range(-1_000.0..1_000.0f64);
+
+            let a = FheIFix64::<FRAC>::try_encrypt(clear_a, &cks).unwrap();
+            let b = FheIFix64::<FRAC>::try_encrypt(clear_b, &cks).unwrap();
+
+            // Reference fixed-point arithmetic on the scaled values
+            let raw_a: i64 = a.decrypt(&cks);
+            let raw_b: i64 = b.decrypt(&cks);
+
+            let difference = &a - &b;
+            let raw_difference: i64 = difference.decrypt(&cks);
+            assert_eq!(raw_difference, raw_a.wrapping_sub(raw_b));
+            let decrypted: f64 = difference.decrypt(&cks);
+            assert!((decrypted - (clear_a - clear_b)).abs() <= resolution);
+
+            let product = &a * &b;
+            let raw_product: i64 = product.decrypt(&cks);
+            assert_eq!(
+                raw_product,
+                ((raw_a as i128 * raw_b as i128) >> FRAC) as i64
+            );
+            let decrypted: f64 = product.decrypt(&cks);
+            // Rounding of the inputs is amplified by the other operand
+            let tolerance = resolution * (1.0 + clear_a.abs() + clear_b.abs());
+            assert!(
+                (decrypted - clear_a * clear_b).abs() <= tolerance,
+                "{decrypted} != {clear_a} * {clear_b}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ifix64_encrypt_out_of_range() {
+        let cks = setup_default_cpu();
+
+        let bound = 2f64.powi(63 - FRAC as i32);
+        assert!(FheIFix64::<FRAC>::try_encrypt(-bound, &cks).is_ok());
+        assert!(FheIFix64::<FRAC>::try_encrypt(bound, &cks).is_err());
+        assert!(FheIFix64::<FRAC>::try_encrypt(-bound - 1.0, &cks).is_err());
+        assert!(FheIFix64::<FRAC>::try_encrypt(f64::NAN, &cks).is_err());
+    }
+}
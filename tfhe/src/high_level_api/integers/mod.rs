@@ -23,9 +23,13 @@ pub(in crate::high_level_api) use unsigned::{
 // These are pub-exported so that their doc can appear in generated rust docs
 use crate::high_level_api::traits::FheId;
 use crate::shortint::MessageModulus;
+pub use expr::FheExpr;
+pub use fixed::{FheFix64, FheIFix64};
 pub use signed::{CompressedFheInt, FheInt};
 pub use unsigned::{CompressedFheUint, FheUint};
 
+pub(super) mod expr;
+pub(super) mod fixed;
 pub mod oprf;
 pub(super) mod signed;
 pub(super) mod unsigned;
@@ -6,7 +6,7 @@ use crate::conformance::ParameterSetConformant;
 use crate::high_level_api::global_state;
 use crate::high_level_api::integers::{FheUint, FheUintId, IntegerId};
 use crate::high_level_api::keys::InternalServerKey;
-use crate::high_level_api::traits::Tagged;
+use crate::high_level_api::traits::{FheMax, FheMin, Tagged};
 use crate::integer::client_key::RecomposableSignedInteger;
 use crate::integer::parameters::RadixCiphertextConformanceParams;
 use crate::named::Named;
@@ -136,6 +136,28 @@ where
         }
     }
 
+    /// Reinterprets the bits of `self` as an unsigned integer of the same width
+    ///
+    /// The blocks are moved as is, no PBS or re-encryption is done.
+    pub(crate) fn reinterpret_bits<IntoId>(self) -> FheUint<IntoId>
+    where
+        IntoId: FheUintId,
+    {
+        assert_eq!(Id::num_bits(), IntoId::num_bits());
+        match self.ciphertext {
+            RadixCiphertext::Cpu(ct) => {
+                FheUint::new(crate::integer::RadixCiphertext::from(ct.blocks), self.tag)
+            }
+            #[cfg(feature = "gpu")]
+            RadixCiphertext::Cuda(ct) => FheUint::new(
+                crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext {
+                    ciphertext: ct.ciphertext,
+                },
+                self.tag,
+            ),
+        }
+    }
+
     pub fn num_bits() -> usize {
         Id::num_bits()
     }
@@ -147,6 +169,15 @@ where
         self.ciphertext.move_to_device(device)
     }
 
+    /// Moves (in-place) the ciphertext to the device of the current
+    /// thread-local server key
+    ///
+    /// Does nothing if the ciphertext is already in the desired device
+    /// or if no server key is set
+    pub fn move_to_current_device(&mut self) {
+        self.ciphertext.move_to_device_of_server_key_if_set();
+    }
+
     /// Returns the device where the ciphertext is currently on
     pub fn current_device(&self) -> Device {
         self.ciphertext.current_device()
@@ -192,6 +223,44 @@ where
         })
     }
 
+    /// Returns the sign of the value
+    ///
+    /// - `-1` if the value is negative
+    /// - `0` if the value is zero
+    /// - `1` if the value is positive
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-3i16, &client_key);
+    /// let result: i16 = a.signum().decrypt(&client_key);
+    /// assert_eq!(result, -1);
+    ///
+    /// let a = FheInt16::encrypt(0i16, &client_key);
+    /// let result: i16 = a.signum().decrypt(&client_key);
+    /// assert_eq!(result, 0);
+    ///
+    /// let a = FheInt16::encrypt(3i16, &client_key);
+    /// let result: i16 = a.signum().decrypt(&client_key);
+    /// assert_eq!(result, 1);
+    /// ```
+    pub fn signum(&self) -> Self {
+        let mut result = global_state::with_cpu_fallback_keys("signum", |cpu_key| {
+            let ciphertext = cpu_key
+                .pbs_key()
+                .signum_parallelized(&*self.ciphertext.on_cpu());
+            Self::new(ciphertext, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
+    }
+
     /// Returns a FheBool that encrypts `true` if the value is even
     ///
     /// # Example
@@ -463,22 +532,18 @@ where
     /// assert_eq!(decrypted, clear_a.count_ones());
     /// ```
     pub fn count_ones(&self) -> crate::FheUint32 {
-        global_state::with_internal_keys(|key| match key {
-            InternalServerKey::Cpu(cpu_key) => {
-                let result = cpu_key
-                    .pbs_key()
-                    .count_ones_parallelized(&*self.ciphertext.on_cpu());
-                let result = cpu_key.pbs_key().cast_to_unsigned(
-                    result,
-                    crate::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
-                );
-                crate::FheUint32::new(result, cpu_key.tag.clone())
-            }
-            #[cfg(feature = "gpu")]
-            InternalServerKey::Cuda(_) => {
-                panic!("Cuda devices do not support count_ones yet");
-            }
-        })
+        let mut result = global_state::with_cpu_fallback_keys("count_ones", |cpu_key| {
+            let result = cpu_key
+                .pbs_key()
+                .count_ones_parallelized(&*self.ciphertext.on_cpu());
+            let result = cpu_key.pbs_key().cast_to_unsigned(
+                result,
+                crate::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
+            );
+            crate::FheUint32::new(result, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
     }
 
     /// Returns the number of zeros in the binary representation of self.
@@ -500,22 +565,18 @@ where
     /// assert_eq!(decrypted, clear_a.count_zeros());
     /// ```
     pub fn count_zeros(&self) -> crate::FheUint32 {
-        global_state::with_internal_keys(|key| match key {
-            InternalServerKey::Cpu(cpu_key) => {
-                let result = cpu_key
-                    .pbs_key()
-                    .count_zeros_parallelized(&*self.ciphertext.on_cpu());
-                let result = cpu_key.pbs_key().cast_to_unsigned(
-                    result,
-                    crate::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
-                );
-                crate::FheUint32::new(result, cpu_key.tag.clone())
-            }
-            #[cfg(feature = "gpu")]
-            InternalServerKey::Cuda(_) => {
-                panic!("Cuda devices do not support count_zeros yet");
-            }
-        })
+        let mut result = global_state::with_cpu_fallback_keys("count_zeros", |cpu_key| {
+            let result = cpu_key
+                .pbs_key()
+                .count_zeros_parallelized(&*self.ciphertext.on_cpu());
+            let result = cpu_key.pbs_key().cast_to_unsigned(
+                result,
+                crate::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
+            );
+            crate::FheUint32::new(result, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
     }
 
     /// Returns the base 2 logarithm of the number, rounded down.
@@ -681,19 +742,165 @@ where
     /// assert_eq!(decrypted, msg.reverse_bits());
     /// ```
     pub fn reverse_bits(&self) -> Self {
-        global_state::with_internal_keys(|key| match key {
-            InternalServerKey::Cpu(cpu_key) => {
-                let sk = &cpu_key.pbs_key();
+        let mut result = global_state::with_cpu_fallback_keys("reverse_bits", |cpu_key| {
+            let sk = &cpu_key.pbs_key();
 
-                let ct = self.ciphertext.on_cpu();
+            let ct = self.ciphertext.on_cpu();
 
-                Self::new(sk.reverse_bits_parallelized(&*ct), cpu_key.tag.clone())
-            }
-            #[cfg(feature = "gpu")]
-            InternalServerKey::Cuda(_) => {
-                panic!("Cuda devices do not support reverse yet");
-            }
-        })
+            Self::new(sk.reverse_bits_parallelized(&*ct), cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
+    }
+
+    /// Restricts the value to the interval `[min, max]`
+    ///
+    /// Returns `max` if `self` is greater than `max`, `min` if `self` is less than `min`,
+    /// and `self` otherwise.
+    ///
+    /// This is computed as `min(max(self, min), max)`.
+    ///
+    /// # Note
+    ///
+    /// Unlike Rust's `clamp`, this cannot panic when `min > max`
+    /// as the bounds are encrypted, in that case the returned value is `max`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let min = FheInt16::encrypt(-10i16, &client_key);
+    /// let max = FheInt16::encrypt(20i16, &client_key);
+    ///
+    /// let a = FheInt16::encrypt(-15i16, &client_key);
+    /// let result: i16 = a.clamp(&min, &max).decrypt(&client_key);
+    /// assert_eq!(result, -10i16);
+    ///
+    /// let a = FheInt16::encrypt(15i16, &client_key);
+    /// let result: i16 = a.clamp(&min, &max).decrypt(&client_key);
+    /// assert_eq!(result, 15i16);
+    ///
+    /// let a = FheInt16::encrypt(25i16, &client_key);
+    /// let result: i16 = a.clamp(&min, &max).decrypt(&client_key);
+    /// assert_eq!(result, 20i16);
+    /// ```
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        FheMin::min(&FheMax::max(self, min), max)
+    }
+
+    /// Computes the euclidean quotient and remainder of the division
+    ///
+    /// The results match Rust's [i16::div_euclid] and [i16::rem_euclid],
+    /// the remainder is always positive.
+    ///
+    /// If you only need one of the results, use [Self::div_euclid] or [Self::rem_euclid].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-7i16, &client_key);
+    /// let b = FheInt16::encrypt(3i16, &client_key);
+    ///
+    /// let (q, r) = a.div_rem_euclid(&b);
+    /// let q: i16 = q.decrypt(&client_key);
+    /// let r: i16 = r.decrypt(&client_key);
+    /// assert_eq!(q, (-7i16).div_euclid(3));
+    /// assert_eq!(r, (-7i16).rem_euclid(3));
+    /// ```
+    pub fn div_rem_euclid(&self, divisor: &Self) -> (Self, Self) {
+        let (mut q, mut r) = global_state::with_cpu_fallback_keys("div_rem_euclid", |cpu_key| {
+            let (q, r) = cpu_key.pbs_key().div_rem_euclid_parallelized(
+                &self.ciphertext.on_cpu(),
+                &divisor.ciphertext.on_cpu(),
+            );
+            (
+                Self::new(q, cpu_key.tag.clone()),
+                Self::new(r, cpu_key.tag.clone()),
+            )
+        });
+        q.move_to_current_device();
+        r.move_to_current_device();
+        (q, r)
+    }
+
+    /// Computes the euclidean quotient of the division, see [Self::div_rem_euclid]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(7i16, &client_key);
+    /// let b = FheInt16::encrypt(-3i16, &client_key);
+    ///
+    /// let result: i16 = a.div_euclid(&b).decrypt(&client_key);
+    /// assert_eq!(result, 7i16.div_euclid(-3));
+    /// ```
+    pub fn div_euclid(&self, divisor: &Self) -> Self {
+        self.div_rem_euclid(divisor).0
+    }
+
+    /// Computes the euclidean remainder of the division, see [Self::div_rem_euclid]
+    ///
+    /// The result is always positive, i.e. in `[0, |divisor|)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-7i16, &client_key);
+    /// let b = FheInt16::encrypt(-3i16, &client_key);
+    ///
+    /// let result: i16 = a.rem_euclid(&b).decrypt(&client_key);
+    /// assert_eq!(result, (-7i16).rem_euclid(-3));
+    /// ```
+    pub fn rem_euclid(&self, divisor: &Self) -> Self {
+        self.div_rem_euclid(divisor).1
+    }
+
+    /// Returns the number of bytes `bincode` would produce when serializing `self`
+    ///
+    /// The size is computed without serializing into a buffer, which makes it
+    /// cheap to use to preallocate buffers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, _server_key) = generate_keys(ConfigBuilder::default());
+    ///
+    /// let a = FheInt16::encrypt(-12345i16, &client_key);
+    ///
+    /// let serialized = bincode::serialize(&a).unwrap();
+    /// assert_eq!(a.serialized_size(), serialized.len());
+    /// ```
+    pub fn serialized_size(&self) -> usize
+    where
+        Self: serde::Serialize,
+    {
+        bincode::serialized_size(self).unwrap() as usize
     }
 }
 
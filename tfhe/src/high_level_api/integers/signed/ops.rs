@@ -47,30 +47,47 @@ where
     /// assert_eq!(decrypted, clears.into_iter().sum::<i16>());
     /// ```
     fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
-        global_state::with_internal_keys(|key| match key {
-            InternalServerKey::Cpu(cpu_key) => {
-                let ciphertexts = iter
-                    .map(|elem| elem.ciphertext.on_cpu().to_owned())
-                    .collect::<Vec<_>>();
-                cpu_key
-                    .pbs_key()
-                    .sum_ciphertexts_parallelized(ciphertexts.iter())
-                    .map_or_else(
-                        || {
-                            let radix: crate::integer::SignedRadixCiphertext =
-                                cpu_key.pbs_key().create_trivial_zero_radix(Id::num_blocks(
-                                    cpu_key.message_modulus(),
-                                ));
-                            Self::new(radix, cpu_key.tag.clone())
-                        },
-                        |ct| Self::new(ct, cpu_key.tag.clone()),
-                    )
-            }
-            #[cfg(feature = "gpu")]
-            InternalServerKey::Cuda(_) => {
-                panic!("Cuda devices do not support sum of signed integers");
-            }
-        })
+        let mut result = global_state::with_cpu_fallback_keys("sum", |cpu_key| {
+            let ciphertexts = iter
+                .map(|elem| elem.ciphertext.on_cpu().to_owned())
+                .collect::<Vec<_>>();
+            cpu_key
+                .pbs_key()
+                .sum_ciphertexts_parallelized(ciphertexts.iter())
+                .map_or_else(
+                    || {
+                        let radix: crate::integer::SignedRadixCiphertext = cpu_key
+                            .pbs_key()
+                            .create_trivial_zero_radix(Id::num_blocks(cpu_key.message_modulus()));
+                        Self::new(radix, cpu_key.tag.clone())
+                    },
+                    |ct| Self::new(ct, cpu_key.tag.clone()),
+                )
+        });
+        result.move_to_current_device();
+        result
+    }
+}
+
+impl<Id> FheMax<Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    fn max(&self, rhs: Self) -> Self::Output {
+        self.max(&rhs)
+    }
+}
+
+impl<Id> FheMin<Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    fn min(&self, rhs: Self) -> Self::Output {
+        self.min(&rhs)
     }
 }
 
@@ -126,7 +143,7 @@ where
 {
     type Output = Self;
 
-    /// Returns the max between two [FheInt]
+    /// Returns the min between two [FheInt]
     ///
     /// # Example
     ///
@@ -1473,18 +1490,12 @@ where
     /// ```
     fn div_assign(&mut self, rhs: I) {
         let rhs = rhs.borrow();
-        global_state::with_internal_keys(|key| match key {
-            InternalServerKey::Cpu(cpu_key) => {
-                cpu_key.pbs_key().div_assign_parallelized(
-                    self.ciphertext.as_cpu_mut(),
-                    &*rhs.ciphertext.on_cpu(),
-                );
-            }
-            #[cfg(feature = "gpu")]
-            InternalServerKey::Cuda(_) => {
-                panic!("Cuda devices do not support division");
-            }
-        })
+        global_state::with_cpu_fallback_keys("div_assign", |cpu_key| {
+            cpu_key
+                .pbs_key()
+                .div_assign_parallelized(self.ciphertext.as_cpu_mut(), &*rhs.ciphertext.on_cpu());
+        });
+        self.move_to_current_device();
     }
 }
 impl<Id, I> RemAssign<I> for FheInt<Id>
@@ -1517,18 +1528,12 @@ where
     /// ```
     fn rem_assign(&mut self, rhs: I) {
         let rhs = rhs.borrow();
-        global_state::with_internal_keys(|key| match key {
-            InternalServerKey::Cpu(cpu_key) => {
-                cpu_key.pbs_key().rem_assign_parallelized(
-                    self.ciphertext.as_cpu_mut(),
-                    &*rhs.ciphertext.on_cpu(),
-                );
-            }
-            #[cfg(feature = "gpu")]
-            InternalServerKey::Cuda(_) => {
-                panic!("Cuda devices do not support remainder");
-            }
-        })
+        global_state::with_cpu_fallback_keys("rem_assign", |cpu_key| {
+            cpu_key
+                .pbs_key()
+                .rem_assign_parallelized(self.ciphertext.as_cpu_mut(), &*rhs.ciphertext.on_cpu());
+        });
+        self.move_to_current_device();
     }
 }
 
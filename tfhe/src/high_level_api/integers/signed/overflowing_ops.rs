@@ -5,7 +5,9 @@ use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::FheIntId;
 use crate::high_level_api::keys::InternalServerKey;
 use crate::integer::block_decomposition::DecomposableInto;
-use crate::prelude::{OverflowingAdd, OverflowingMul, OverflowingSub};
+use crate::prelude::{
+    OverflowingAdd, OverflowingMul, OverflowingSub, SaturatingAdd, SaturatingSub,
+};
 use crate::{FheBool, FheInt};
 
 impl<Id> OverflowingAdd<Self> for &FheInt<Id>
@@ -18,6 +20,8 @@ where
     ///
     /// * The operation is modular, i.e. on overflow the result wraps around.
     /// * On overflow the [FheBool] is true, otherwise false
+    /// * Overflow means the mathematical result does not fit in the signed range of the type, it is
+    ///   not the carry-out of the underlying unsigned operation
     ///
     /// # Example
     ///
@@ -78,6 +82,8 @@ where
     ///
     /// * The operation is modular, i.e. on overflow the result wraps around.
     /// * On overflow the [FheBool] is true, otherwise false
+    /// * Overflow means the mathematical result does not fit in the signed range of the type, it is
+    ///   not the carry-out of the underlying unsigned operation
     ///
     /// # Example
     ///
@@ -250,6 +256,8 @@ where
     ///
     /// * The operation is modular, i.e. on overflow the result wraps around.
     /// * On overflow the [FheBool] is true, otherwise false
+    /// * Overflow means the mathematical result does not fit in the signed range of the type, it is
+    ///   not the carry-out of the underlying unsigned operation
     ///
     /// # Example
     ///
@@ -308,6 +316,8 @@ where
     ///
     /// * The operation is modular, i.e. on overflow the result wraps around.
     /// * On overflow the [FheBool] is true, otherwise false
+    /// * Overflow means the mathematical result does not fit in the signed range of the type, it is
+    ///   not the carry-out of the underlying unsigned operation
     ///
     /// # Example
     ///
@@ -515,3 +525,100 @@ where
         <&Self as OverflowingMul<&Self>>::overflowing_mul(&self, other)
     }
 }
+
+impl<Id> SaturatingAdd<Self> for &FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = FheInt<Id>;
+
+    /// Adds two [FheInt], saturating at the min or max value of the type instead of wrapping around
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt8::encrypt(100i8, &client_key);
+    /// let b = FheInt8::encrypt(100i8, &client_key);
+    ///
+    /// let result = (&a).saturating_add(&b);
+    /// let result: i8 = result.decrypt(&client_key);
+    /// assert_eq!(result, i8::MAX);
+    /// ```
+    fn saturating_add(self, other: Self) -> Self::Output {
+        let mut result = global_state::with_cpu_fallback_keys("saturating_add", |cpu_key| {
+            let inner_result = cpu_key.pbs_key().signed_saturating_add_parallelized(
+                &self.ciphertext.on_cpu(),
+                &other.ciphertext.on_cpu(),
+            );
+            FheInt::new(inner_result, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
+    }
+}
+
+impl<Id> SaturatingAdd<&Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    fn saturating_add(self, other: &Self) -> Self::Output {
+        <&Self as SaturatingAdd<&Self>>::saturating_add(&self, other)
+    }
+}
+
+impl<Id> SaturatingSub<Self> for &FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = FheInt<Id>;
+
+    /// Subtracts two [FheInt], saturating at the min or max value of the type instead of wrapping
+    /// around
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt8::encrypt(-100i8, &client_key);
+    /// let b = FheInt8::encrypt(100i8, &client_key);
+    ///
+    /// let result = (&a).saturating_sub(&b);
+    /// let result: i8 = result.decrypt(&client_key);
+    /// assert_eq!(result, i8::MIN);
+    /// ```
+    fn saturating_sub(self, other: Self) -> Self::Output {
+        let mut result = global_state::with_cpu_fallback_keys("saturating_sub", |cpu_key| {
+            let inner_result = cpu_key.pbs_key().signed_saturating_sub_parallelized(
+                &self.ciphertext.on_cpu(),
+                &other.ciphertext.on_cpu(),
+            );
+            FheInt::new(inner_result, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
+    }
+}
+
+impl<Id> SaturatingSub<&Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    fn saturating_sub(self, other: &Self) -> Self::Output {
+        <&Self as SaturatingSub<&Self>>::saturating_sub(&self, other)
+    }
+}
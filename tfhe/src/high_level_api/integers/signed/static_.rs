@@ -43,6 +43,26 @@ macro_rules! static_int_type {
 
             // Conformance Params
             pub type [<FheInt $num_bits ConformanceParams>] = FheIntConformanceParams<[<FheInt $num_bits Id>]>;
+
+            impl [<FheInt $num_bits>] {
+                #[doc = concat!("Reinterprets the bits as a [FheUint", stringify!($num_bits), "](crate::FheUint", stringify!($num_bits), "),")]
+                #[doc = "this is the homomorphic equivalent of `i32 as u32` for native integers."]
+                #[doc = ""]
+                #[doc = "The encrypted blocks are kept as is, no PBS or re-encryption is done."]
+                pub fn reinterpret_as_unsigned(self) -> crate::[<FheUint $num_bits>] {
+                    self.reinterpret_bits()
+                }
+            }
+
+            impl crate::[<FheUint $num_bits>] {
+                #[doc = concat!("Reinterprets the bits as a [FheInt", stringify!($num_bits), "](crate::FheInt", stringify!($num_bits), "),")]
+                #[doc = "this is the homomorphic equivalent of `u32 as i32` for native integers."]
+                #[doc = ""]
+                #[doc = "The encrypted blocks are kept as is, no PBS or re-encryption is done."]
+                pub fn reinterpret_as_signed(self) -> [<FheInt $num_bits>] {
+                    self.reinterpret_bits()
+                }
+            }
         }
     };
 }
@@ -6,7 +6,7 @@ use crate::shortint::parameters::*;
 use crate::{
     generate_keys, set_server_key, ClientKey, CompactCiphertextList, CompactPublicKey,
     CompressedFheInt16, CompressedFheInt32, Config, ConfigBuilder, FheInt16, FheInt256, FheInt32,
-    FheInt32ConformanceParams, FheInt64, FheInt8, FheUint64, FheUint8,
+    FheInt32ConformanceParams, FheInt64, FheInt8, FheUint32, FheUint64, FheUint8,
 };
 use rand::prelude::*;
 
@@ -482,6 +482,67 @@ fn test_if_then_else() {
     );
 }
 
+#[test]
+fn test_min_max() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    for (clear_a, clear_b) in [
+        (rng.gen_range(i8::MIN..0), rng.gen_range(0..=i8::MAX)),
+        (rng.gen_range(0..=i8::MAX), rng.gen_range(i8::MIN..0)),
+        (rng.gen_range(i8::MIN..0), rng.gen_range(i8::MIN..0)),
+    ] {
+        let a = FheInt8::encrypt(clear_a, &client_key);
+        let b = FheInt8::encrypt(clear_b, &client_key);
+
+        let min: i8 = a.min(&b).decrypt(&client_key);
+        assert_eq!(min, std::cmp::min(clear_a, clear_b));
+        let max: i8 = a.max(&b).decrypt(&client_key);
+        assert_eq!(max, std::cmp::max(clear_a, clear_b));
+
+        let min: i8 = FheInt8::min(&a, b.clone()).decrypt(&client_key);
+        assert_eq!(min, std::cmp::min(clear_a, clear_b));
+        let max: i8 = FheInt8::max(&a, b).decrypt(&client_key);
+        assert_eq!(max, std::cmp::max(clear_a, clear_b));
+    }
+}
+
+#[test]
+fn test_clamp() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_min = -50i8;
+    let clear_max = 100i8;
+
+    let min = FheInt8::encrypt(clear_min, &client_key);
+    let max = FheInt8::encrypt(clear_max, &client_key);
+
+    // below, in-range, above
+    for clear in [-120i8, 0, 120] {
+        let a = FheInt8::encrypt(clear, &client_key);
+        let result: i8 = a.clamp(&min, &max).decrypt(&client_key);
+        assert_eq!(
+            result,
+            clear.clamp(clear_min, clear_max),
+            "Invalid result for {clear}.clamp({clear_min}, {clear_max})"
+        );
+    }
+
+    // min > max returns max
+    let a = FheInt8::encrypt(0i8, &client_key);
+    let result: i8 = a.clamp(&max, &min).decrypt(&client_key);
+    assert_eq!(result, clear_min);
+}
+
 #[test]
 fn test_abs() {
     let config = ConfigBuilder::default().build();
@@ -498,6 +559,224 @@ fn test_abs() {
         let decrypted_result: i64 = abs_a.decrypt(&client_key);
         assert_eq!(decrypted_result, clear.abs());
     }
+
+    for clear in [0i64, i64::MIN] {
+        let a = FheInt64::encrypt(clear, &client_key);
+        let abs_a = a.abs();
+        let decrypted_result: i64 = abs_a.decrypt(&client_key);
+        assert_eq!(decrypted_result, clear.wrapping_abs());
+    }
+}
+
+#[test]
+fn test_signum() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    for clear in [
+        rng.gen_range(i64::MIN..0),
+        0,
+        rng.gen_range(1..=i64::MAX),
+        i64::MIN,
+    ] {
+        let a = FheInt64::encrypt(clear, &client_key);
+        let signum_a = a.signum();
+        let decrypted_result: i64 = signum_a.decrypt(&client_key);
+        assert_eq!(decrypted_result, clear.signum());
+    }
+}
+
+#[test]
+fn test_overflowing_add_sub() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    let cases = [
+        (i64::MAX, 1i64),
+        (i64::MIN, 1),
+        (i64::MIN, -1),
+        (0, i64::MIN),
+        (rng.gen(), rng.gen()),
+    ];
+
+    for (clear_a, clear_b) in cases {
+        let a = FheInt64::encrypt(clear_a, &client_key);
+        let b = FheInt64::encrypt(clear_b, &client_key);
+
+        let (result, overflowed) = (&a).overflowing_add(&b);
+        let (expected_result, expected_overflowed) = clear_a.overflowing_add(clear_b);
+        let decrypted_result: i64 = result.decrypt(&client_key);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear_a}.overflowing_add({clear_b})"
+        );
+        assert_eq!(
+            overflowed.decrypt(&client_key),
+            expected_overflowed,
+            "Invalid overflow flag for {clear_a}.overflowing_add({clear_b})"
+        );
+
+        let (result, overflowed) = (&a).overflowing_sub(&b);
+        let (expected_result, expected_overflowed) = clear_a.overflowing_sub(clear_b);
+        let decrypted_result: i64 = result.decrypt(&client_key);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear_a}.overflowing_sub({clear_b})"
+        );
+        assert_eq!(
+            overflowed.decrypt(&client_key),
+            expected_overflowed,
+            "Invalid overflow flag for {clear_a}.overflowing_sub({clear_b})"
+        );
+    }
+}
+
+#[test]
+fn test_saturating_add_sub() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    let cases = [
+        (i8::MAX, 1i8),
+        (i8::MAX, -1),
+        (i8::MIN, 1),
+        (i8::MIN, -1),
+        (100, 100),
+        (-100, -100),
+        (0, i8::MIN),
+        (-1, i8::MIN),
+        (i8::MAX, i8::MIN),
+        (rng.gen(), rng.gen()),
+    ];
+
+    for (clear_a, clear_b) in cases {
+        let a = FheInt8::encrypt(clear_a, &client_key);
+        let b = FheInt8::encrypt(clear_b, &client_key);
+
+        let result = (&a).saturating_add(&b);
+        let decrypted_result: i8 = result.decrypt(&client_key);
+        assert_eq!(
+            decrypted_result,
+            clear_a.saturating_add(clear_b),
+            "Invalid result for {clear_a}.saturating_add({clear_b})"
+        );
+
+        let result = a.saturating_sub(&b);
+        let decrypted_result: i8 = result.decrypt(&client_key);
+        assert_eq!(
+            decrypted_result,
+            clear_a.saturating_sub(clear_b),
+            "Invalid result for {clear_a}.saturating_sub({clear_b})"
+        );
+    }
+}
+
+#[test]
+fn test_div_rem_euclid() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let cases = [
+        (7i8, 3i8),
+        (-7, 3),
+        (7, -3),
+        (-7, -3),
+        (-6, 3),
+        (6, -3),
+        (i8::MIN, 7),
+        (i8::MIN, -7),
+        (i8::MAX, -128),
+    ];
+
+    for (clear_a, clear_b) in cases {
+        let a = FheInt8::encrypt(clear_a, &client_key);
+        let b = FheInt8::encrypt(clear_b, &client_key);
+
+        let (q, r) = a.div_rem_euclid(&b);
+        let q: i8 = q.decrypt(&client_key);
+        let r: i8 = r.decrypt(&client_key);
+        assert_eq!(
+            q,
+            clear_a.div_euclid(clear_b),
+            "Invalid result for {clear_a}.div_euclid({clear_b})"
+        );
+        assert_eq!(
+            r,
+            clear_a.rem_euclid(clear_b),
+            "Invalid result for {clear_a}.rem_euclid({clear_b})"
+        );
+
+        let q: i8 = a.div_euclid(&b).decrypt(&client_key);
+        assert_eq!(q, clear_a.div_euclid(clear_b));
+        let r: i8 = a.rem_euclid(&b).decrypt(&client_key);
+        assert_eq!(r, clear_a.rem_euclid(clear_b));
+    }
+}
+
+#[test]
+fn test_reinterpret_signed_unsigned() {
+    let config = ConfigBuilder::default().build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    for clear in [-1i32, 0, i32::MIN, i32::MAX, rng.gen()] {
+        let a = FheInt32::encrypt(clear, &client_key);
+
+        let unsigned = a.reinterpret_as_unsigned();
+        let decrypted: u32 = unsigned.decrypt(&client_key);
+        assert_eq!(
+            decrypted, clear as u32,
+            "Invalid reinterpretation of {clear}"
+        );
+
+        // Round trip
+        let signed = unsigned.reinterpret_as_signed();
+        let decrypted: i32 = signed.decrypt(&client_key);
+        assert_eq!(decrypted, clear, "Invalid round trip of {clear}");
+    }
+
+    // A signed negative value reinterpreted then used in unsigned ops
+    // follows the wrapping native semantics
+    for _ in 0..3 {
+        let clear_a = rng.gen_range(i32::MIN..0);
+        let clear_b = rng.gen::<u32>();
+
+        let a = FheInt32::encrypt(clear_a, &client_key);
+        let b = FheUint32::encrypt(clear_b, &client_key);
+
+        let result = a.reinterpret_as_unsigned() + &b;
+        let decrypted: u32 = result.decrypt(&client_key);
+        assert_eq!(
+            decrypted,
+            (clear_a as u32).wrapping_add(clear_b),
+            "Invalid result for ({clear_a} as u32) + {clear_b}"
+        );
+
+        let decrypted: i32 = result.reinterpret_as_signed().decrypt(&client_key);
+        assert_eq!(decrypted, clear_a.wrapping_add(clear_b as i32));
+    }
 }
 
 #[test]
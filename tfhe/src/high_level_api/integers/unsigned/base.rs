@@ -9,7 +9,7 @@ use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::signed::{FheInt, FheIntId};
 use crate::high_level_api::integers::IntegerId;
 use crate::high_level_api::keys::InternalServerKey;
-use crate::high_level_api::traits::Tagged;
+use crate::high_level_api::traits::{FheMax, FheMin, FheTrivialEncrypt, Tagged};
 use crate::high_level_api::{global_state, Device};
 use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
 #[cfg(feature = "gpu")]
@@ -23,6 +23,7 @@ use crate::shortint::PBSParameters;
 #[cfg(feature = "gpu")]
 use crate::GpuIndex;
 use crate::{FheBool, ServerKey, Tag};
+use rayon::prelude::*;
 use std::marker::PhantomData;
 
 #[derive(Debug)]
@@ -182,6 +183,29 @@ where
         }
     }
 
+    /// Reinterprets the bits of `self` as a signed integer of the same width
+    ///
+    /// The blocks are moved as is, no PBS or re-encryption is done.
+    pub(crate) fn reinterpret_bits<IntoId>(self) -> FheInt<IntoId>
+    where
+        IntoId: FheIntId,
+    {
+        assert_eq!(Id::num_bits(), IntoId::num_bits());
+        match self.ciphertext {
+            RadixCiphertext::Cpu(ct) => FheInt::new(
+                crate::integer::SignedRadixCiphertext::from(ct.blocks),
+                self.tag,
+            ),
+            #[cfg(feature = "gpu")]
+            RadixCiphertext::Cuda(ct) => FheInt::new(
+                crate::integer::gpu::ciphertext::CudaSignedRadixCiphertext {
+                    ciphertext: ct.ciphertext,
+                },
+                self.tag,
+            ),
+        }
+    }
+
     pub fn num_bits() -> usize {
         Id::num_bits()
     }
@@ -295,6 +319,66 @@ where
         })
     }
 
+    /// Returns a FheBool that encrypts `true` if the value is a power of two
+    ///
+    /// This is computed as `self != 0 && (self & (self - 1)) == 0`,
+    /// so like [u32::is_power_of_two], 0 is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(64u16, &client_key);
+    /// assert!(a.is_power_of_two().decrypt(&client_key));
+    ///
+    /// let a = FheUint16::encrypt(65u16, &client_key);
+    /// assert!(!a.is_power_of_two().decrypt(&client_key));
+    ///
+    /// let a = FheUint16::encrypt(0u16, &client_key);
+    /// assert!(!a.is_power_of_two().decrypt(&client_key));
+    /// ```
+    pub fn is_power_of_two(&self) -> FheBool {
+        let (is_non_zero, is_power_of_two_or_zero) =
+            global_state::with_internal_keys(|key| match key {
+                InternalServerKey::Cpu(cpu_key) => {
+                    let sk = cpu_key.pbs_key();
+                    let ct = self.ciphertext.on_cpu();
+                    let (is_non_zero, is_power_of_two_or_zero) = rayon::join(
+                        || sk.scalar_ne_parallelized(&*ct, 0u64),
+                        || {
+                            let ct_minus_one = sk.scalar_sub_parallelized(&*ct, 1u64);
+                            let masked = sk.bitand_parallelized(&*ct, &ct_minus_one);
+                            sk.scalar_eq_parallelized(&masked, 0u64)
+                        },
+                    );
+                    (
+                        FheBool::new(is_non_zero, cpu_key.tag.clone()),
+                        FheBool::new(is_power_of_two_or_zero, cpu_key.tag.clone()),
+                    )
+                }
+                #[cfg(feature = "gpu")]
+                InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                    let sk = &cuda_key.key.key;
+                    let ct = self.ciphertext.on_gpu(streams);
+                    let is_non_zero = sk.scalar_ne(&*ct, 0u64, streams);
+                    let ct_minus_one = sk.scalar_sub(&*ct, 1u64, streams);
+                    let masked = sk.bitand(&*ct, &ct_minus_one, streams);
+                    let is_power_of_two_or_zero = sk.scalar_eq(&masked, 0u64, streams);
+                    (
+                        FheBool::new(is_non_zero, cuda_key.tag.clone()),
+                        FheBool::new(is_power_of_two_or_zero, cuda_key.tag.clone()),
+                    )
+                }),
+            });
+
+        is_non_zero & is_power_of_two_or_zero
+    }
+
     /// Tries to decrypt a trivial ciphertext
     ///
     /// Trivial ciphertexts are ciphertexts which are not encrypted
@@ -480,6 +564,9 @@ where
 
     /// Returns the number of trailing zeros in the binary representation of self.
     ///
+    /// This is the index of the least significant set bit. Like the clear
+    /// `trailing_zeros`, an input of zero returns the bit width of the type.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -494,6 +581,12 @@ where
     /// let result = a.trailing_zeros();
     /// let decrypted: u16 = result.decrypt(&client_key);
     /// assert_eq!(decrypted, 3);
+    ///
+    /// let a = FheUint16::encrypt(0u16, &client_key);
+    ///
+    /// let result = a.trailing_zeros();
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 16);
     /// ```
     pub fn trailing_zeros(&self) -> super::FheUint32 {
         global_state::with_internal_keys(|key| match key {
@@ -587,22 +680,18 @@ where
     /// assert_eq!(decrypted, clear_a.count_ones());
     /// ```
     pub fn count_ones(&self) -> super::FheUint32 {
-        global_state::with_internal_keys(|key| match key {
-            InternalServerKey::Cpu(cpu_key) => {
-                let result = cpu_key
-                    .pbs_key()
-                    .count_ones_parallelized(&*self.ciphertext.on_cpu());
-                let result = cpu_key.pbs_key().cast_to_unsigned(
-                    result,
-                    super::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
-                );
-                super::FheUint32::new(result, cpu_key.tag.clone())
-            }
-            #[cfg(feature = "gpu")]
-            InternalServerKey::Cuda(_) => {
-                panic!("Cuda devices do not support count_ones yet");
-            }
-        })
+        let mut result = global_state::with_cpu_fallback_keys("count_ones", |cpu_key| {
+            let result = cpu_key
+                .pbs_key()
+                .count_ones_parallelized(&*self.ciphertext.on_cpu());
+            let result = cpu_key.pbs_key().cast_to_unsigned(
+                result,
+                super::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
+            );
+            super::FheUint32::new(result, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
     }
 
     /// Returns the number of zeros in the binary representation of self.
@@ -624,22 +713,99 @@ where
     /// assert_eq!(decrypted, clear_a.count_zeros());
     /// ```
     pub fn count_zeros(&self) -> super::FheUint32 {
-        global_state::with_internal_keys(|key| match key {
-            InternalServerKey::Cpu(cpu_key) => {
-                let result = cpu_key
-                    .pbs_key()
-                    .count_zeros_parallelized(&*self.ciphertext.on_cpu());
-                let result = cpu_key.pbs_key().cast_to_unsigned(
-                    result,
-                    super::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
-                );
-                super::FheUint32::new(result, cpu_key.tag.clone())
-            }
-            #[cfg(feature = "gpu")]
-            InternalServerKey::Cuda(_) => {
-                panic!("Cuda devices do not support count_zeros yet");
-            }
-        })
+        let mut result = global_state::with_cpu_fallback_keys("count_zeros", |cpu_key| {
+            let result = cpu_key
+                .pbs_key()
+                .count_zeros_parallelized(&*self.ciphertext.on_cpu());
+            let result = cpu_key.pbs_key().cast_to_unsigned(
+                result,
+                super::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
+            );
+            super::FheUint32::new(result, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
+    }
+
+    /// Returns the bit at position `index` as a [FheBool]
+    ///
+    /// Index 0 is the least significant bit.
+    ///
+    /// If `index` is not smaller than the number of bits of the type,
+    /// an encryption of `false` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let clear_a = 0b0000000_0110111u16;
+    /// let a = FheUint16::encrypt(clear_a, &client_key);
+    ///
+    /// let result = a.get_bit(2);
+    /// assert!(result.decrypt(&client_key));
+    ///
+    /// let result = a.get_bit(3);
+    /// assert!(!result.decrypt(&client_key));
+    /// ```
+    pub fn get_bit(&self, index: u32) -> FheBool {
+        // Bits past the width of the type are never part of the value
+        if index >= Id::num_bits() as u32 {
+            return FheBool::encrypt_trivial(false);
+        }
+
+        let mut result = global_state::with_cpu_fallback_keys("get_bit", |cpu_key| {
+            let result = cpu_key
+                .pbs_key()
+                .get_bit_parallelized(&*self.ciphertext.on_cpu(), index);
+            FheBool::new(result, cpu_key.tag.clone())
+        });
+        result.ciphertext.move_to_device_of_server_key_if_set();
+        result
+    }
+
+    /// Sets the bit at position `index` to the encrypted `value`
+    ///
+    /// Index 0 is the least significant bit.
+    ///
+    /// If `index` is not smaller than the number of bits of the type,
+    /// `self` is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let clear_a = 0b0000000_0110111u16;
+    /// let mut a = FheUint16::encrypt(clear_a, &client_key);
+    ///
+    /// a.set_bit(3, &FheBool::encrypt(true, &client_key));
+    /// a.set_bit(0, &FheBool::encrypt(false, &client_key));
+    ///
+    /// let decrypted: u16 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, (clear_a | (1 << 3)) & !1);
+    /// ```
+    pub fn set_bit(&mut self, index: u32, value: &FheBool) {
+        if index >= Id::num_bits() as u32 {
+            return;
+        }
+
+        global_state::with_cpu_fallback_keys("set_bit", |cpu_key| {
+            cpu_key.pbs_key().set_bit_assign_parallelized(
+                self.ciphertext.as_cpu_mut(),
+                index,
+                &value.ciphertext.on_cpu(),
+            );
+        });
+        self.move_to_current_device();
     }
 
     /// Returns the base 2 logarithm of the number, rounded down.
@@ -746,6 +912,40 @@ where
         })
     }
 
+    /// Multiplies `self` by `rhs`, returning an error if the server key cannot do it
+    ///
+    /// Contrary to the `*` operator, which panics, an error is returned when the server key
+    /// was generated with
+    /// [ConfigBuilder::use_only_additions](crate::ConfigBuilder::use_only_additions).
+    ///
+    /// The operation is modular, i.e on overflow it wraps around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    /// let b = FheUint16::encrypt(37849u16, &client_key);
+    ///
+    /// let result = a.try_mul(&b).unwrap();
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 3u16.wrapping_mul(37849u16));
+    /// ```
+    pub fn try_mul(&self, rhs: &Self) -> crate::Result<Self> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => cpu_key.key.check_bootstrapping_material(),
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => Ok(()),
+        })?;
+
+        Ok(self * rhs)
+    }
+
     /// `match` an input value to an output value
     ///
     /// - Input values are not required to span all possible values that `self` could hold. And the
@@ -926,20 +1126,544 @@ where
     /// assert_eq!(decrypted, msg.reverse_bits());
     /// ```
     pub fn reverse_bits(&self) -> Self {
-        global_state::with_internal_keys(|key| match key {
-            InternalServerKey::Cpu(cpu_key) => {
-                let sk = &cpu_key.pbs_key();
+        let mut result = global_state::with_cpu_fallback_keys("reverse_bits", |cpu_key| {
+            let sk = &cpu_key.pbs_key();
 
-                let ct = self.ciphertext.on_cpu();
+            let ct = self.ciphertext.on_cpu();
 
-                Self::new(sk.reverse_bits_parallelized(&*ct), cpu_key.tag.clone())
+            Self::new(sk.reverse_bits_parallelized(&*ct), cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
+    }
+
+    /// Restricts the value to the interval `[min, max]`
+    ///
+    /// Returns `max` if `self` is greater than `max`, `min` if `self` is less than `min`,
+    /// and `self` otherwise.
+    ///
+    /// This is computed as `min(max(self, min), max)`.
+    ///
+    /// # Note
+    ///
+    /// Unlike Rust's `clamp`, this cannot panic when `min > max`
+    /// as the bounds are encrypted, in that case the returned value is `max`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let min = FheUint16::encrypt(10u16, &client_key);
+    /// let max = FheUint16::encrypt(20u16, &client_key);
+    ///
+    /// let a = FheUint16::encrypt(5u16, &client_key);
+    /// let result: u16 = a.clamp(&min, &max).decrypt(&client_key);
+    /// assert_eq!(result, 10u16);
+    ///
+    /// let a = FheUint16::encrypt(15u16, &client_key);
+    /// let result: u16 = a.clamp(&min, &max).decrypt(&client_key);
+    /// assert_eq!(result, 15u16);
+    ///
+    /// let a = FheUint16::encrypt(25u16, &client_key);
+    /// let result: u16 = a.clamp(&min, &max).decrypt(&client_key);
+    /// assert_eq!(result, 20u16);
+    /// ```
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        FheMin::min(&FheMax::max(self, min), max)
+    }
+
+    /// Casts to a [FheUint] with a possibly different number of bits,
+    /// saturating to the maximum value of the target type if the value does not fit.
+    ///
+    /// Returns the casted value and a [FheBool] that encrypts `true`
+    /// if the value did not fit in the target type, i.e. if information was lost.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32, FheUint8Id};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint32::encrypt(300u32, &client_key);
+    /// let (b, overflowed) = a.narrowing_cast_with_flag::<FheUint8Id>();
+    ///
+    /// let decrypted: u8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, u8::MAX);
+    /// assert!(overflowed.decrypt(&client_key));
+    /// ```
+    pub fn narrowing_cast_with_flag<IntoId>(&self) -> (FheUint<IntoId>, FheBool)
+    where
+        IntoId: FheUintId,
+    {
+        let (mut casted, mut overflowed) =
+            global_state::with_cpu_fallback_keys("narrowing_cast_with_flag", |cpu_key| {
+                let (casted, overflowed) = cpu_key.pbs_key().saturating_cast_to_unsigned(
+                    self.ciphertext.on_cpu().to_owned(),
+                    IntoId::num_blocks(cpu_key.message_modulus()),
+                );
+                (
+                    FheUint::new(casted, cpu_key.tag.clone()),
+                    FheBool::new(overflowed, cpu_key.tag.clone()),
+                )
+            });
+        casted.move_to_current_device();
+        overflowed.ciphertext.move_to_device_of_server_key_if_set();
+        (casted, overflowed)
+    }
+
+    /// Computes the dot product of two slices of [FheUint]
+    ///
+    /// The elements are casted to the output type before being multiplied,
+    /// so using a wider output type (e.g. [crate::FheUint64] for [crate::FheUint32] inputs)
+    /// avoids overflows. Otherwise, the result wraps around.
+    ///
+    /// Empty slices return an encryption of 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lhs` and `rhs` do not have the same length
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32, FheUint64Id};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let lhs = [u32::MAX, 2];
+    /// let rhs = [3u32, 5];
+    ///
+    /// let a = lhs
+    ///     .iter()
+    ///     .map(|v| FheUint32::encrypt(*v, &client_key))
+    ///     .collect::<Vec<_>>();
+    /// let b = rhs
+    ///     .iter()
+    ///     .map(|v| FheUint32::encrypt(*v, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let result = FheUint32::dot_product::<FheUint64Id>(&a, &b);
+    /// let decrypted: u64 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, u64::from(u32::MAX) * 3 + 10);
+    /// ```
+    pub fn dot_product<IntoId>(lhs: &[Self], rhs: &[Self]) -> FheUint<IntoId>
+    where
+        IntoId: FheUintId,
+    {
+        let mut result = global_state::with_cpu_fallback_keys("dot_product", |cpu_key| {
+            let lhs = lhs
+                .iter()
+                .map(|ct| ct.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            let rhs = rhs
+                .iter()
+                .map(|ct| ct.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            let result = cpu_key.pbs_key().dot_product_parallelized(
+                &lhs,
+                &rhs,
+                IntoId::num_blocks(cpu_key.message_modulus()),
+            );
+            FheUint::new(result, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
+    }
+
+    /// Maps the encrypted value through a clear lookup table
+    ///
+    /// The result encrypts `table[x]` where `x` is the value encrypted by `self`.
+    ///
+    /// - If the table is shorter than the range of values `self` can hold, the values that are out
+    ///   of the table are mapped to 0.
+    /// - Entries of the table are truncated to the number of bits of `Self`.
+    ///
+    /// This is built on the same machinery as [Self::match_value],
+    /// so it works for inputs spanning any number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// // Squares of the first values
+    /// let table = (0..10u64).map(|x| x * x).collect::<Vec<_>>();
+    ///
+    /// let a = FheUint8::encrypt(7u8, &client_key);
+    /// let decrypted: u8 = a.map_lut(&table).decrypt(&client_key);
+    /// assert_eq!(decrypted, 49);
+    ///
+    /// // Out of the table
+    /// let a = FheUint8::encrypt(12u8, &client_key);
+    /// let decrypted: u8 = a.map_lut(&table).decrypt(&client_key);
+    /// assert_eq!(decrypted, 0);
+    /// ```
+    pub fn map_lut(&self, table: &[u64]) -> Self {
+        let num_bits = Id::num_bits();
+        let mask = if num_bits >= u64::BITS as usize {
+            u64::MAX
+        } else {
+            (1u64 << num_bits) - 1
+        };
+        // Entries whose index cannot be encrypted by self are unreachable
+        let table_len = (table.len() as u64).min(mask.saturating_add(1));
+        let matches = MatchValues::from_fn_and_range(|x| table[x as usize] & mask, 0..table_len);
+
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                // Inputs that are not in the table do not match,
+                // in which case the result encrypts 0
+                let (result, _) = cpu_key
+                    .pbs_key()
+                    .match_value_parallelized(&self.ciphertext.on_cpu(), &matches);
+                let result = cpu_key
+                    .pbs_key()
+                    .cast_to_unsigned(result, Id::num_blocks(cpu_key.message_modulus()));
+                Self::new(result, cpu_key.tag.clone())
             }
             #[cfg(feature = "gpu")]
-            InternalServerKey::Cuda(_) => {
-                panic!("Cuda devices do not support reverse yet");
-            }
+            InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                let (result, _) = cuda_key.key.key.match_value(
+                    &self.ciphertext.on_gpu(streams),
+                    &matches,
+                    streams,
+                );
+                let result = cuda_key.key.key.cast_to_unsigned(
+                    result,
+                    Id::num_blocks(cuda_key.key.key.message_modulus),
+                    streams,
+                );
+                Self::new(result, cuda_key.tag.clone())
+            }),
         })
     }
+
+    /// Increments by one a big-endian counter made of several ciphertexts
+    ///
+    /// The slice is interpreted as a single big integer, where `counter[0]` holds the
+    /// most significant part, e.g. a `[FheUint8; 16]` holding a 128-bit nonce.
+    /// The carry is propagated across the elements.
+    ///
+    /// When all the elements are at their max value, the counter wraps around to 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// // 0x00FF
+    /// let mut counter = vec![
+    ///     FheUint8::encrypt(0x00u8, &client_key),
+    ///     FheUint8::encrypt(0xFFu8, &client_key),
+    /// ];
+    ///
+    /// FheUint8::increment_counter(&mut counter);
+    ///
+    /// let decrypted: Vec<u8> = counter.iter().map(|ct| ct.decrypt(&client_key)).collect();
+    /// assert_eq!(decrypted, vec![0x01, 0x00]);
+    /// ```
+    pub fn increment_counter(counter: &mut [Self]) {
+        global_state::with_cpu_fallback_keys("increment_counter", |cpu_key| {
+            let mut elements = counter
+                .iter()
+                .map(|ct| ct.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            cpu_key
+                .pbs_key()
+                .increment_counter_parallelized(&mut elements);
+            for (ct, element) in counter.iter_mut().zip(elements) {
+                *ct.ciphertext.as_cpu_mut() = element;
+            }
+        });
+        for ct in counter.iter_mut() {
+            ct.move_to_current_device();
+        }
+    }
+
+    /// Sorts the values in ascending order
+    ///
+    /// This uses a bitonic sorting network, whose stages are computed in parallel.
+    /// Lengths that are not a power of two are supported.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let clears = [42u8, 7, 255, 7, 100];
+    /// let mut values = clears
+    ///     .iter()
+    ///     .map(|v| FheUint8::encrypt(*v, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// FheUint8::sort(&mut values);
+    ///
+    /// let decrypted: Vec<u8> = values.iter().map(|ct| ct.decrypt(&client_key)).collect();
+    /// assert_eq!(decrypted, vec![7, 7, 42, 100, 255]);
+    /// ```
+    pub fn sort(values: &mut [Self]) {
+        global_state::with_cpu_fallback_keys("sort", |cpu_key| {
+            let mut elements = values
+                .iter()
+                .map(|ct| ct.ciphertext.on_cpu().into_owned())
+                .collect::<Vec<_>>();
+            cpu_key.pbs_key().sort_parallelized(&mut elements);
+            for (ct, element) in values.iter_mut().zip(elements) {
+                *ct.ciphertext.as_cpu_mut() = element;
+            }
+        });
+        for ct in values.iter_mut() {
+            ct.move_to_current_device();
+        }
+    }
+
+    /// Builds a value from its little-endian encrypted bytes
+    ///
+    /// `bytes[0]` holds the least significant byte.
+    ///
+    /// As blocks may not be aligned on byte boundaries, depending on the message modulus,
+    /// each byte is shifted to its position before being added to the others.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bits of `Self` is not `8 * bytes.len()`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let clear = 0x1234_5678u32;
+    /// let bytes = clear
+    ///     .to_le_bytes()
+    ///     .map(|byte| FheUint8::encrypt(byte, &client_key));
+    ///
+    /// let a = FheUint32::from_le_bytes(&bytes);
+    ///
+    /// let decrypted: u32 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, clear);
+    /// ```
+    pub fn from_le_bytes(bytes: &[super::FheUint8]) -> Self {
+        assert_eq!(
+            Id::num_bits(),
+            8 * bytes.len(),
+            "Cannot build a {} bits integer from {} bytes",
+            Id::num_bits(),
+            bytes.len()
+        );
+
+        let mut result = global_state::with_cpu_fallback_keys("from_le_bytes", |cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let num_blocks = Id::num_blocks(sks.message_modulus());
+
+            let parts = bytes
+                .par_iter()
+                .enumerate()
+                .map(|(i, byte)| {
+                    let byte =
+                        sks.cast_to_unsigned(byte.ciphertext.on_cpu().into_owned(), num_blocks);
+                    sks.scalar_left_shift_parallelized(&byte, 8 * i as u32)
+                })
+                .collect::<Vec<_>>();
+            let result = sks
+                .sum_ciphertexts_parallelized(parts.iter())
+                .unwrap_or_else(|| sks.create_trivial_zero_radix(num_blocks));
+
+            Self::new(result, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
+    }
+
+    /// Returns the little-endian encrypted bytes of the value
+    ///
+    /// The first element holds the least significant byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bits of `Self` is not a multiple of 8
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let clear = 0x1234_5678u32;
+    /// let a = FheUint32::encrypt(clear, &client_key);
+    ///
+    /// let bytes = a.to_le_bytes();
+    ///
+    /// let decrypted: Vec<u8> = bytes.iter().map(|ct| ct.decrypt(&client_key)).collect();
+    /// assert_eq!(decrypted, clear.to_le_bytes());
+    /// ```
+    pub fn to_le_bytes(&self) -> Vec<super::FheUint8> {
+        assert_eq!(
+            Id::num_bits() % 8,
+            0,
+            "Cannot split a {} bits integer into bytes",
+            Id::num_bits()
+        );
+        let num_bytes = Id::num_bits() / 8;
+
+        let mut bytes = global_state::with_cpu_fallback_keys("to_le_bytes", |cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let num_blocks = super::FheUint8Id::num_blocks(sks.message_modulus());
+            // When the bits per block do not divide 8, the blocks of a byte
+            // also hold the low bits of the next byte, they have to be cleared
+            let needs_mask = num_blocks as u32 * sks.message_modulus().0.ilog2() > 8;
+            let ct = self.ciphertext.on_cpu();
+            let ct: &crate::integer::RadixCiphertext = &ct;
+
+            (0..num_bytes)
+                .into_par_iter()
+                .map(|i| {
+                    let shifted = sks.scalar_right_shift_parallelized(ct, 8 * i as u32);
+                    let mut byte = sks.cast_to_unsigned(shifted, num_blocks);
+                    if needs_mask {
+                        byte = sks.scalar_bitand_parallelized(&byte, u8::MAX);
+                    }
+                    super::FheUint8::new(byte, cpu_key.tag.clone())
+                })
+                .collect::<Vec<_>>()
+        });
+        for byte in bytes.iter_mut() {
+            byte.move_to_current_device();
+        }
+        bytes
+    }
+
+    /// Computes `self` raised to the power of an encrypted `exponent`, modulo a clear `modulus`
+    ///
+    /// Intermediate products are reduced modulo `modulus`, so contrary to
+    /// [pow](crate::prelude::FhePow::pow), the result does not wrap around.
+    ///
+    /// The cost grows with the number of bits of the exponent,
+    /// so using a small exponent type is recommended.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0, or if `modulus - 1` does not fit in `Self`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(7u16, &client_key);
+    /// let b = FheUint8::encrypt(13u8, &client_key);
+    ///
+    /// let result = a.pow_mod(&b, 23);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// // 7^13 mod 23
+    /// assert_eq!(decrypted, 20);
+    /// ```
+    pub fn pow_mod<Id2>(&self, exponent: &FheUint<Id2>, modulus: u64) -> Self
+    where
+        Id2: FheUintId,
+    {
+        let mut result = global_state::with_cpu_fallback_keys("pow_mod", |cpu_key| {
+            let inner_result = cpu_key.pbs_key().pow_mod_parallelized(
+                &self.ciphertext.on_cpu(),
+                &exponent.ciphertext.on_cpu(),
+                modulus,
+            );
+            Self::new(inner_result, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
+    }
+
+    /// Returns the number of bytes `bincode` would produce when serializing `self`
+    ///
+    /// The size is computed without serializing into a buffer, which makes it
+    /// cheap to use to preallocate buffers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, _server_key) = generate_keys(ConfigBuilder::default());
+    ///
+    /// let a = FheUint16::encrypt(12345u16, &client_key);
+    ///
+    /// let serialized = bincode::serialize(&a).unwrap();
+    /// assert_eq!(a.serialized_size(), serialized.len());
+    /// ```
+    pub fn serialized_size(&self) -> usize
+    where
+        Self: serde::Serialize,
+    {
+        bincode::serialized_size(self).unwrap() as usize
+    }
+
+    /// Serializes `self` with `bincode` directly into `writer`
+    ///
+    /// The blocks are streamed into the writer instead of being serialized into
+    /// an intermediate buffer first, and exactly [Self::serialized_size] bytes are written.
+    /// The data can be read back using `bincode::deserialize_from`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, _server_key) = generate_keys(ConfigBuilder::default());
+    ///
+    /// let a = FheUint16::encrypt(12345u16, &client_key);
+    ///
+    /// let mut buffer = Vec::new();
+    /// a.serialize_into(&mut buffer).unwrap();
+    /// assert_eq!(buffer.len(), a.serialized_size());
+    ///
+    /// let b: FheUint16 = bincode::deserialize_from(buffer.as_slice()).unwrap();
+    /// let decrypted: u16 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, 12345u16);
+    /// ```
+    pub fn serialize_into<W>(&self, writer: W) -> bincode::Result<()>
+    where
+        Self: serde::Serialize,
+        W: std::io::Write,
+    {
+        bincode::serialize_into(writer, self)
+    }
 }
 
 impl<Id> TryFrom<crate::integer::RadixCiphertext> for FheUint<Id>
@@ -4,7 +4,7 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::FheUintId;
 use crate::high_level_api::keys::InternalServerKey;
-use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
+use crate::integer::block_decomposition::{BlockRecomposer, DecomposableInto, RecomposableFrom};
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
 use crate::prelude::{FheDecrypt, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt};
@@ -38,7 +38,26 @@ where
     /// assert_eq!(decrypted, 7288u32);
     /// ```
     fn decrypt(&self, key: &ClientKey) -> ClearType {
-        key.key.key.decrypt_radix(&self.ciphertext.on_cpu())
+        let ciphertext = self.ciphertext.on_cpu();
+        if ciphertext.block_carries_are_empty() {
+            return key.key.key.decrypt_radix(&ciphertext);
+        }
+
+        // With a lazy carry propagation, blocks may still hold carries,
+        // they are propagated in the clear, the last carry is dropped as the
+        // operations wrap around
+        let message_modulus = key.message_modulus().0;
+        let mut recomposer = BlockRecomposer::<ClearType>::new(message_modulus.ilog2());
+        let mut carry = 0;
+        for block in ciphertext.blocks.iter() {
+            let value = key.key.key.key.decrypt_message_and_carry(block) + carry;
+            carry = value / message_modulus;
+            if !recomposer.add_unmasked(value % message_modulus) {
+                break;
+            }
+        }
+
+        recomposer.value()
     }
 }
 
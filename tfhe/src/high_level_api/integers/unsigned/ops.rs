@@ -2,26 +2,80 @@
 // this is the pattern we use for the macros
 #![allow(clippy::redundant_closure_call)]
 use super::inner::RadixCiphertext;
+use crate::high_level_api::config::CarryPropagationStrategy;
 #[cfg(feature = "gpu")]
 use crate::high_level_api::details::MaybeCloned;
 use crate::high_level_api::global_state;
 #[cfg(feature = "gpu")]
 use crate::high_level_api::global_state::with_thread_local_cuda_streams;
-use crate::high_level_api::integers::FheUintId;
+use crate::high_level_api::integers::{FheUintId, IntegerId};
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::traits::{
-    DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
-    RotateRightAssign,
+    DivRem, FheEq, FheMax, FheMin, FheOrd, FhePow, RotateLeft, RotateLeftAssign, RotateRight,
+    RotateRightAssign, WideningMul,
 };
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
-use crate::{FheBool, FheUint};
+use crate::{FheBool, FheUint, ServerKey};
 use std::borrow::Borrow;
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
     Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
 
+/// Returns whether carries should only be propagated when required
+///
+/// This is the case with [CarryPropagationStrategy::Lazy], or when the key
+/// cannot propagate carries at all (see [crate::ConfigBuilder::use_only_additions]).
+fn cpu_propagates_lazily(cpu_key: &ServerKey) -> bool {
+    cpu_key.carry_propagation == CarryPropagationStrategy::Lazy
+        || cpu_key.key.check_bootstrapping_material().is_err()
+}
+
+/// Adds `rhs` to `lhs` on the CPU, following the carry propagation strategy of the key
+///
+/// With [CarryPropagationStrategy::Lazy], carries are only propagated
+/// when the carry space or noise budget of the blocks does not allow the addition.
+fn cpu_add_assign(
+    cpu_key: &ServerKey,
+    lhs: &mut crate::integer::RadixCiphertext,
+    rhs: &crate::integer::RadixCiphertext,
+) {
+    let sks = cpu_key.pbs_key();
+    if cpu_propagates_lazily(cpu_key) && sks.is_add_possible(lhs, rhs).is_ok() {
+        sks.unchecked_add_assign(lhs, rhs);
+    } else {
+        sks.add_assign_parallelized(lhs, rhs);
+    }
+}
+
+/// Subtracts `rhs` from `lhs` on the CPU, following the carry propagation strategy of the key
+///
+/// See [cpu_add_assign]
+fn cpu_sub_assign(
+    cpu_key: &ServerKey,
+    lhs: &mut crate::integer::RadixCiphertext,
+    rhs: &crate::integer::RadixCiphertext,
+) {
+    let sks = cpu_key.pbs_key();
+    if cpu_propagates_lazily(cpu_key) && sks.is_sub_possible(lhs, rhs).is_ok() {
+        sks.unchecked_sub_assign(lhs, rhs);
+    } else {
+        sks.sub_assign_parallelized(lhs, rhs);
+    }
+}
+
+/// Returns the ciphertext with empty carries, as needed by `unchecked` operations
+fn cpu_with_clean_carries(
+    cpu_key: &ServerKey,
+    mut ct: crate::integer::RadixCiphertext,
+) -> crate::integer::RadixCiphertext {
+    if !ct.block_carries_are_empty() {
+        cpu_key.pbs_key().full_propagate_parallelized(&mut ct);
+    }
+    ct
+}
+
 impl<Id> std::iter::Sum<Self> for FheUint<Id>
 where
     Id: FheUintId,
@@ -31,6 +85,13 @@ where
     /// This is much more efficient than manually calling the `+` operator, thus
     /// using sum should always be preferred.
     ///
+    /// The sum wraps around on overflow, and summing an empty iterator
+    /// returns a trivial encryption of zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key is not set
+    ///
     /// # Example
     ///
     /// ```rust
@@ -56,7 +117,9 @@ where
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
-                let ciphertexts = iter.map(|elem| elem.ciphertext.into_cpu()).collect();
+                let ciphertexts = iter
+                    .map(|elem| cpu_with_clean_carries(cpu_key, elem.ciphertext.into_cpu()))
+                    .collect();
                 cpu_key
                     .pbs_key()
                     .unchecked_sum_ciphertexts_vec_parallelized(ciphertexts)
@@ -104,6 +167,13 @@ where
     /// This is much more efficient than manually calling the `+` operator, thus
     /// using sum should always be preferred.
     ///
+    /// The sum wraps around on overflow, and summing an empty iterator
+    /// returns a trivial encryption of zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key is not set
+    ///
     /// # Example
     ///
     /// ```rust
@@ -130,7 +200,9 @@ where
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
                 let ciphertexts = iter
-                    .map(|elem| elem.ciphertext.on_cpu().to_owned())
+                    .map(|elem| {
+                        cpu_with_clean_carries(cpu_key, elem.ciphertext.on_cpu().to_owned())
+                    })
                     .collect();
                 let msg_mod = cpu_key.pbs_key().message_modulus();
                 cpu_key
@@ -188,6 +260,28 @@ where
     }
 }
 
+impl<Id> FheMax<Self> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    fn max(&self, rhs: Self) -> Self::Output {
+        self.max(&rhs)
+    }
+}
+
+impl<Id> FheMin<Self> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    fn min(&self, rhs: Self) -> Self::Output {
+        self.min(&rhs)
+    }
+}
+
 impl<Id> FheMax<&Self> for FheUint<Id>
 where
     Id: FheUintId,
@@ -280,6 +374,49 @@ where
     }
 }
 
+impl<Id, Id2> FhePow<&FheUint<Id2>> for FheUint<Id>
+where
+    Id: FheUintId,
+    Id2: FheUintId,
+{
+    type Output = Self;
+
+    /// Raises a [FheUint] to the power of an encrypted exponent
+    ///
+    /// The result wraps around on overflow, like `wrapping_pow`.
+    ///
+    /// The cost grows with the number of bits of the exponent,
+    /// so using a small exponent type is recommended.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    /// let b = FheUint8::encrypt(5u8, &client_key);
+    ///
+    /// let result = a.pow(&b);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 3u16.wrapping_pow(5));
+    /// ```
+    fn pow(&self, exponent: &FheUint<Id2>) -> Self::Output {
+        let mut result = global_state::with_cpu_fallback_keys("pow", |cpu_key| {
+            let inner_result = cpu_key
+                .pbs_key()
+                .pow_parallelized(&*self.ciphertext.on_cpu(), &exponent.ciphertext.on_cpu());
+            Self::new(inner_result, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
+    }
+}
+
 impl<Id> FheEq<Self> for FheUint<Id>
 where
     Id: FheUintId,
@@ -708,9 +845,8 @@ generic_integer_impl_operation!(
         |lhs: &FheUint<_>, rhs: &FheUint<_>| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
-                    let inner_result = cpu_key
-                        .pbs_key()
-                        .add_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
+                    let mut inner_result = lhs.ciphertext.on_cpu().to_owned();
+                    cpu_add_assign(cpu_key, &mut inner_result, &rhs.ciphertext.on_cpu());
                     FheUint::new(inner_result, cpu_key.tag.clone())
                 },
                 #[cfg(feature = "gpu")]
@@ -751,9 +887,8 @@ generic_integer_impl_operation!(
         |lhs: &FheUint<_>, rhs: &FheUint<_>| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
-                    let inner_result = cpu_key
-                        .pbs_key()
-                        .sub_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
+                    let mut inner_result = lhs.ciphertext.on_cpu().to_owned();
+                    cpu_sub_assign(cpu_key, &mut inner_result, &rhs.ciphertext.on_cpu());
                     FheUint::new(inner_result, cpu_key.tag.clone())
                 },
                 #[cfg(feature = "gpu")]
@@ -1313,9 +1448,10 @@ where
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
-                cpu_key.pbs_key().add_assign_parallelized(
+                cpu_add_assign(
+                    cpu_key,
                     self.ciphertext.as_cpu_mut(),
-                    &*rhs.ciphertext.on_cpu(),
+                    &rhs.ciphertext.on_cpu(),
                 );
             }
             #[cfg(feature = "gpu")]
@@ -1358,9 +1494,10 @@ where
         let rhs = rhs.borrow();
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
-                cpu_key.pbs_key().sub_assign_parallelized(
+                cpu_sub_assign(
+                    cpu_key,
                     self.ciphertext.as_cpu_mut(),
-                    &*rhs.ciphertext.on_cpu(),
+                    &rhs.ciphertext.on_cpu(),
                 );
             }
             #[cfg(feature = "gpu")]
@@ -2011,3 +2148,74 @@ where
         })
     }
 }
+
+// Implements WideningMul for each type whose double width type exists
+macro_rules! impl_widening_mul {
+    ($($id:ident => $wide_id:ident),* $(,)?) => {
+        $(
+            impl WideningMul<&FheUint<super::$id>> for &FheUint<super::$id> {
+                type Output = FheUint<super::$wide_id>;
+
+                /// Multiplies two [FheUint], the result is twice as wide as the inputs
+                /// so that the high half of the product is kept.
+                fn widening_mul(self, rhs: &FheUint<super::$id>) -> Self::Output {
+                    global_state::with_internal_keys(|key| match key {
+                        InternalServerKey::Cpu(cpu_key) => {
+                            let sks = cpu_key.pbs_key();
+                            let num_blocks =
+                                <super::$wide_id as IntegerId>::num_blocks(sks.message_modulus());
+                            let (lhs, rhs) = rayon::join(
+                                || sks.cast_to_unsigned(self.ciphertext.on_cpu().into_owned(), num_blocks),
+                                || sks.cast_to_unsigned(rhs.ciphertext.on_cpu().into_owned(), num_blocks),
+                            );
+                            let result = sks.mul_parallelized(&lhs, &rhs);
+                            FheUint::new(result, cpu_key.tag.clone())
+                        }
+                        #[cfg(feature = "gpu")]
+                        InternalServerKey::Cuda(cuda_key) => with_thread_local_cuda_streams(|streams| {
+                            let sks = &cuda_key.key.key;
+                            let num_blocks =
+                                <super::$wide_id as IntegerId>::num_blocks(cuda_key.message_modulus());
+                            let lhs = sks.cast_to_unsigned(
+                                self.ciphertext.on_gpu(streams).duplicate(streams),
+                                num_blocks,
+                                streams,
+                            );
+                            let rhs = sks.cast_to_unsigned(
+                                rhs.ciphertext.on_gpu(streams).duplicate(streams),
+                                num_blocks,
+                                streams,
+                            );
+                            let result = sks.mul(&lhs, &rhs, streams);
+                            FheUint::new(result, cuda_key.tag.clone())
+                        }),
+                    })
+                }
+            }
+
+            impl WideningMul<&FheUint<super::$id>> for FheUint<super::$id> {
+                type Output = FheUint<super::$wide_id>;
+
+                /// Multiplies two [FheUint], the result is twice as wide as the inputs
+                /// so that the high half of the product is kept.
+                fn widening_mul(self, rhs: &Self) -> Self::Output {
+                    <&Self as WideningMul<&Self>>::widening_mul(&self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_widening_mul!(
+    FheUint2Id => FheUint4Id,
+    FheUint4Id => FheUint8Id,
+    FheUint6Id => FheUint12Id,
+    FheUint8Id => FheUint16Id,
+    FheUint16Id => FheUint32Id,
+    FheUint32Id => FheUint64Id,
+    FheUint64Id => FheUint128Id,
+    FheUint128Id => FheUint256Id,
+    FheUint256Id => FheUint512Id,
+    FheUint512Id => FheUint1024Id,
+    FheUint1024Id => FheUint2048Id,
+);
@@ -5,7 +5,9 @@ use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::FheUintId;
 use crate::high_level_api::keys::InternalServerKey;
 use crate::integer::block_decomposition::DecomposableInto;
-use crate::prelude::{CastInto, OverflowingAdd, OverflowingMul, OverflowingSub};
+use crate::prelude::{
+    CastInto, OverflowingAdd, OverflowingMul, OverflowingSub, SaturatingAdd, SaturatingSub,
+};
 use crate::{FheBool, FheUint};
 
 impl<Id> OverflowingAdd<Self> for &FheUint<Id>
@@ -370,8 +372,8 @@ where
     /// assert!(overflowed.decrypt(&client_key));
     /// ```
     fn overflowing_sub(self, other: Clear) -> (Self::Output, FheBool) {
-        global_state::with_internal_keys(|key| match key {
-            InternalServerKey::Cpu(cpu_key) => {
+        let (mut result, mut overflow) =
+            global_state::with_cpu_fallback_keys("overflowing_sub", |cpu_key| {
                 let (result, overflow) = cpu_key
                     .pbs_key()
                     .unsigned_overflowing_scalar_sub_parallelized(&self.ciphertext.on_cpu(), other);
@@ -379,12 +381,10 @@ where
                     FheUint::new(result, cpu_key.tag.clone()),
                     FheBool::new(overflow, cpu_key.tag.clone()),
                 )
-            }
-            #[cfg(feature = "gpu")]
-            InternalServerKey::Cuda(_) => {
-                panic!("Cuda devices do not support overflowing_add yet");
-            }
-        })
+            });
+        result.move_to_current_device();
+        overflow.ciphertext.move_to_device_of_server_key_if_set();
+        (result, overflow)
     }
 }
 
@@ -509,3 +509,99 @@ where
         <&Self as OverflowingMul<&Self>>::overflowing_mul(&self, other)
     }
 }
+
+impl<Id> SaturatingAdd<Self> for &FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Adds two [FheUint], saturating at the max value of the type instead of wrapping around
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(200u8, &client_key);
+    /// let b = FheUint8::encrypt(100u8, &client_key);
+    ///
+    /// let result = (&a).saturating_add(&b);
+    /// let result: u8 = result.decrypt(&client_key);
+    /// assert_eq!(result, u8::MAX);
+    /// ```
+    fn saturating_add(self, other: Self) -> Self::Output {
+        let mut result = global_state::with_cpu_fallback_keys("saturating_add", |cpu_key| {
+            let inner_result = cpu_key.pbs_key().unsigned_saturating_add_parallelized(
+                &self.ciphertext.on_cpu(),
+                &other.ciphertext.on_cpu(),
+            );
+            FheUint::new(inner_result, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
+    }
+}
+
+impl<Id> SaturatingAdd<&Self> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    fn saturating_add(self, other: &Self) -> Self::Output {
+        <&Self as SaturatingAdd<&Self>>::saturating_add(&self, other)
+    }
+}
+
+impl<Id> SaturatingSub<Self> for &FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Subtracts two [FheUint], saturating at zero instead of wrapping around
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(100u8, &client_key);
+    /// let b = FheUint8::encrypt(200u8, &client_key);
+    ///
+    /// let result = (&a).saturating_sub(&b);
+    /// let result: u8 = result.decrypt(&client_key);
+    /// assert_eq!(result, 0);
+    /// ```
+    fn saturating_sub(self, other: Self) -> Self::Output {
+        let mut result = global_state::with_cpu_fallback_keys("saturating_sub", |cpu_key| {
+            let inner_result = cpu_key.pbs_key().unsigned_saturating_sub_parallelized(
+                &self.ciphertext.on_cpu(),
+                &other.ciphertext.on_cpu(),
+            );
+            FheUint::new(inner_result, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
+    }
+}
+
+impl<Id> SaturatingSub<&Self> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    fn saturating_sub(self, other: &Self) -> Self::Output {
+        <&Self as SaturatingSub<&Self>>::saturating_sub(&self, other)
+    }
+}
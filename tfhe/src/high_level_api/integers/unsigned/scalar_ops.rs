@@ -12,8 +12,8 @@ use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::FheUintId;
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::traits::{
-    BitSlice, DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
-    RotateRightAssign,
+    BitSlice, DivRem, FheEq, FheMax, FheMin, FheOrd, FhePow, RotateLeft, RotateLeftAssign,
+    RotateRight, RotateRightAssign,
 };
 use crate::integer::bigint::{U1024, U2048, U512};
 use crate::integer::block_decomposition::DecomposableInto;
@@ -274,6 +274,45 @@ where
     }
 }
 
+impl<Id> FhePow<u32> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    /// Raises a [FheUint] to the power of a clear exponent
+    ///
+    /// The result wraps around on overflow, like `wrapping_pow`.
+    /// An exponent of 0 returns a trivial encryption of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    ///
+    /// let result = a.pow(5);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 3u16.wrapping_pow(5));
+    /// ```
+    fn pow(&self, exponent: u32) -> Self::Output {
+        let mut result = global_state::with_cpu_fallback_keys("pow", |cpu_key| {
+            let inner_result = cpu_key
+                .pbs_key()
+                .scalar_pow_parallelized(&*self.ciphertext.on_cpu(), exponent);
+            Self::new(inner_result, cpu_key.tag.clone())
+        });
+        result.move_to_current_device();
+        result
+    }
+}
+
 impl<Id, Clear> FheMax<Clear> for FheUint<Id>
 where
     Clear: DecomposableInto<u64>,
@@ -403,18 +442,14 @@ where
     where
         R: RangeBounds<Clear>,
     {
-        global_state::with_internal_keys(|key| match key {
-            InternalServerKey::Cpu(cpu_key) => {
-                let result = cpu_key
-                    .pbs_key()
-                    .scalar_bitslice_parallelized(&self.ciphertext.on_cpu(), range)?;
-                Ok(FheUint::new(result, cpu_key.tag.clone()))
-            }
-            #[cfg(feature = "gpu")]
-            InternalServerKey::Cuda(_) => {
-                panic!("Cuda devices do not support bitslice yet");
-            }
-        })
+        let mut result = global_state::with_cpu_fallback_keys("bitslice", |cpu_key| {
+            let result = cpu_key
+                .pbs_key()
+                .scalar_bitslice_parallelized(&self.ciphertext.on_cpu(), range)?;
+            Ok(FheUint::new(result, cpu_key.tag.clone()))
+        })?;
+        result.move_to_current_device();
+        Ok(result)
     }
 }
 
@@ -1,7 +1,7 @@
 use crate::conformance::ListSizeConstraint;
 use crate::high_level_api::prelude::*;
 use crate::high_level_api::tests::{setup_cpu, setup_default_cpu};
-use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder, FheBool, FheUint8};
 use crate::integer::U256;
 use crate::safe_serialization::{DeserializationConfig, SerializationConfig};
 use crate::shortint::parameters::current_params::*;
@@ -9,8 +9,8 @@ use crate::shortint::parameters::*;
 use crate::{
     ClientKey, CompactCiphertextList, CompactCiphertextListConformanceParams, CompactPublicKey,
     CompressedCompactPublicKey, CompressedFheUint16, CompressedFheUint256, CompressedFheUint32,
-    CompressedPublicKey, CompressedServerKey, FheInt16, FheInt32, FheInt8, FheUint128, FheUint16,
-    FheUint256, FheUint32, FheUint32ConformanceParams,
+    CompressedPublicKey, CompressedServerKey, FheExpr, FheInt16, FheInt32, FheInt8, FheUint128,
+    FheUint16, FheUint256, FheUint32, FheUint32ConformanceParams, ServerKey,
 };
 use rand::prelude::*;
 
@@ -30,6 +30,23 @@ fn test_integer_compressed_can_be_serialized() {
     assert_eq!(clear_decompressed, clear);
 }
 
+#[test]
+fn test_integer_serialize_into_writer() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, _) = generate_keys(config);
+
+    let clear = U256::from(u64::MAX);
+    let a = FheUint256::try_encrypt(clear, &client_key).unwrap();
+
+    let mut writer: Vec<u8> = Vec::new();
+    a.serialize_into(&mut writer).unwrap();
+    assert_eq!(writer, bincode::serialize(&a).unwrap());
+
+    let deserialized: FheUint256 = bincode::deserialize_from(writer.as_slice()).unwrap();
+    let clear_deserialized: U256 = deserialized.decrypt(&client_key);
+    assert_eq!(clear_deserialized, clear);
+}
+
 #[test]
 fn test_integer_compressed() {
     let config = ConfigBuilder::default().build();
@@ -124,6 +141,12 @@ fn test_uint32_div_rem() {
     super::test_case_uint32_div_rem(&client_key);
 }
 
+#[test]
+fn test_uint64_div_rem() {
+    let client_key = setup_default_cpu();
+    super::test_case_uint64_div_rem(&client_key);
+}
+
 #[test]
 fn test_multi_div_rem() {
     let client_key = setup_cpu(Some(
@@ -241,6 +264,12 @@ fn test_trivial_uint256_small() {
     super::test_case_uint256_trivial(&client_key);
 }
 
+#[test]
+fn test_trivial_round_trip_and_mixing() {
+    let client_key = setup_default_cpu();
+    super::test_case_trivial_round_trip_and_mixing(&client_key);
+}
+
 #[test]
 fn test_integer_casting() {
     let config = ConfigBuilder::default().build();
@@ -390,12 +419,153 @@ fn test_leading_trailing_zeros_ones() {
     super::test_case_leading_trailing_zeros_ones(&client_key);
 }
 
+#[test]
+fn test_trailing_zeros_edge_cases() {
+    let client_key = setup_default_cpu();
+    super::test_case_trailing_zeros_edge_cases(&client_key);
+}
+
 #[test]
 fn test_sum() {
     let client_key = setup_default_cpu();
     super::test_case_sum(&client_key);
 }
 
+#[test]
+fn test_sum_uint64() {
+    let client_key = setup_default_cpu();
+    super::test_case_sum_uint64(&client_key);
+}
+
+#[test]
+fn test_pow() {
+    let client_key = setup_default_cpu();
+    super::test_case_pow(&client_key);
+}
+
+#[test]
+fn test_clamp() {
+    let client_key = setup_default_cpu();
+    super::test_case_clamp(&client_key);
+}
+
+#[test]
+fn test_narrowing_cast_with_flag() {
+    let client_key = setup_default_cpu();
+    super::test_case_narrowing_cast_with_flag(&client_key);
+}
+
+#[test]
+fn test_dot_product() {
+    let client_key = setup_default_cpu();
+    super::test_case_dot_product(&client_key);
+}
+
+#[test]
+fn test_map_lut() {
+    let client_key = setup_default_cpu();
+    super::test_case_map_lut(&client_key);
+}
+
+#[test]
+fn test_increment_counter() {
+    let client_key = setup_default_cpu();
+    super::test_case_increment_counter(&client_key);
+}
+
+#[test]
+fn test_sort() {
+    let client_key = setup_default_cpu();
+    super::test_case_sort(&client_key);
+}
+
+#[test]
+fn test_pow_mod() {
+    let client_key = setup_default_cpu();
+    super::test_case_pow_mod(&client_key);
+}
+
+#[test]
+fn test_saturating_add_sub() {
+    let client_key = setup_default_cpu();
+    super::test_case_saturating_add_sub(&client_key);
+}
+
+#[test]
+fn test_widening_mul() {
+    let client_key = setup_default_cpu();
+    super::test_case_widening_mul(&client_key);
+}
+
+#[test]
+fn test_le_bytes() {
+    let client_key = setup_default_cpu();
+    super::test_case_le_bytes(&client_key);
+}
+
+/// Sets up keys for parameters with 3 bits blocks, whose number of
+/// blocks does not exactly match the number of bits of the types
+///
+/// The keys are built from their raw parts, as key generation
+/// only supports 1 and 2 bits blocks
+fn setup_cpu_3_bits_blocks() -> ClientKey {
+    let client_key = ClientKey::from_raw_parts(
+        crate::integer::ClientKey::new(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128),
+        None,
+        None,
+        crate::Tag::default(),
+    );
+    set_server_key(ServerKey::new(&client_key));
+    client_key
+}
+
+#[test]
+fn test_le_bytes_3_bits_blocks() {
+    let client_key = setup_cpu_3_bits_blocks();
+
+    // With 3 bits blocks, a FheUint8 holds 6 bits and a FheUint32 30 bits
+    let mut rng = thread_rng();
+    for clear in [0u32, u32::MAX, 0x1234_5678, rng.gen::<u32>()] {
+        let expected = (clear & 0x3fff_ffff).to_le_bytes().map(|byte| byte & 0x3f);
+
+        let a = FheUint32::encrypt(clear, &client_key);
+        let bytes = a.to_le_bytes();
+        // Decrypted on a wider type, so that bits of the next byte would be seen
+        let decrypted: Vec<u16> = bytes.iter().map(|ct| ct.decrypt(&client_key)).collect();
+        assert_eq!(
+            decrypted,
+            expected.map(u16::from),
+            "Invalid to_le_bytes of {clear:#x}"
+        );
+
+        let b = FheUint32::from_le_bytes(&bytes);
+        let decrypted: u32 = b.decrypt(&client_key);
+        assert_eq!(
+            decrypted,
+            u32::from_le_bytes(expected),
+            "Invalid round trip of {clear:#x}"
+        );
+    }
+}
+
+#[test]
+fn test_reverse_bits() {
+    let client_key = setup_default_cpu();
+    super::test_case_reverse_bits(&client_key);
+}
+
+#[test]
+fn test_is_power_of_two() {
+    let client_key = setup_default_cpu();
+    super::test_case_is_power_of_two(&client_key);
+}
+
+#[test]
+fn test_min_max() {
+    let client_key = setup_default_cpu();
+    super::test_case_min_max(&client_key);
+}
+
 #[test]
 fn test_safe_deserialize_conformant_fhe_uint32() {
     let block_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
@@ -581,3 +751,331 @@ fn test_compressed_cpk_encrypt_cast_compute_hl() {
     let clear: u64 = mul.decrypt(&client_key);
     assert_eq!(clear, (input_msg * multiplier) % modulus);
 }
+
+#[test]
+fn test_encrypt_slice() {
+    let client_key = setup_default_cpu();
+    let mut rng = rand::thread_rng();
+
+    let clears = (0..1000).map(|_| rng.gen::<u64>()).collect::<Vec<_>>();
+
+    // Use an explicit pool so the encryptions are spread over several threads
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+    let thread_indices = std::sync::Mutex::new(std::collections::HashSet::new());
+    let encrypted = pool.install(|| {
+        client_key.encrypt_slice_inspect(&clears, |_| {
+            thread_indices
+                .lock()
+                .unwrap()
+                .insert(rayon::current_thread_index());
+        })
+    });
+    assert_eq!(encrypted.len(), clears.len());
+    let thread_indices = thread_indices.into_inner().unwrap();
+    assert!(
+        !thread_indices.contains(&None),
+        "Encryptions must run on the pool threads"
+    );
+    assert!(
+        thread_indices.len() > 1,
+        "Encryptions were not spread over several threads"
+    );
+
+    let decrypted = encrypted
+        .iter()
+        .map(|ct| ct.decrypt(&client_key))
+        .collect::<Vec<u64>>();
+    assert_eq!(decrypted, clears);
+
+    // The same value must be encrypted with fresh randomness each time,
+    // even when encrypted by different threads
+    let encrypted = pool.install(|| client_key.encrypt_slice(&[42; 8]));
+    for (i, a) in encrypted.iter().enumerate() {
+        let decrypted: u64 = a.decrypt(&client_key);
+        assert_eq!(decrypted, 42);
+        for b in &encrypted[i + 1..] {
+            assert_ne!(
+                bincode::serialize(a).unwrap(),
+                bincode::serialize(b).unwrap()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_lazy_carry_propagation() {
+    use crate::CarryPropagationStrategy;
+
+    let mut rng = thread_rng();
+    let clears = (0..4).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+    let expected = clears[0]
+        .wrapping_add(clears[1])
+        .wrapping_add(clears[2])
+        .wrapping_sub(clears[3]);
+
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    assert_eq!(
+        server_key.carry_propagation(),
+        CarryPropagationStrategy::Eager
+    );
+
+    let cts = clears
+        .iter()
+        .map(|clear| FheUint8::encrypt(*clear, &client_key))
+        .collect::<Vec<_>>();
+
+    #[cfg(feature = "pbs-stats")]
+    let mut pbs_counts = vec![];
+    for strategy in [
+        CarryPropagationStrategy::Eager,
+        CarryPropagationStrategy::Lazy,
+    ] {
+        let mut server_key = server_key.clone();
+        server_key.set_carry_propagation(strategy);
+        assert_eq!(server_key.carry_propagation(), strategy);
+        set_server_key(server_key.clone());
+
+        let chained_ops = || {
+            let mut result = &cts[0] + &cts[1];
+            result += &cts[2];
+            &result - &cts[3]
+        };
+
+        // The count is done in a dedicated scope, so that PBS done by
+        // tests running in parallel are not counted
+        #[cfg(feature = "pbs-stats")]
+        let result = {
+            let (result, pbs_count) =
+                crate::shortint::server_key::pbs_stats::count_pbs_in_scope(|| {
+                    set_server_key(server_key.clone());
+                    chained_ops()
+                });
+            pbs_counts.push(pbs_count);
+            result
+        };
+        #[cfg(not(feature = "pbs-stats"))]
+        let result = chained_ops();
+
+        let decrypted: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted, expected, "Invalid result for {strategy:?}");
+
+        // Operations needing clean carries still work on the lazy result
+        let product = &result * &cts[0];
+        let decrypted: u8 = product.decrypt(&client_key);
+        assert_eq!(decrypted, expected.wrapping_mul(clears[0]));
+
+        let sum = [result.clone(), cts[1].clone()].iter().sum::<FheUint8>();
+        let decrypted: u8 = sum.decrypt(&client_key);
+        assert_eq!(decrypted, expected.wrapping_add(clears[1]));
+    }
+
+    #[cfg(feature = "pbs-stats")]
+    assert!(
+        pbs_counts[1] < pbs_counts[0],
+        "Lazy carry propagation used {} PBS, eager used {} PBS",
+        pbs_counts[1],
+        pbs_counts[0]
+    );
+
+    // The strategy is a runtime setting, it is not serialized with the key
+    let mut server_key = server_key;
+    server_key.set_carry_propagation(CarryPropagationStrategy::Lazy);
+
+    let serialized = bincode::serialize(&server_key).unwrap();
+    let deserialized: ServerKey = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(
+        deserialized.carry_propagation(),
+        CarryPropagationStrategy::Eager
+    );
+
+    let mut serialized = vec![];
+    crate::safe_serialization::safe_serialize(&server_key, &mut serialized, 1 << 30).unwrap();
+    let deserialized: ServerKey =
+        crate::safe_serialization::safe_deserialize(serialized.as_slice(), 1 << 30).unwrap();
+    assert_eq!(
+        deserialized.carry_propagation(),
+        CarryPropagationStrategy::Eager
+    );
+}
+
+#[test]
+fn test_only_additions_server_key() {
+    let config = ConfigBuilder::default().use_only_additions().build();
+    let client_key = ClientKey::generate(config);
+    let compressed_server_key = CompressedServerKey::new(&client_key);
+
+    let full_client_key = ClientKey::generate(ConfigBuilder::default());
+    let full_compressed_server_key = CompressedServerKey::new(&full_client_key);
+
+    let serialized = bincode::serialize(&compressed_server_key).unwrap();
+    let full_serialized = bincode::serialize(&full_compressed_server_key).unwrap();
+    assert!(
+        serialized.len() * 100 < full_serialized.len(),
+        "Additions only key is {} bytes, full key is {} bytes",
+        serialized.len(),
+        full_serialized.len()
+    );
+
+    let compressed_server_key: CompressedServerKey = bincode::deserialize(&serialized).unwrap();
+    set_server_key(compressed_server_key.decompress());
+
+    let mut rng = thread_rng();
+    let clears = (0..3).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+    let cts = clears
+        .iter()
+        .map(|clear| FheUint8::encrypt(*clear, &client_key))
+        .collect::<Vec<_>>();
+
+    let mut result = &cts[0] + &cts[1];
+    result += &cts[2];
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(
+        decrypted,
+        clears[0].wrapping_add(clears[1]).wrapping_add(clears[2])
+    );
+
+    assert!(cts[0].try_mul(&cts[1]).is_err());
+}
+
+#[test]
+fn test_get_and_set_bit() {
+    let client_key = setup_default_cpu();
+    let mut rng = thread_rng();
+
+    let clear = rng.gen::<u16>();
+    let a = FheUint16::encrypt(clear, &client_key);
+
+    // Out of range indices are included, they read false
+    for index in [0, 1, 7, 8, 15, 16, 100] {
+        let bit = a.get_bit(index);
+        let expected = clear.checked_shr(index).is_some_and(|v| v & 1 == 1);
+        assert_eq!(bit.decrypt(&client_key), expected, "Invalid bit {index}");
+    }
+
+    let mut expected = clear;
+    let mut a = a;
+    for index in [0, 3, 8, 13, 15] {
+        let value = rng.gen::<bool>();
+        a.set_bit(index, &FheBool::encrypt(value, &client_key));
+        if value {
+            expected |= 1 << index;
+        } else {
+            expected &= !(1 << index);
+        }
+
+        let decrypted: u16 = a.decrypt(&client_key);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid result after setting bit {index}"
+        );
+    }
+
+    // Setting an out of range bit is a no-op
+    a.set_bit(16, &FheBool::encrypt(true, &client_key));
+    let decrypted: u16 = a.decrypt(&client_key);
+    assert_eq!(decrypted, expected);
+}
+
+#[test]
+fn test_get_and_set_bit_3_bits_blocks() {
+    let client_key = setup_cpu_3_bits_blocks();
+
+    // The 5 blocks of a FheUint16 hold 15 bits, the 16th one is not encrypted
+    let clear = thread_rng().gen::<u16>() >> 1;
+    let mut a = FheUint16::encrypt(clear, &client_key);
+
+    for i in 0..17 {
+        let bit = a.get_bit(i).decrypt(&client_key);
+        assert_eq!(
+            bit,
+            i < 16 && (clear >> i) & 1 == 1,
+            "Invalid bit {i} of {clear}"
+        );
+    }
+
+    a.set_bit(17, &FheBool::encrypt(true, &client_key));
+    let decrypted: u16 = a.decrypt(&client_key);
+    assert_eq!(decrypted, clear);
+
+    a.set_bit(14, &FheBool::encrypt(true, &client_key));
+    let decrypted: u16 = a.decrypt(&client_key);
+    assert_eq!(decrypted, clear | (1 << 14));
+}
+
+#[test]
+fn test_expr_folds_scalars() {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key.clone());
+
+    let mut rng = thread_rng();
+    let clear = rng.gen::<u16>();
+    let x = FheUint16::encrypt(clear, &client_key);
+
+    let result = FheExpr::from(x.clone())
+        .add_scalar(3)
+        .add_scalar(5)
+        .sub_scalar(2)
+        .eval();
+    let decrypted: u16 = result.decrypt(&client_key);
+    assert_eq!(decrypted, clear.wrapping_add(6));
+
+    #[cfg(feature = "pbs-stats")]
+    {
+        use crate::shortint::server_key::pbs_stats::count_pbs_in_scope;
+
+        let (_, single_add_pbs) = count_pbs_in_scope(|| {
+            set_server_key(server_key.clone());
+            &x + 6u16
+        });
+        let (result, expr_pbs) = count_pbs_in_scope(|| {
+            set_server_key(server_key.clone());
+            FheExpr::from(x.clone())
+                .add_scalar(3)
+                .add_scalar(5)
+                .sub_scalar(2)
+                .eval()
+        });
+        assert_eq!(expr_pbs, single_add_pbs);
+        let decrypted: u16 = result.decrypt(&client_key);
+        assert_eq!(decrypted, clear.wrapping_add(6));
+
+        // Constants cancelling out do not need any PBS
+        let (_, pbs_count) = count_pbs_in_scope(|| {
+            set_server_key(server_key.clone());
+            FheExpr::from(x.clone()).add_scalar(7).sub_scalar(7).eval()
+        });
+        assert_eq!(pbs_count, 0);
+    }
+}
+
+#[test]
+fn test_expr_wraps_at_type_width() {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    let mut rng = thread_rng();
+    let clear = rng.gen::<u8>();
+    let x = FheUint8::encrypt(clear, &client_key);
+
+    let result = FheExpr::from(x.clone())
+        .add_scalar(250)
+        .add_scalar(10)
+        .sub_scalar(3)
+        .eval();
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(
+        decrypted,
+        clear.wrapping_add(250).wrapping_add(10).wrapping_sub(3)
+    );
+
+    // Net offset is negative
+    let result = FheExpr::from(x).sub_scalar(1000).add_scalar(1).eval();
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(
+        decrypted,
+        clear.wrapping_sub((1000 % 256) as u8).wrapping_add(1)
+    );
+}
@@ -74,6 +74,12 @@ fn test_trivial_uint256_small_gpu() {
     super::test_case_uint256_trivial(&client_key);
 }
 
+#[test]
+fn test_trivial_round_trip_and_mixing_gpu() {
+    let client_key = setup_default_gpu();
+    super::test_case_trivial_round_trip_and_mixing(&client_key);
+}
+
 #[test]
 fn test_uint32_bitwise_gpu() {
     let client_key = setup_default_gpu();
@@ -110,6 +116,12 @@ fn test_sum_gpu_multibit() {
     super::test_case_sum(&client_key);
 }
 
+#[test]
+fn test_sum_uint64_gpu() {
+    let client_key = setup_default_gpu();
+    super::test_case_sum_uint64(&client_key);
+}
+
 #[test]
 fn test_is_even_is_odd_gpu() {
     let client_key = setup_default_gpu();
@@ -128,6 +140,12 @@ fn test_leading_trailing_zeros_ones_gpu() {
     super::test_case_leading_trailing_zeros_ones(&client_key);
 }
 
+#[test]
+fn test_trailing_zeros_edge_cases_gpu() {
+    let client_key = setup_default_gpu();
+    super::test_case_trailing_zeros_edge_cases(&client_key);
+}
+
 #[test]
 fn test_leading_trailing_zeros_ones_gpu_multibit() {
     let client_key = setup_gpu(Some(PARAM_GPU_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS));
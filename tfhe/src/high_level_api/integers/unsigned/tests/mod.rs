@@ -1,7 +1,10 @@
 use crate::high_level_api::traits::BitSlice;
 use crate::integer::U256;
 use crate::prelude::*;
-use crate::{ClientKey, FheUint256, FheUint32, FheUint64, FheUint8};
+use crate::{
+    ClientKey, FheUint128, FheUint16, FheUint256, FheUint32, FheUint4, FheUint64, FheUint64Id,
+    FheUint8, FheUint8Id,
+};
 use rand::{thread_rng, Rng};
 
 mod cpu;
@@ -72,6 +75,30 @@ fn test_case_uint256_trivial(client_key: &ClientKey) {
     assert_eq!(clear, clear_a);
 }
 
+fn test_case_trivial_round_trip_and_mixing(cks: &ClientKey) {
+    let mut rng = thread_rng();
+
+    for _ in 0..5 {
+        let clear_a = rng.gen::<u32>();
+        let clear_b = rng.gen::<u32>();
+
+        // Round trip without the client key
+        let a = FheUint32::try_encrypt_trivial(clear_a).unwrap();
+        assert!(a.is_trivial());
+        let decrypted: u32 = a.try_decrypt_trivial().unwrap();
+        assert_eq!(decrypted, clear_a);
+
+        // Mixing with a real ciphertext gives a non trivial ciphertext
+        let b = FheUint32::encrypt(clear_b, cks);
+        assert!(!b.is_trivial());
+        let c = &a + &b;
+        assert!(!c.is_trivial());
+        assert!(c.try_decrypt_trivial::<u32>().is_err());
+        let decrypted: u32 = c.decrypt(cks);
+        assert_eq!(decrypted, clear_a.wrapping_add(clear_b));
+    }
+}
+
 #[allow(clippy::eq_op)]
 fn test_case_uint8_compare(client_key: &ClientKey) {
     let clear_a = 27u8;
@@ -401,6 +428,42 @@ fn test_case_uint32_div_rem(cks: &ClientKey) {
     }
 }
 
+fn test_case_uint64_div_rem(cks: &ClientKey) {
+    // Makes sure `/` and `%` can be used from code that is generic over the type
+    fn generic_div_rem<T>(a: &T, b: &T) -> (T, T)
+    where
+        for<'a> &'a T: std::ops::Div<&'a T, Output = T> + std::ops::Rem<&'a T, Output = T>,
+    {
+        (a / b, a % b)
+    }
+
+    let mut rng = rand::thread_rng();
+
+    let clear_a = rng.gen::<u64>();
+    let clear_b = rng.gen_range(1u64..=u64::MAX);
+
+    let a = FheUint64::encrypt(clear_a, cks);
+    let b = FheUint64::encrypt(clear_b, cks);
+
+    let (q, r) = generic_div_rem(&a, &b);
+    let decrypted_q: u64 = q.decrypt(cks);
+    let decrypted_r: u64 = r.decrypt(cks);
+    assert_eq!(decrypted_q, clear_a / clear_b);
+    assert_eq!(decrypted_r, clear_a % clear_b);
+
+    // Division by an encrypted zero: quotient is the max value,
+    // remainder is the numerator
+    let clear_a = rng.gen::<u64>();
+    let a = FheUint64::encrypt(clear_a, cks);
+    let b = FheUint64::encrypt(0u64, cks);
+
+    let (q, r) = generic_div_rem(&a, &b);
+    let decrypted_q: u64 = q.decrypt(cks);
+    let decrypted_r: u64 = r.decrypt(cks);
+    assert_eq!(decrypted_q, u64::MAX);
+    assert_eq!(decrypted_r, clear_a);
+}
+
 fn test_case_if_then_else(client_key: &ClientKey) {
     let clear_a = 27u8;
     let clear_b = 128u8;
@@ -443,6 +506,16 @@ fn test_case_leading_trailing_zeros_ones(cks: &ClientKey) {
     }
 }
 
+fn test_case_trailing_zeros_edge_cases(cks: &ClientKey) {
+    // Zero has no set bit, the result is the bit width
+    for clear_a in [0u32, 1, 1 << 31, u32::MAX] {
+        let a = FheUint32::try_encrypt(clear_a, cks).unwrap();
+
+        let trailing_zeros: u32 = a.trailing_zeros().decrypt(cks);
+        assert_eq!(trailing_zeros, clear_a.trailing_zeros());
+    }
+}
+
 fn test_case_ilog2(cks: &ClientKey) {
     let mut rng = rand::thread_rng();
     for _ in 0..5 {
@@ -534,6 +607,432 @@ fn test_case_sum(client_key: &ClientKey) {
     }
 }
 
+fn test_case_sum_uint64(client_key: &ClientKey) {
+    let mut rng = thread_rng();
+
+    let num_ct = rng.gen_range(3..=6);
+    let clears = (0..num_ct).map(|_| rng.gen::<u64>()).collect::<Vec<_>>();
+
+    let expected_result = clears
+        .iter()
+        .copied()
+        .fold(0u64, |acc, x| acc.wrapping_add(x));
+
+    let ciphertexts = clears
+        .iter()
+        .copied()
+        .map(|clear| FheUint64::encrypt(clear, client_key))
+        .collect::<Vec<_>>();
+
+    let sum: u64 = ciphertexts.iter().sum::<FheUint64>().decrypt(client_key);
+    assert_eq!(sum, expected_result);
+
+    let sum: u64 = ciphertexts
+        .into_iter()
+        .sum::<FheUint64>()
+        .decrypt(client_key);
+    assert_eq!(sum, expected_result);
+
+    // Empty iterators give a trivial zero
+    let sum = std::iter::empty::<FheUint64>().sum::<FheUint64>();
+    assert!(sum.is_trivial());
+    let sum: u64 = sum.decrypt(client_key);
+    assert_eq!(sum, 0);
+
+    let sum = Vec::<FheUint64>::new().iter().sum::<FheUint64>();
+    assert!(sum.is_trivial());
+    let sum: u64 = sum.decrypt(client_key);
+    assert_eq!(sum, 0);
+}
+
+fn test_case_pow(cks: &ClientKey) {
+    let mut rng = thread_rng();
+
+    for exponent in [0u32, 1, rng.gen_range(2..12)] {
+        let clear_a = rng.gen_range(0..16u32);
+        let a = FheUint32::encrypt(clear_a, cks);
+
+        let result: u32 = a.pow(exponent).decrypt(cks);
+        assert_eq!(
+            result,
+            clear_a.wrapping_pow(exponent),
+            "Invalid result for {clear_a}.pow({exponent})"
+        );
+    }
+
+    // Wraps around
+    let clear_a = rng.gen::<u32>();
+    let exponent = rng.gen_range(2..6u32);
+    let a = FheUint32::encrypt(clear_a, cks);
+    let result: u32 = a.pow(exponent).decrypt(cks);
+    assert_eq!(
+        result,
+        clear_a.wrapping_pow(exponent),
+        "Invalid result for {clear_a}.pow({exponent})"
+    );
+
+    // Encrypted exponent
+    for clear_exponent in [0u8, rng.gen_range(1..12)] {
+        let clear_a = rng.gen_range(0..16u32);
+        let a = FheUint32::encrypt(clear_a, cks);
+        let exponent = FheUint8::encrypt(clear_exponent, cks);
+
+        let result: u32 = a.pow(&exponent).decrypt(cks);
+        assert_eq!(
+            result,
+            clear_a.wrapping_pow(clear_exponent as u32),
+            "Invalid result for {clear_a}.pow({clear_exponent})"
+        );
+    }
+}
+
+fn test_case_min_max(cks: &ClientKey) {
+    let mut rng = thread_rng();
+
+    for _ in 0..3 {
+        let clear_a = rng.gen::<u8>();
+        let clear_b = rng.gen::<u8>();
+
+        let a = FheUint8::encrypt(clear_a, cks);
+        let b = FheUint8::encrypt(clear_b, cks);
+
+        let min: u8 = a.min(&b).decrypt(cks);
+        assert_eq!(min, std::cmp::min(clear_a, clear_b));
+        let max: u8 = a.max(&b).decrypt(cks);
+        assert_eq!(max, std::cmp::max(clear_a, clear_b));
+
+        let min: u8 = FheUint8::min(&a, b.clone()).decrypt(cks);
+        assert_eq!(min, std::cmp::min(clear_a, clear_b));
+        let max: u8 = FheUint8::max(&a, b).decrypt(cks);
+        assert_eq!(max, std::cmp::max(clear_a, clear_b));
+    }
+}
+
+fn test_case_clamp(cks: &ClientKey) {
+    let clear_min = 50u8;
+    let clear_max = 200u8;
+
+    let min = FheUint8::encrypt(clear_min, cks);
+    let max = FheUint8::encrypt(clear_max, cks);
+
+    // below, in-range, above
+    for clear in [10u8, 120, 250] {
+        let a = FheUint8::encrypt(clear, cks);
+        let result: u8 = a.clamp(&min, &max).decrypt(cks);
+        assert_eq!(
+            result,
+            clear.clamp(clear_min, clear_max),
+            "Invalid result for {clear}.clamp({clear_min}, {clear_max})"
+        );
+    }
+
+    // min > max returns max
+    let a = FheUint8::encrypt(120u8, cks);
+    let result: u8 = a.clamp(&max, &min).decrypt(cks);
+    assert_eq!(result, clear_min);
+}
+
+fn test_case_narrowing_cast_with_flag(cks: &ClientKey) {
+    // Does not fit, saturates
+    let a = FheUint32::encrypt(300u32, cks);
+    let (b, overflowed) = a.narrowing_cast_with_flag::<FheUint8Id>();
+    let decrypted: u8 = b.decrypt(cks);
+    assert_eq!(decrypted, u8::MAX);
+    assert!(overflowed.decrypt(cks));
+
+    // Fits
+    let a = FheUint32::encrypt(100u32, cks);
+    let (b, overflowed) = a.narrowing_cast_with_flag::<FheUint8Id>();
+    let decrypted: u8 = b.decrypt(cks);
+    assert_eq!(decrypted, 100);
+    assert!(!overflowed.decrypt(cks));
+
+    // Widening never overflows
+    let a = FheUint32::encrypt(u32::MAX, cks);
+    let (b, overflowed) = a.narrowing_cast_with_flag::<FheUint64Id>();
+    let decrypted: u64 = b.decrypt(cks);
+    assert_eq!(decrypted, u64::from(u32::MAX));
+    assert!(!overflowed.decrypt(cks));
+}
+
+fn test_case_dot_product(cks: &ClientKey) {
+    let mut rng = thread_rng();
+
+    for len in [0usize, 1, 4] {
+        let clear_lhs = (0..len).map(|_| rng.gen::<u32>()).collect::<Vec<_>>();
+        let clear_rhs = (0..len).map(|_| rng.gen::<u32>()).collect::<Vec<_>>();
+
+        let lhs = clear_lhs
+            .iter()
+            .map(|v| FheUint32::encrypt(*v, cks))
+            .collect::<Vec<_>>();
+        let rhs = clear_rhs
+            .iter()
+            .map(|v| FheUint32::encrypt(*v, cks))
+            .collect::<Vec<_>>();
+
+        let result = FheUint32::dot_product::<FheUint64Id>(&lhs, &rhs);
+        let decrypted: u64 = result.decrypt(cks);
+
+        let expected = clear_lhs
+            .iter()
+            .zip(clear_rhs.iter())
+            .map(|(l, r)| u64::from(*l) * u64::from(*r))
+            .fold(0u64, u64::wrapping_add);
+        assert_eq!(decrypted, expected, "Invalid dot product for length {len}");
+    }
+}
+
+fn test_case_map_lut(cks: &ClientKey) {
+    // Full table for a 4 bits input, with outputs that need to be truncated
+    let table = (0..16u64).map(|x| x * 3).collect::<Vec<_>>();
+    for clear in 0..16u8 {
+        let a = FheUint4::encrypt(clear, cks);
+        let decrypted: u8 = a.map_lut(&table).decrypt(cks);
+        assert_eq!(
+            u64::from(decrypted),
+            table[clear as usize] % 16,
+            "Invalid result for table[{clear}]"
+        );
+    }
+
+    // Partial table for an 8 bits input, out of range values map to 0
+    let mut rng = thread_rng();
+    let table = (0..20u64)
+        .map(|_| rng.gen_range(0..=255u64))
+        .collect::<Vec<_>>();
+    for clear in [0u8, 5, 19, 20, 200] {
+        let a = FheUint8::encrypt(clear, cks);
+        let decrypted: u8 = a.map_lut(&table).decrypt(cks);
+        let expected = table.get(clear as usize).copied().unwrap_or(0);
+        assert_eq!(
+            u64::from(decrypted),
+            expected,
+            "Invalid result for table[{clear}]"
+        );
+    }
+}
+
+fn test_case_increment_counter(cks: &ClientKey) {
+    let encrypt_counter = |clear: u32| {
+        clear
+            .to_be_bytes()
+            .iter()
+            .map(|byte| FheUint8::encrypt(*byte, cks))
+            .collect::<Vec<_>>()
+    };
+    let decrypt_counter = |counter: &[FheUint8]| {
+        let bytes = counter
+            .iter()
+            .map(|ct| ct.decrypt(cks))
+            .collect::<Vec<u8>>();
+        u32::from_be_bytes(bytes.try_into().unwrap())
+    };
+
+    let mut rng = thread_rng();
+    for clear in [0x00FF, 0x00FF_FFFF, u32::MAX, rng.gen::<u32>()] {
+        let mut counter = encrypt_counter(clear);
+        FheUint8::increment_counter(&mut counter);
+        assert_eq!(
+            decrypt_counter(&counter),
+            clear.wrapping_add(1),
+            "Invalid result when incrementing counter {clear:#x}"
+        );
+    }
+}
+
+fn test_case_sort(cks: &ClientKey) {
+    let mut rng = thread_rng();
+    // Power of two and non power of two lengths
+    for len in [0, 1, 4, 7] {
+        let clears = (0..len).map(|_| rng.gen::<u16>()).collect::<Vec<_>>();
+        let mut values = clears
+            .iter()
+            .map(|v| FheUint16::encrypt(*v, cks))
+            .collect::<Vec<_>>();
+
+        FheUint16::sort(&mut values);
+
+        let decrypted: Vec<u16> = values.iter().map(|ct| ct.decrypt(cks)).collect();
+        let mut expected = clears.clone();
+        expected.sort_unstable();
+        assert_eq!(decrypted, expected, "Invalid sort of {clears:?}");
+    }
+}
+
+fn test_case_pow_mod(cks: &ClientKey) {
+    let clear_pow_mod = |base: u16, exponent: u8, modulus: u64| {
+        (0..exponent).fold(1 % modulus, |acc, _| (acc * base as u64) % modulus)
+    };
+
+    let mut rng = thread_rng();
+    for modulus in [1u64, 2, 7, 13, 251] {
+        let clear_base = rng.gen::<u16>();
+        let clear_exponent = rng.gen_range(0..16u8);
+
+        let base = FheUint16::encrypt(clear_base, cks);
+        let exponent = FheUint8::encrypt(clear_exponent, cks);
+
+        let result = base.pow_mod(&exponent, modulus);
+        let decrypted: u16 = result.decrypt(cks);
+        assert_eq!(
+            decrypted as u64,
+            clear_pow_mod(clear_base, clear_exponent, modulus),
+            "Invalid result for {clear_base}.pow({clear_exponent}) % {modulus}"
+        );
+    }
+}
+
+fn test_case_saturating_add_sub(cks: &ClientKey) {
+    let mut rng = thread_rng();
+    let cases = [
+        (200u8, 100u8),
+        (u8::MAX, 1),
+        (u8::MAX, u8::MAX),
+        (0, 1),
+        (0, u8::MAX),
+        (0, 0),
+        (rng.gen(), rng.gen()),
+    ];
+
+    for (clear_a, clear_b) in cases {
+        let a = FheUint8::encrypt(clear_a, cks);
+        let b = FheUint8::encrypt(clear_b, cks);
+
+        let result = (&a).saturating_add(&b);
+        let decrypted: u8 = result.decrypt(cks);
+        assert_eq!(
+            decrypted,
+            clear_a.saturating_add(clear_b),
+            "Invalid result for {clear_a}.saturating_add({clear_b})"
+        );
+
+        let result = a.saturating_sub(&b);
+        let decrypted: u8 = result.decrypt(cks);
+        assert_eq!(
+            decrypted,
+            clear_a.saturating_sub(clear_b),
+            "Invalid result for {clear_a}.saturating_sub({clear_b})"
+        );
+    }
+}
+
+fn test_case_widening_mul(cks: &ClientKey) {
+    let mut rng = thread_rng();
+    for _ in 0..3 {
+        let clear_a = rng.gen::<u64>();
+        let clear_b = rng.gen::<u64>();
+
+        let a = FheUint64::encrypt(clear_a, cks);
+        let b = FheUint64::encrypt(clear_b, cks);
+
+        let c: FheUint128 = (&a).widening_mul(&b);
+        let decrypted: u128 = c.decrypt(cks);
+        assert_eq!(
+            decrypted,
+            clear_a as u128 * clear_b as u128,
+            "Invalid widening_mul of {clear_a} and {clear_b}"
+        );
+    }
+
+    let a = FheUint64::encrypt(u64::MAX, cks);
+    let b = a.clone();
+    let c: FheUint128 = a.widening_mul(&b);
+    let decrypted: u128 = c.decrypt(cks);
+    assert_eq!(decrypted, u64::MAX as u128 * u64::MAX as u128);
+}
+
+fn test_case_le_bytes(cks: &ClientKey) {
+    let mut rng = thread_rng();
+    for clear in [
+        0u32,
+        u32::MAX,
+        0x1234_5678,
+        rng.gen::<u32>(),
+        rng.gen::<u32>(),
+    ] {
+        let a = FheUint32::encrypt(clear, cks);
+
+        let bytes = a.to_le_bytes();
+        // Decrypted on a wider type, so that bits above the byte would be seen
+        let decrypted: Vec<u16> = bytes.iter().map(|ct| ct.decrypt(cks)).collect();
+        assert_eq!(
+            decrypted,
+            clear.to_le_bytes().map(u16::from),
+            "Invalid to_le_bytes of {clear:#x}"
+        );
+
+        // Round trip
+        let b = FheUint32::from_le_bytes(&bytes);
+        let decrypted: u32 = b.decrypt(cks);
+        assert_eq!(decrypted, clear, "Invalid round trip of {clear:#x}");
+
+        // From independently encrypted bytes
+        let bytes = clear.to_le_bytes().map(|byte| FheUint8::encrypt(byte, cks));
+        let b = FheUint32::from_le_bytes(&bytes);
+        let decrypted: u32 = b.decrypt(cks);
+        assert_eq!(decrypted, clear, "Invalid from_le_bytes of {clear:#x}");
+    }
+}
+
+fn test_case_reverse_bits(cks: &ClientKey) {
+    let a = FheUint8::encrypt(0b0000_0001u8, cks);
+    let decrypted: u8 = a.reverse_bits().decrypt(cks);
+    assert_eq!(decrypted, 0b1000_0000);
+
+    let mut rng = thread_rng();
+    for _ in 0..5 {
+        let clear_a = rng.gen::<u16>();
+        let a = FheUint16::encrypt(clear_a, cks);
+        let decrypted: u16 = a.reverse_bits().decrypt(cks);
+        assert_eq!(
+            decrypted,
+            clear_a.reverse_bits(),
+            "Invalid reverse_bits result for {clear_a:#018b}"
+        );
+
+        let clear_a = rng.gen::<u32>();
+        let a = FheUint32::encrypt(clear_a, cks);
+        let decrypted: u32 = a.reverse_bits().decrypt(cks);
+        assert_eq!(
+            decrypted,
+            clear_a.reverse_bits(),
+            "Invalid reverse_bits result for {clear_a:#034b}"
+        );
+    }
+}
+
+fn test_case_is_power_of_two(cks: &ClientKey) {
+    let a = FheUint16::encrypt(0u16, cks);
+    let decrypted = a.is_power_of_two().decrypt(cks);
+    assert!(!decrypted, "0 must not be a power of two");
+
+    for i in 0..16 {
+        let clear_a = 1u16 << i;
+        let a = FheUint16::encrypt(clear_a, cks);
+        let decrypted = a.is_power_of_two().decrypt(cks);
+        assert!(decrypted, "{clear_a} must be a power of two");
+    }
+
+    for clear_a in [3u16, 5, 6, 12, 100, 255, 1023, u16::MAX] {
+        let a = FheUint16::encrypt(clear_a, cks);
+        let decrypted = a.is_power_of_two().decrypt(cks);
+        assert!(!decrypted, "{clear_a} must not be a power of two");
+    }
+
+    let mut rng = thread_rng();
+    for _ in 0..5 {
+        let clear_a = rng.gen::<u16>();
+        let a = FheUint16::encrypt(clear_a, cks);
+        let decrypted = a.is_power_of_two().decrypt(cks);
+        assert_eq!(
+            decrypted,
+            clear_a.is_power_of_two(),
+            "Invalid is_power_of_two result for {clear_a}"
+        );
+    }
+}
+
 fn test_case_is_even_is_odd(cks: &ClientKey) {
     let mut rng = rand::thread_rng();
     // This operation is cheap
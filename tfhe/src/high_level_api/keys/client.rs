@@ -8,9 +8,10 @@ use crate::high_level_api::config::Config;
 use crate::high_level_api::keys::{CompactPrivateKey, IntegerClientKey};
 use crate::integer::compression_keys::CompressionPrivateKeys;
 use crate::named::Named;
-use crate::prelude::Tagged;
+use crate::prelude::{FheEncrypt, Tagged};
 use crate::shortint::MessageModulus;
-use crate::Tag;
+use crate::{FheBool, FheUint64, Tag};
+use rayon::prelude::*;
 use tfhe_csprng::seeders::Seed;
 use tfhe_versionable::Versionize;
 
@@ -118,6 +119,81 @@ impl ClientKey {
         CompressedServerKey::new(self)
     }
 
+    /// Encrypts a slice of clear values into [FheUint64]
+    ///
+    /// The encryptions are done in parallel, which is faster than
+    /// encrypting each value one after the other. Each thread uses its own
+    /// random generator, and the output is in the same order as the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{ClientKey, ConfigBuilder};
+    ///
+    /// let client_key = ClientKey::generate(ConfigBuilder::default());
+    ///
+    /// let clears = [1u64, 2, 3, u64::MAX];
+    /// let encrypted = client_key.encrypt_slice(&clears);
+    ///
+    /// let decrypted = encrypted
+    ///     .iter()
+    ///     .map(|ct| ct.decrypt(&client_key))
+    ///     .collect::<Vec<u64>>();
+    /// assert_eq!(decrypted, clears);
+    /// ```
+    pub fn encrypt_slice(&self, clears: &[u64]) -> Vec<FheUint64> {
+        self.par_encrypt_slice(clears).collect()
+    }
+
+    /// Same as [Self::encrypt_slice], but calls `inspect` on each encrypted value,
+    /// from the thread that encrypted it
+    #[cfg(test)]
+    pub(crate) fn encrypt_slice_inspect<F>(&self, clears: &[u64], inspect: F) -> Vec<FheUint64>
+    where
+        F: Fn(&FheUint64) + Send + Sync,
+    {
+        self.par_encrypt_slice(clears).inspect(inspect).collect()
+    }
+
+    fn par_encrypt_slice<'a>(
+        &'a self,
+        clears: &'a [u64],
+    ) -> impl IndexedParallelIterator<Item = FheUint64> + 'a {
+        clears
+            .par_iter()
+            .map(|clear| FheUint64::encrypt(*clear, self))
+    }
+
+    /// Decrypts a slice of [FheBool]
+    ///
+    /// The decryptions are done in parallel, which is faster than
+    /// decrypting each [FheBool] one after the other.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{ClientKey, ConfigBuilder, FheBool};
+    ///
+    /// let client_key = ClientKey::generate(ConfigBuilder::default());
+    ///
+    /// let clears = [true, false, false, true];
+    /// let flags = clears
+    ///     .iter()
+    ///     .map(|b| FheBool::encrypt(*b, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let decrypted = client_key.decrypt_bools(&flags);
+    /// assert_eq!(decrypted, clears);
+    /// ```
+    pub fn decrypt_bools(&self, flags: &[FheBool]) -> Vec<bool> {
+        flags
+            .par_iter()
+            .map(|flag| self.key.key.decrypt_bool(&flag.ciphertext.on_cpu()))
+            .collect()
+    }
+
     pub(crate) fn message_modulus(&self) -> MessageModulus {
         self.key.block_parameters().message_modulus()
     }
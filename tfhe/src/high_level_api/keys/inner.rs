@@ -30,6 +30,7 @@ pub(crate) struct IntegerConfig {
         crate::shortint::parameters::ShortintKeySwitchingParameters,
     )>,
     pub(crate) compression_parameters: Option<CompressionParameters>,
+    pub(crate) only_additions: bool,
 }
 
 impl IntegerConfig {
@@ -44,6 +45,7 @@ impl IntegerConfig {
             block_parameters,
             dedicated_compact_public_key_parameters,
             compression_parameters: None,
+            only_additions: false,
         }
     }
 
@@ -76,6 +78,7 @@ impl Default for IntegerConfig {
             block_parameters: params,
             dedicated_compact_public_key_parameters: None,
             compression_parameters: None,
+            only_additions: false,
         }
     }
 }
@@ -91,6 +94,9 @@ pub(crate) struct IntegerClientKey {
     pub(crate) key: crate::integer::ClientKey,
     pub(crate) dedicated_compact_private_key: Option<CompactPrivateKey>,
     pub(crate) compression_key: Option<CompressionPrivateKeys>,
+    /// Whether the server keys are generated without bootstrapping material,
+    /// see [crate::ConfigBuilder::use_only_additions]
+    pub(crate) only_additions: bool,
 }
 
 impl IntegerClientKey {
@@ -116,6 +122,7 @@ impl IntegerClientKey {
             key,
             dedicated_compact_private_key,
             compression_key,
+            only_additions: config.only_additions,
         }
     }
 
@@ -131,6 +138,7 @@ impl IntegerClientKey {
             key,
             dedicated_compact_private_key,
             compression_key,
+            only_additions: _,
         } = self;
         (key, dedicated_compact_private_key, compression_key)
     }
@@ -170,6 +178,7 @@ impl IntegerClientKey {
             key,
             dedicated_compact_private_key,
             compression_key,
+            only_additions: false,
         }
     }
 
@@ -199,6 +208,7 @@ impl From<IntegerConfig> for IntegerClientKey {
             key,
             dedicated_compact_private_key,
             compression_key,
+            only_additions: config.only_additions,
         }
     }
 }
@@ -219,6 +229,10 @@ pub struct IntegerServerKey {
 
 impl IntegerServerKey {
     pub(in crate::high_level_api) fn new(client_key: &IntegerClientKey) -> Self {
+        if client_key.only_additions {
+            return IntegerCompressedServerKey::new(client_key).decompress();
+        }
+
         let cks = &client_key.key;
 
         let (compression_key, decompression_key) = client_key.compression_key.as_ref().map_or_else(
@@ -258,6 +272,20 @@ impl IntegerServerKey {
         &self.key
     }
 
+    /// Returns an error if the key was generated without bootstrapping material,
+    /// see [crate::ConfigBuilder::use_only_additions]
+    pub(in crate::high_level_api) fn check_bootstrapping_material(&self) -> crate::Result<()> {
+        if self.key.key.has_bootstrapping_material() {
+            Ok(())
+        } else {
+            Err(crate::Error::new(
+                "The server key was generated with only additions enabled, \
+                it has no bootstrapping material for this operation"
+                    .to_string(),
+            ))
+        }
+    }
+
     pub(in crate::high_level_api) fn cpk_casting_key(
         &self,
     ) -> Option<crate::integer::key_switching_key::KeySwitchingKeyView> {
@@ -298,6 +326,19 @@ impl IntegerCompressedServerKey {
     pub(in crate::high_level_api) fn new(client_key: &IntegerClientKey) -> Self {
         let cks = &client_key.key;
 
+        if client_key.only_additions {
+            let key = crate::shortint::CompressedServerKey::new_without_bootstrapping_material(
+                cks.as_ref(),
+            );
+
+            return Self {
+                key: crate::integer::CompressedServerKey::from_raw_parts(key),
+                cpk_key_switching_key_material: None,
+                compression_key: None,
+                decompression_key: None,
+            };
+        }
+
         let key = crate::integer::CompressedServerKey::new_radix_compressed_server_key(cks);
 
         let cpk_key_switching_key_material =
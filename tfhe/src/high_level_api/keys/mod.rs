@@ -10,10 +10,10 @@ pub use client::ClientKey;
 pub(crate) use inner::CompactPrivateKey;
 pub use key_switching_key::KeySwitchingKey;
 pub use public::{CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey, PublicKey};
-#[cfg(feature = "gpu")]
-pub use server::CudaServerKey;
-pub(crate) use server::InternalServerKey;
 pub use server::{CompressedServerKey, ServerKey};
+#[cfg(feature = "gpu")]
+pub use server::{CudaServerKey, HybridServerKey};
+pub(crate) use server::{InternalServerKey, VersionizableServerKey};
 
 pub(in crate::high_level_api) use inner::{
     IntegerClientKey, IntegerCompactPublicKey, IntegerCompressedCompactPublicKey,
@@ -31,8 +31,10 @@ pub(in crate::high_level_api) use inner::{
 /// let (client_key, server_key) = generate_keys(config);
 /// ```
 pub fn generate_keys<C: Into<Config>>(config: C) -> (ClientKey, ServerKey) {
+    let config = config.into();
     let client_kc = ClientKey::generate(config);
-    let server_kc = client_kc.generate_server_key();
+    let mut server_kc = client_kc.generate_server_key();
+    server_kc.set_carry_propagation(config.carry_propagation);
 
     (client_kc, server_kc)
 }
@@ -4,7 +4,8 @@ use super::ClientKey;
 use crate::backward_compatibility::keys::{CompressedServerKeyVersions, ServerKeyVersions};
 use crate::conformance::ParameterSetConformant;
 #[cfg(feature = "gpu")]
-use crate::core_crypto::gpu::{synchronize_devices, CudaStreams};
+use crate::core_crypto::gpu::{get_number_of_gpus, synchronize_devices, CudaStreams};
+use crate::high_level_api::config::CarryPropagationStrategy;
 #[cfg(feature = "gpu")]
 use crate::high_level_api::keys::inner::IntegerCudaServerKey;
 use crate::high_level_api::keys::{IntegerCompressedServerKey, IntegerServerKey};
@@ -31,11 +32,48 @@ use std::sync::Arc;
 /// Keys are stored in an Arc, so that cloning them is cheap
 /// (compared to an actual clone hundreds of MB / GB), and cheap cloning is needed for
 /// multithreading with less overhead)
+///
+/// The [CarryPropagationStrategy] is a runtime setting of the key,
+/// it is not serialized and deserialized keys use the default strategy.
 #[derive(Clone, Versionize)]
-#[versionize(ServerKeyVersions)]
+#[versionize(convert = "VersionizableServerKey")]
 pub struct ServerKey {
     pub(crate) key: Arc<IntegerServerKey>,
     pub(crate) tag: Tag,
+    pub(crate) carry_propagation: CarryPropagationStrategy,
+}
+
+/// The versioned content of a [ServerKey]
+///
+/// The carry propagation strategy is not part of it, as it is an evaluation
+/// setting and not key material.
+#[derive(Clone, Versionize)]
+#[versionize(ServerKeyVersions)]
+pub struct VersionizableServerKey {
+    pub(crate) key: Arc<IntegerServerKey>,
+    pub(crate) tag: Tag,
+}
+
+impl From<ServerKey> for VersionizableServerKey {
+    fn from(value: ServerKey) -> Self {
+        let ServerKey {
+            key,
+            tag,
+            carry_propagation: _,
+        } = value;
+        Self { key, tag }
+    }
+}
+
+impl From<VersionizableServerKey> for ServerKey {
+    fn from(value: VersionizableServerKey) -> Self {
+        let VersionizableServerKey { key, tag } = value;
+        Self {
+            key,
+            tag,
+            carry_propagation: CarryPropagationStrategy::default(),
+        }
+    }
 }
 
 impl ServerKey {
@@ -43,6 +81,7 @@ impl ServerKey {
         Self {
             key: Arc::new(IntegerServerKey::new(&keys.key)),
             tag: keys.tag.clone(),
+            carry_propagation: CarryPropagationStrategy::default(),
         }
     }
 
@@ -88,9 +127,50 @@ impl ServerKey {
                 decompression_key,
             }),
             tag,
+            carry_propagation: CarryPropagationStrategy::default(),
         }
     }
 
+    /// Returns the strategy used to propagate the carries of integer operations
+    pub fn carry_propagation(&self) -> CarryPropagationStrategy {
+        self.carry_propagation
+    }
+
+    /// Sets the strategy used to propagate the carries of integer operations
+    ///
+    /// The strategy applies to the operations done after the key is given to
+    /// [set_server_key](crate::set_server_key).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, CarryPropagationStrategy, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, mut server_key) = generate_keys(ConfigBuilder::default());
+    /// server_key.set_carry_propagation(CarryPropagationStrategy::Lazy);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(200u8, &client_key);
+    /// let b = FheUint8::encrypt(100u8, &client_key);
+    /// let c = FheUint8::encrypt(7u8, &client_key);
+    ///
+    /// let result = &(&a + &b) + &c;
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 200u8.wrapping_add(100).wrapping_add(7));
+    /// ```
+    pub fn set_carry_propagation(&mut self, strategy: CarryPropagationStrategy) {
+        self.carry_propagation = strategy;
+    }
+
+    /// Returns the number of bytes `bincode` would produce when serializing `self`
+    ///
+    /// The size is computed without serializing into a buffer, this is useful
+    /// to know beforehand how much memory/disk space is needed to store the key.
+    pub fn serialized_size(&self) -> usize {
+        bincode::serialized_size(self).unwrap() as usize
+    }
+
     pub(in crate::high_level_api) fn pbs_key(&self) -> &crate::integer::ServerKey {
         self.key.pbs_key()
     }
@@ -192,6 +272,7 @@ impl<'de> serde::Deserialize<'de> for ServerKey {
         DeserializableServerKey::deserialize(deserializer).map(|deserialized| Self {
             key: Arc::new(deserialized.integer_key),
             tag: deserialized.tag,
+            carry_propagation: CarryPropagationStrategy::default(),
         })
     }
 }
@@ -256,6 +337,7 @@ impl CompressedServerKey {
         ServerKey {
             key: Arc::new(self.integer_key.decompress()),
             tag: self.tag.clone(),
+            carry_propagation: CarryPropagationStrategy::default(),
         }
     }
 
@@ -264,11 +346,26 @@ impl CompressedServerKey {
         self.decompress_to_specific_gpu(crate::CudaGpuChoice::default())
     }
 
+    /// # Panics
+    ///
+    /// Panics if the key was generated with
+    /// [ConfigBuilder::use_only_additions](crate::ConfigBuilder::use_only_additions),
+    /// as such keys are only supported on the CPU.
     #[cfg(feature = "gpu")]
     pub fn decompress_to_specific_gpu(
         &self,
         gpu_choice: impl Into<crate::CudaGpuChoice>,
     ) -> CudaServerKey {
+        assert_ne!(
+            self.integer_key
+                .key
+                .key
+                .bootstrapping_key
+                .input_lwe_dimension()
+                .0,
+            0,
+            "Server keys generated with only additions enabled are not supported on GPU"
+        );
         let streams = gpu_choice.into().build_streams();
         let key = crate::integer::gpu::CudaServerKey::decompress_from_cpu(
             &self.integer_key.key,
@@ -314,8 +411,18 @@ impl CompressedServerKey {
                 decompression_key,
             }),
             tag: self.tag.clone(),
+            cpu_fallback: None,
         }
     }
+
+    /// Decompresses the key for the CPU and, if a Cuda GPU is available, for the GPU
+    ///
+    /// See [HybridServerKey]
+    #[cfg(feature = "gpu")]
+    pub fn decompress_to_hybrid(&self) -> HybridServerKey {
+        let gpu_key = (get_number_of_gpus() > 0).then(|| self.decompress_to_gpu());
+        HybridServerKey::new(self.decompress(), gpu_key)
+    }
 }
 
 impl Tagged for CompressedServerKey {
@@ -337,6 +444,9 @@ impl Named for CompressedServerKey {
 pub struct CudaServerKey {
     pub(crate) key: Arc<IntegerCudaServerKey>,
     pub(crate) tag: Tag,
+    // Set when the key comes from a HybridServerKey,
+    // used to run operations not implemented on GPU
+    pub(crate) cpu_fallback: Option<ServerKey>,
 }
 
 #[cfg(feature = "gpu")]
@@ -373,6 +483,52 @@ impl Tagged for CudaServerKey {
     }
 }
 
+/// Server key holding the key material for the CPU and, optionally, for Cuda GPUs
+///
+/// When set with [set_server_key](crate::set_server_key):
+///
+/// - if there is a GPU key, ciphertexts are moved to the GPU and operations run on it. Operations
+///   that are not yet implemented on GPU fall back to the CPU: operands are copied to the host, the
+///   operation runs on CPU and the result is uploaded back to the GPU.
+/// - otherwise, all operations run on CPU.
+///
+/// With the `gpu-fallback-log` feature, operations falling back to the CPU are logged
+/// using the [log](https://docs.rs/log) crate.
+#[cfg(feature = "gpu")]
+#[derive(Clone)]
+pub struct HybridServerKey {
+    pub(crate) cpu_key: ServerKey,
+    pub(crate) gpu_key: Option<CudaServerKey>,
+}
+
+#[cfg(feature = "gpu")]
+impl HybridServerKey {
+    /// # Panics
+    ///
+    /// Panics if the keys do not have the same tag
+    pub fn new(cpu_key: ServerKey, gpu_key: Option<CudaServerKey>) -> Self {
+        if let Some(gpu_key) = &gpu_key {
+            assert_eq!(
+                cpu_key.tag, gpu_key.tag,
+                "The CPU and GPU keys must have the same tag"
+            );
+        }
+        Self { cpu_key, gpu_key }
+    }
+
+    pub fn cpu_key(&self) -> &ServerKey {
+        &self.cpu_key
+    }
+
+    pub fn gpu_key(&self) -> Option<&CudaServerKey> {
+        self.gpu_key.as_ref()
+    }
+
+    pub fn into_raw_parts(self) -> (ServerKey, Option<CudaServerKey>) {
+        (self.cpu_key, self.gpu_key)
+    }
+}
+
 pub enum InternalServerKey {
     Cpu(ServerKey),
     #[cfg(feature = "gpu")]
@@ -400,6 +556,18 @@ impl From<CudaServerKey> for InternalServerKey {
         Self::Cuda(value)
     }
 }
+#[cfg(feature = "gpu")]
+impl From<HybridServerKey> for InternalServerKey {
+    fn from(value: HybridServerKey) -> Self {
+        match value.gpu_key {
+            Some(mut gpu_key) => {
+                gpu_key.cpu_fallback = Some(value.cpu_key);
+                Self::Cuda(gpu_key)
+            }
+            None => Self::Cpu(value.cpu_key),
+        }
+    }
+}
 
 use crate::high_level_api::keys::inner::IntegerServerKeyConformanceParams;
 
@@ -407,7 +575,11 @@ impl ParameterSetConformant for ServerKey {
     type ParameterSet = IntegerServerKeyConformanceParams;
 
     fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
-        let Self { key, tag: _ } = self;
+        let Self {
+            key,
+            tag: _,
+            carry_propagation: _,
+        } = self;
 
         key.is_conformant(parameter_set)
     }
@@ -48,18 +48,20 @@ macro_rules! export_concrete_array_types {
 
 pub use crate::core_crypto::commons::math::random::Seed;
 pub use crate::integer::server_key::MatchValues;
-pub use config::{Config, ConfigBuilder};
+pub use config::{CarryPropagationStrategy, Config, ConfigBuilder};
 #[cfg(feature = "gpu")]
 pub use global_state::CudaGpuChoice;
 pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
 
-pub use integers::{CompressedFheInt, CompressedFheUint, FheInt, FheUint, IntegerId};
-#[cfg(feature = "gpu")]
-pub use keys::CudaServerKey;
+pub use integers::{
+    CompressedFheInt, CompressedFheUint, FheExpr, FheFix64, FheIFix64, FheInt, FheUint, IntegerId,
+};
 pub use keys::{
     generate_keys, ClientKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey,
     CompressedServerKey, KeySwitchingKey, PublicKey, ServerKey,
 };
+#[cfg(feature = "gpu")]
+pub use keys::{CudaServerKey, HybridServerKey};
 
 #[cfg(test)]
 mod tests;
@@ -88,7 +90,9 @@ export_concrete_array_types!(
 );
 
 pub use crate::integer::parameters::CompactCiphertextListConformanceParams;
-pub use crate::safe_serialization::{DeserializationConfig, SerializationConfig};
+pub use crate::safe_serialization::{
+    safe_deserialize, safe_serialize, DeserializationConfig, SerializationConfig,
+};
 #[cfg(feature = "strings")]
 pub use crate::strings::ciphertext::ClearString;
 
@@ -6,6 +6,12 @@ fn test_string_eq_ne() {
     super::test_string_eq_ne(&cks);
 }
 
+#[test]
+fn test_string_eq_ne_padding() {
+    let cks = setup_default_cpu();
+    super::test_string_eq_ne_padding(&cks);
+}
+
 #[test]
 fn test_string_find_rfind() {
     let cks = setup_default_cpu();
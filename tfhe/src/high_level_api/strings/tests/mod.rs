@@ -21,6 +21,43 @@ fn test_string_eq_ne(client_key: &ClientKey) {
     assert!(!string2.ne(&string2).decrypt(client_key));
 }
 
+fn test_string_eq_ne_padding(client_key: &ClientKey) {
+    // (lhs, lhs padding, rhs, rhs padding)
+    let cases = [
+        ("zama", 0, "zama", 3),
+        ("zama", 2, "zama", 5),
+        ("zama", 0, "zam", 0),
+        ("zam", 1, "zama", 0),
+        ("zama", 4, "zamaa", 3),
+        ("", 0, "", 4),
+        ("", 2, "a", 1),
+    ];
+
+    for (lhs, lhs_padding, rhs, rhs_padding) in cases {
+        let expected = lhs == rhs;
+
+        let enc_lhs =
+            FheAsciiString::try_encrypt_with_padding(lhs, lhs_padding, client_key).unwrap();
+        let enc_rhs =
+            FheAsciiString::try_encrypt_with_padding(rhs, rhs_padding, client_key).unwrap();
+
+        assert_eq!(
+            enc_lhs.eq(&enc_rhs).decrypt(client_key),
+            expected,
+            "Invalid eq for '{lhs}' (padding {lhs_padding}) and '{rhs}' (padding {rhs_padding})"
+        );
+        assert_eq!(
+            enc_lhs.ne(&enc_rhs).decrypt(client_key),
+            !expected,
+            "Invalid ne for '{lhs}' (padding {lhs_padding}) and '{rhs}' (padding {rhs_padding})"
+        );
+
+        let clear_rhs = ClearString::new(rhs.into());
+        assert_eq!(enc_lhs.eq(&clear_rhs).decrypt(client_key), expected);
+        assert_eq!(enc_lhs.ne(&clear_rhs).decrypt(client_key), !expected);
+    }
+}
+
 fn test_string_find_rfind(client_key: &ClientKey) {
     // Simple case with no duplicate
     {
@@ -136,6 +173,26 @@ fn test_string_lower_upper(client_key: &ClientKey) {
     let upper = string.to_uppercase();
     let dec = upper.decrypt(client_key);
     assert_eq!(&dec, "12TFHE3-8RS!@");
+    let string = FheAsciiString::try_encrypt_with_padding("Hello", 2, client_key).unwrap();
+
+    let lower = string.to_lowercase();
+    let dec = lower.decrypt(client_key);
+    assert_eq!(&dec, "hello");
+
+    let upper = string.to_uppercase();
+    let dec = upper.decrypt(client_key);
+    assert_eq!(&dec, "HELLO");
+
+    // Only letters change case, the chars right before and after the
+    // letter ranges are left untouched
+    let clear_string = "@AZ[`az{";
+    let string = FheAsciiString::try_encrypt(clear_string, client_key).unwrap();
+
+    let dec = string.to_lowercase().decrypt(client_key);
+    assert_eq!(dec, clear_string.to_lowercase());
+
+    let dec = string.to_uppercase().decrypt(client_key);
+    assert_eq!(dec, clear_string.to_uppercase());
 }
 
 fn test_string_trim(client_key: &ClientKey) {
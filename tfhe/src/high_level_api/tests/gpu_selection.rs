@@ -3,7 +3,8 @@ use rand::Rng;
 use crate::core_crypto::gpu::get_number_of_gpus;
 use crate::prelude::*;
 use crate::{
-    set_server_key, ClientKey, CompressedServerKey, ConfigBuilder, Device, FheUint32, GpuIndex,
+    set_server_key, ClientKey, CompressedServerKey, ConfigBuilder, Device, FheInt8, FheUint32,
+    FheUint8, GpuIndex,
 };
 
 #[test]
@@ -117,3 +118,102 @@ fn test_gpu_selection_2() {
     assert_eq!(c.gpu_indexes(), &[first_gpu]);
     assert_eq!(decrypted, clear_a.wrapping_add(clear_b));
 }
+
+#[test]
+fn test_hybrid_server_key_falls_back_to_cpu() {
+    let config = ConfigBuilder::default().build();
+    let keys = ClientKey::generate(config);
+    let compressed_server_keys = CompressedServerKey::new(&keys);
+
+    let hybrid_key = compressed_server_keys.decompress_to_hybrid();
+    assert!(hybrid_key.gpu_key().is_some());
+    set_server_key(hybrid_key);
+
+    let mut rng = rand::thread_rng();
+    let clear_a: u32 = rng.gen();
+    let clear_b: u32 = rng.gen();
+
+    let mut a = FheUint32::try_encrypt(clear_a, &keys).unwrap();
+    let b = FheUint32::try_encrypt(clear_b, &keys).unwrap();
+    a.move_to_current_device();
+    assert_eq!(a.current_device(), Device::CudaGpu);
+
+    // Implemented on GPU
+    let c = &a + &b;
+    assert_eq!(c.current_device(), Device::CudaGpu);
+    let decrypted: u32 = c.decrypt(&keys);
+    assert_eq!(decrypted, clear_a.wrapping_add(clear_b));
+
+    // Only implemented on CPU, the result is uploaded back to the GPU
+    let ones = c.count_ones();
+    assert_eq!(ones.current_device(), Device::CudaGpu);
+    let decrypted: u32 = ones.decrypt(&keys);
+    assert_eq!(decrypted, clear_a.wrapping_add(clear_b).count_ones());
+
+    // The result can be used in GPU operations
+    let d = &ones + &c;
+    assert_eq!(d.current_device(), Device::CudaGpu);
+    let decrypted: u32 = d.decrypt(&keys);
+    assert_eq!(
+        decrypted,
+        clear_a
+            .wrapping_add(clear_b)
+            .count_ones()
+            .wrapping_add(clear_a.wrapping_add(clear_b))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cuda devices do not support count_ones yet")]
+fn test_cuda_server_key_has_no_cpu_fallback() {
+    let config = ConfigBuilder::default().build();
+    let keys = ClientKey::generate(config);
+    let compressed_server_keys = CompressedServerKey::new(&keys);
+
+    set_server_key(compressed_server_keys.decompress_to_gpu());
+
+    let a = FheUint32::try_encrypt(42u32, &keys).unwrap();
+    let _ = a.count_ones();
+}
+
+#[test]
+fn test_hybrid_server_key_runs_cpu_only_ops() {
+    let config = ConfigBuilder::default().build();
+    let keys = ClientKey::generate(config);
+    let compressed_server_keys = CompressedServerKey::new(&keys);
+
+    set_server_key(compressed_server_keys.decompress_to_hybrid());
+
+    let mut rng = rand::thread_rng();
+
+    // In place operation, every value is moved back to the GPU
+    let clears = [rng.gen::<u8>(), rng.gen::<u8>(), rng.gen::<u8>()];
+    let mut values = clears
+        .iter()
+        .map(|v| FheUint8::try_encrypt(*v, &keys).unwrap())
+        .collect::<Vec<_>>();
+    FheUint8::sort(&mut values);
+    let mut expected = clears;
+    expected.sort_unstable();
+    for (value, expected) in values.iter().zip(expected) {
+        assert_eq!(value.current_device(), Device::CudaGpu);
+        let decrypted: u8 = value.decrypt(&keys);
+        assert_eq!(decrypted, expected);
+    }
+
+    let clear_a: u8 = rng.gen();
+    let a = FheUint8::try_encrypt(clear_a, &keys).unwrap();
+    let result = a.pow(3);
+    assert_eq!(result.current_device(), Device::CudaGpu);
+    let decrypted: u8 = result.decrypt(&keys);
+    assert_eq!(decrypted, clear_a.wrapping_pow(3));
+
+    let clear_a: i8 = rng.gen();
+    let clear_b: i8 = rng.gen_range(1..=i8::MAX);
+    let mut a = FheInt8::try_encrypt(clear_a, &keys).unwrap();
+    let b = FheInt8::try_encrypt(clear_b, &keys).unwrap();
+    a /= &b;
+    assert_eq!(a.current_device(), Device::CudaGpu);
+    let decrypted: i8 = a.decrypt(&keys);
+    assert_eq!(decrypted, clear_a / clear_b);
+}
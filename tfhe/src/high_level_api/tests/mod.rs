@@ -180,6 +180,183 @@ fn test_serialize_deserialize_are_implemented() {
     can_be_deserialized(&pksz);
 }
 
+#[test]
+fn test_client_key_prior_format_is_upgraded() {
+    // The layout of the ClientKey before it got a tag
+    mod v0 {
+        use crate::high_level_api::keys::IntegerClientKey;
+        use crate::named::Named;
+        use tfhe_versionable::{Versionize, VersionsDispatch};
+
+        #[derive(serde::Serialize, Versionize)]
+        #[versionize(ClientKeyVersions)]
+        pub(super) struct ClientKey {
+            pub(super) key: IntegerClientKey,
+        }
+
+        #[derive(VersionsDispatch)]
+        #[allow(unused)]
+        pub(super) enum ClientKeyVersions {
+            V0(ClientKey),
+        }
+
+        impl Named for ClientKey {
+            const NAME: &'static str = crate::ClientKey::NAME;
+        }
+    }
+
+    let config = ConfigBuilder::default().build();
+    let cks = ClientKey::generate(config);
+
+    let old_cks = v0::ClientKey {
+        key: cks.key.clone(),
+    };
+    let mut buffer = vec![];
+    crate::safe_serialization::safe_serialize(&old_cks, &mut buffer, 1 << 30).unwrap();
+
+    let upgraded_cks: ClientKey =
+        crate::safe_serialization::safe_deserialize(buffer.as_slice(), 1 << 30).unwrap();
+    assert_eq!(upgraded_cks.tag(), &Tag::default());
+
+    let ct = FheUint8::encrypt(42u8, &cks);
+    let decrypted: u8 = ct.decrypt(&upgraded_cks);
+    assert_eq!(decrypted, 42);
+}
+
+#[test]
+fn test_fhe_uint_prior_format_is_upgraded() {
+    // The layout of the FheUint before it got a tag
+    mod v0 {
+        use crate::high_level_api::integers::unsigned::RadixCiphertext;
+        use crate::named::Named;
+        use crate::FheUint8Id;
+        use tfhe_versionable::{Versionize, VersionsDispatch};
+
+        #[derive(serde::Serialize, Versionize)]
+        #[versionize(FheUint8Versions)]
+        pub(super) struct FheUint8 {
+            pub(super) ciphertext: RadixCiphertext,
+            pub(super) id: FheUint8Id,
+        }
+
+        #[derive(VersionsDispatch)]
+        #[allow(unused)]
+        pub(super) enum FheUint8Versions {
+            V0(FheUint8),
+        }
+
+        impl Named for FheUint8 {
+            const NAME: &'static str = crate::FheUint8::NAME;
+        }
+    }
+
+    let config = ConfigBuilder::default().build();
+    let cks = ClientKey::generate(config);
+
+    let ct = FheUint8::encrypt(42u8, &cks);
+    let old_ct = v0::FheUint8 {
+        ciphertext: ct.ciphertext.clone(),
+        id: ct.id,
+    };
+    let mut buffer = vec![];
+    crate::safe_serialization::safe_serialize(&old_ct, &mut buffer, 1 << 30).unwrap();
+
+    let upgraded_ct: FheUint8 =
+        crate::safe_serialization::safe_deserialize(buffer.as_slice(), 1 << 30).unwrap();
+    assert_eq!(upgraded_ct.tag(), &Tag::default());
+    let decrypted: u8 = upgraded_ct.decrypt(&cks);
+    assert_eq!(decrypted, 42);
+}
+
+#[test]
+fn test_compressed_server_key_deprecated_format_is_rejected() {
+    use bincode::Options;
+    use tfhe_versionable::Versionize;
+
+    let config = ConfigBuilder::default().build();
+    let cks = ClientKey::generate(config);
+    let csks = CompressedServerKey::new(&cks);
+
+    let mut buffer = vec![];
+    crate::safe_serialization::safe_serialize(&csks, &mut buffer, 1 << 30).unwrap();
+
+    // The current format is read back as is
+    let deserialized: CompressedServerKey =
+        crate::safe_serialization::safe_deserialize(buffer.as_slice(), 1 << 30).unwrap();
+    set_server_key(deserialized.decompress());
+    let a = FheUint8::encrypt(10u8, &cks);
+    let b = FheUint8::encrypt(20u8, &cks);
+    let decrypted: u8 = (a + b).decrypt(&cks);
+    assert_eq!(decrypted, 30);
+
+    // The CompressedServerKey of versions prior to TFHE-rs v0.10 cannot be upgraded,
+    // replace the version index with the first one
+    let versioned_size = bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .serialized_size(&csks.versionize())
+        .unwrap() as usize;
+    let version_index_start = buffer.len() - versioned_size;
+    buffer[version_index_start..version_index_start + 4].copy_from_slice(&0u32.to_le_bytes());
+
+    let Err(err) = crate::safe_serialization::safe_deserialize::<CompressedServerKey>(
+        buffer.as_slice(),
+        1 << 30,
+    ) else {
+        panic!("Deserializing a deprecated CompressedServerKey should fail");
+    };
+    assert!(
+        err.contains("Deprecated CompressedServerKey"),
+        "Unexpected error: {err}"
+    );
+}
+
+#[test]
+fn test_unknown_future_version_is_an_error() {
+    use bincode::Options;
+    use tfhe_versionable::Versionize;
+
+    let config = ConfigBuilder::default().build();
+    let cks = ClientKey::generate(config);
+
+    let mut buffer = vec![];
+    crate::safe_serialization::safe_serialize(&cks, &mut buffer, 1 << 30).unwrap();
+
+    // The versioned data starts with the index of its version,
+    // replace it with one that does not exist yet
+    let versioned_size = bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .serialized_size(&cks.versionize())
+        .unwrap() as usize;
+    let version_index_start = buffer.len() - versioned_size;
+    buffer[version_index_start..version_index_start + 4].copy_from_slice(&100u32.to_le_bytes());
+
+    let err = crate::safe_serialization::safe_deserialize::<ClientKey>(buffer.as_slice(), 1 << 30)
+        .unwrap_err();
+    assert!(
+        err.contains("more recent version of TFHE-rs"),
+        "Unexpected error: {err}"
+    );
+}
+
+#[test]
+fn test_serialized_size_matches_bincode_output() {
+    let config = ConfigBuilder::default().build();
+
+    let (cks, sks) = generate_keys(config);
+
+    let a = FheUint32::encrypt(1344u32, &cks);
+    let b = crate::FheInt32::encrypt(-1344i32, &cks);
+    let c = FheBool::encrypt(true, &cks);
+
+    assert_eq!(a.serialized_size(), bincode::serialize(&a).unwrap().len());
+    assert_eq!(b.serialized_size(), bincode::serialize(&b).unwrap().len());
+    assert_eq!(c.serialized_size(), bincode::serialize(&c).unwrap().len());
+    assert_eq!(
+        sks.serialized_size(),
+        bincode::serialize(&sks).unwrap().len()
+    );
+}
+
 #[test]
 fn test_try_from_single_lwe_encryption_key() {
     let parameters = crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
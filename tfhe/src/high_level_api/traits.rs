@@ -112,6 +112,12 @@ pub trait FheMax<Rhs = Self> {
     fn max(&self, other: Rhs) -> Self::Output;
 }
 
+pub trait FhePow<Rhs> {
+    type Output;
+
+    fn pow(&self, exponent: Rhs) -> Self::Output;
+}
+
 pub trait RotateLeft<Rhs = Self> {
     type Output;
 
@@ -166,6 +172,42 @@ pub trait OverflowingMul<Rhs> {
     fn overflowing_mul(self, rhs: Rhs) -> (Self::Output, FheBool);
 }
 
+pub trait SaturatingAdd<Rhs> {
+    type Output;
+
+    fn saturating_add(self, rhs: Rhs) -> Self::Output;
+}
+
+pub trait SaturatingSub<Rhs> {
+    type Output;
+
+    fn saturating_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Multiplication that keeps the full product, in a type twice as wide as the operands
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint32};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheUint16::encrypt(3434u16, &client_key);
+/// let b = FheUint16::encrypt(54321u16, &client_key);
+///
+/// let result: FheUint32 = (&a).widening_mul(&b);
+/// let result: u32 = result.decrypt(&client_key);
+/// assert_eq!(result, 3434u32 * 54321u32);
+/// ```
+pub trait WideningMul<Rhs> {
+    type Output;
+
+    fn widening_mul(self, rhs: Rhs) -> Self::Output;
+}
+
 pub trait BitSlice<Bounds> {
     type Output;
 
@@ -31,4 +31,24 @@ impl CompactPkeCrs {
         )?;
         Ok(crs)
     }
+    /// Create a new `CompactPkeCrs` from a `Config` object, for lists built without packing
+    /// (i.e. with [`CompactCiphertextListBuilder::build_with_proof`]).
+    /// max_bit_size is the maximum number of bits that can be proven, e.g. 64 for a single
+    /// FheUint64 or 8 x FheUint8 values.
+    ///
+    /// Proofs made with this CRS also prove that each encrypted block fits in the message
+    /// modulus.
+    ///
+    /// [`CompactCiphertextListBuilder::build_with_proof`]: crate::CompactCiphertextListBuilder::build_with_proof
+    pub fn from_config_unpacked(config: Config, max_bit_size: usize) -> crate::Result<Self> {
+        let compact_encryption_parameters = config.public_key_encryption_parameters()?;
+
+        let message_modulus = compact_encryption_parameters.message_modulus.0;
+        let max_num_message = max_bit_size.div_ceil(message_modulus.ilog2() as usize);
+        Self::from_shortint_params_with_encryption_modulus(
+            compact_encryption_parameters,
+            LweCiphertextCount(max_num_message),
+            message_modulus,
+        )
+    }
 }
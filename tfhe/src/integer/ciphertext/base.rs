@@ -6,7 +6,7 @@ use crate::integer::backward_compatibility::ciphertext::{
 };
 use crate::integer::block_decomposition::{BlockRecomposer, RecomposableFrom};
 use crate::integer::client_key::{sign_extend_partial_number, RecomposableSignedInteger};
-use crate::shortint::ciphertext::NotTrivialCiphertextError;
+use crate::shortint::ciphertext::{MaxNoiseLevel, NoiseLevel, NotTrivialCiphertextError};
 use crate::shortint::parameters::CiphertextConformanceParams;
 use crate::shortint::Ciphertext;
 use serde::{Deserialize, Serialize};
@@ -54,6 +54,32 @@ impl RadixCiphertext {
         self.blocks.iter().all(Ciphertext::is_trivial)
     }
 
+    /// Returns the noise level of each block, from LSB to MSB
+    pub fn blocks_noise_levels(&self) -> Vec<NoiseLevel> {
+        self.blocks.iter().map(Ciphertext::noise_level).collect()
+    }
+
+    /// Returns the highest noise level among the blocks
+    ///
+    /// An empty ciphertext has a noise level of [NoiseLevel::ZERO]
+    pub fn max_noise_level(&self) -> NoiseLevel {
+        self.blocks
+            .iter()
+            .map(Ciphertext::noise_level)
+            .max()
+            .unwrap_or(NoiseLevel::ZERO)
+    }
+
+    /// Returns true if at least one block has reached the `max_noise_level`,
+    /// meaning no further noise-increasing leveled operation (e.g. an unchecked add)
+    /// can be done before a propagation.
+    ///
+    /// The `max_noise_level` is the one of the parameters the keys were generated with,
+    /// see [crate::shortint::parameters::ShortintParameterSet::max_noise_level].
+    pub fn needs_propagation(&self, max_noise_level: MaxNoiseLevel) -> bool {
+        self.max_noise_level().get() >= max_noise_level.get()
+    }
+
     /// Decrypts a trivial ciphertext
     ///
     /// Trivial ciphertexts are ciphertexts which are not encrypted
@@ -156,6 +182,32 @@ impl SignedRadixCiphertext {
         self.blocks.iter().all(Ciphertext::is_trivial)
     }
 
+    /// Returns the noise level of each block, from LSB to MSB
+    pub fn blocks_noise_levels(&self) -> Vec<NoiseLevel> {
+        self.blocks.iter().map(Ciphertext::noise_level).collect()
+    }
+
+    /// Returns the highest noise level among the blocks
+    ///
+    /// An empty ciphertext has a noise level of [NoiseLevel::ZERO]
+    pub fn max_noise_level(&self) -> NoiseLevel {
+        self.blocks
+            .iter()
+            .map(Ciphertext::noise_level)
+            .max()
+            .unwrap_or(NoiseLevel::ZERO)
+    }
+
+    /// Returns true if at least one block has reached the `max_noise_level`,
+    /// meaning no further noise-increasing leveled operation (e.g. an unchecked add)
+    /// can be done before a propagation.
+    ///
+    /// The `max_noise_level` is the one of the parameters the keys were generated with,
+    /// see [crate::shortint::parameters::ShortintParameterSet::max_noise_level].
+    pub fn needs_propagation(&self, max_noise_level: MaxNoiseLevel) -> bool {
+        self.max_noise_level().get() >= max_noise_level.get()
+    }
+
     /// Decrypts a trivial ciphertext
     ///
     /// Trivial ciphertexts are ciphertexts which are not encrypted
@@ -354,4 +354,92 @@ impl CudaRadixCiphertextInfo {
             .extend(self.blocks[..num_blocks].iter().copied());
         new_block_info
     }
+
+    pub(crate) fn after_block_shift_left(&self, num_blocks: usize) -> Self {
+        let num_blocks = num_blocks.min(self.blocks.len());
+        let mut new_block_info = Self {
+            blocks: Vec::with_capacity(self.blocks.len()),
+        };
+        for _ in 0..num_blocks {
+            new_block_info.blocks.push(CudaBlockInfo {
+                degree: Degree::new(0),
+                message_modulus: self.blocks.first().unwrap().message_modulus,
+                carry_modulus: self.blocks.first().unwrap().carry_modulus,
+                pbs_order: self.blocks.first().unwrap().pbs_order,
+                noise_level: NoiseLevel::ZERO,
+            });
+        }
+        new_block_info.blocks.extend(
+            self.blocks[..self.blocks.len() - num_blocks]
+                .iter()
+                .copied(),
+        );
+        new_block_info
+    }
+
+    /// Returns the info of the ciphertext made by concatenating the blocks of `self`
+    /// (least significant blocks) with the blocks of `other` (most significant blocks).
+    pub fn concat(&self, other: &Self) -> Self {
+        let mut new_block_info = Self {
+            blocks: Vec::with_capacity(self.blocks.len() + other.blocks.len()),
+        };
+        new_block_info.blocks.extend(self.blocks.iter().copied());
+        new_block_info.blocks.extend(other.blocks.iter().copied());
+        new_block_info
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block_info(degree: u64) -> CudaBlockInfo {
+        CudaBlockInfo {
+            degree: Degree::new(degree),
+            message_modulus: MessageModulus(4),
+            carry_modulus: CarryModulus(4),
+            pbs_order: PBSOrder::KeyswitchBootstrap,
+            noise_level: NoiseLevel::NOMINAL,
+        }
+    }
+
+    #[test]
+    fn test_concat_info() {
+        let low = CudaRadixCiphertextInfo {
+            blocks: (0..3).map(block_info).collect(),
+        };
+        let high = CudaRadixCiphertextInfo {
+            blocks: (3..5).map(block_info).collect(),
+        };
+
+        let concatenated = low.concat(&high);
+        assert_eq!(concatenated.blocks.len(), 5);
+        for (i, block) in concatenated.blocks.iter().enumerate() {
+            assert_eq!(block.degree, Degree::new(i as u64));
+        }
+
+        let empty = CudaRadixCiphertextInfo { blocks: vec![] };
+        assert_eq!(empty.concat(&high).blocks.len(), 2);
+        assert_eq!(low.concat(&empty).blocks.len(), 3);
+    }
+
+    #[test]
+    fn test_block_shift_left_info() {
+        let info = CudaRadixCiphertextInfo {
+            blocks: (1..5).map(block_info).collect(),
+        };
+
+        for num_blocks in 0..6 {
+            let shifted = info.after_block_shift_left(num_blocks);
+            assert_eq!(shifted.blocks.len(), 4);
+            for (i, block) in shifted.blocks.iter().enumerate() {
+                if i < num_blocks {
+                    assert_eq!(block.degree, Degree::new(0));
+                    assert_eq!(block.noise_level, NoiseLevel::ZERO);
+                } else {
+                    assert_eq!(block.degree, Degree::new((i - num_blocks + 1) as u64));
+                }
+            }
+        }
+    }
 }
@@ -2,13 +2,17 @@ pub mod boolean_value;
 pub mod compressed_ciphertext_list;
 pub mod info;
 
+use crate::core_crypto::entities::Cleartext;
+use crate::core_crypto::gpu::algorithms::{
+    cuda_lwe_ciphertext_negate_assign_async, cuda_lwe_ciphertext_plaintext_add_assign_async,
+};
 use crate::core_crypto::gpu::lwe_ciphertext_list::CudaLweCiphertextList;
 use crate::core_crypto::gpu::vec::CudaVec;
 use crate::core_crypto::gpu::CudaStreams;
 use crate::core_crypto::prelude::{LweCiphertextList, LweCiphertextOwned};
 use crate::integer::gpu::ciphertext::info::{CudaBlockInfo, CudaRadixCiphertextInfo};
 use crate::integer::{IntegerCiphertext, RadixCiphertext, SignedRadixCiphertext};
-use crate::shortint::Ciphertext;
+use crate::shortint::{Ciphertext, PaddingBit, ShortintEncoding};
 use crate::GpuIndex;
 
 pub trait CudaIntegerRadixCiphertext: Sized {
@@ -79,6 +83,19 @@ pub struct CudaSignedRadixCiphertext {
     pub ciphertext: CudaRadixCiphertext,
 }
 
+/// Read-only view of a [CudaRadixCiphertext]
+///
+/// The view borrows the device memory of the ciphertext, so it can be used for
+/// operations that only read the ciphertext (comparisons, copies to the host) without
+/// having to [duplicate](CudaRadixCiphertext::duplicate) it.
+///
+/// Operations that modify a ciphertext require the owned [CudaRadixCiphertext].
+#[derive(Copy, Clone)]
+pub struct CudaRadixCiphertextView<'a> {
+    pub d_blocks: &'a CudaLweCiphertextList<u64>,
+    pub info: &'a CudaRadixCiphertextInfo,
+}
+
 impl CudaIntegerRadixCiphertext for CudaUnsignedRadixCiphertext {
     const IS_SIGNED: bool = false;
 
@@ -152,6 +169,20 @@ impl CudaRadixCiphertext {
         Self { d_blocks, info }
     }
 
+    pub fn to_cpu_blocks(&self, streams: &CudaStreams) -> Vec<Ciphertext> {
+        self.as_view().to_cpu_blocks(streams)
+    }
+
+    /// Returns a read-only view of the ciphertext, no device memory is copied
+    pub fn as_view(&self) -> CudaRadixCiphertextView<'_> {
+        CudaRadixCiphertextView {
+            d_blocks: &self.d_blocks,
+            info: &self.info,
+        }
+    }
+}
+
+impl CudaRadixCiphertextView<'_> {
     pub fn to_cpu_blocks(&self, streams: &CudaStreams) -> Vec<Ciphertext> {
         let h_lwe_ciphertext_list = self.d_blocks.to_lwe_ciphertext_list(streams);
         let ciphertext_modulus = h_lwe_ciphertext_list.ciphertext_modulus();
@@ -171,6 +202,98 @@ impl CudaRadixCiphertext {
             })
             .collect()
     }
+
+    /// Copies the viewed ciphertext to the CPU as an unsigned radix ciphertext
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// # // TODO GPU DRIFT UPDATE
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex::new(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(
+    /// # // TODO GPU DRIFT UPDATE
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    ///     num_blocks,
+    ///     &streams,
+    /// );
+    ///
+    /// let msg = 10u32;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    /// let view = d_ct.as_ref().as_view();
+    ///
+    /// let ct_copied = view.to_radix_ciphertext(&streams);
+    /// let msg_copied: u32 = cks.decrypt(&ct_copied);
+    ///
+    /// assert_eq!(msg, msg_copied);
+    /// ```
+    pub fn to_radix_ciphertext(&self, streams: &CudaStreams) -> RadixCiphertext {
+        RadixCiphertext::from(self.to_cpu_blocks(streams))
+    }
+
+    /// Copies the viewed ciphertext to the CPU as a signed radix ciphertext
+    pub fn to_signed_radix_ciphertext(&self, streams: &CudaStreams) -> SignedRadixCiphertext {
+        SignedRadixCiphertext::from(self.to_cpu_blocks(streams))
+    }
+
+    /// Returns an owned copy of the viewed ciphertext
+    pub fn duplicate(&self, streams: &CudaStreams) -> CudaRadixCiphertext {
+        let ct = unsafe { self.duplicate_async(streams) };
+        streams.synchronize();
+        ct
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn duplicate_async(&self, streams: &CudaStreams) -> CudaRadixCiphertext {
+        let lwe_ciphertext_count = self.d_blocks.lwe_ciphertext_count();
+        let ciphertext_modulus = self.d_blocks.ciphertext_modulus();
+
+        let mut d_ct = CudaVec::new_async(self.d_blocks.0.d_vec.len(), streams, 0);
+        d_ct.copy_from_gpu_async(&self.d_blocks.0.d_vec, streams, 0);
+
+        let d_blocks =
+            CudaLweCiphertextList::from_cuda_vec(d_ct, lwe_ciphertext_count, ciphertext_modulus);
+
+        CudaRadixCiphertext {
+            d_blocks,
+            info: self.info.clone(),
+        }
+    }
+
+    /// Returns whether both ciphertexts have the same content in device memory
+    pub fn is_equal(&self, other: CudaRadixCiphertextView<'_>, streams: &CudaStreams) -> bool {
+        let self_size = self.d_blocks.0.d_vec.len();
+        let other_size = other.d_blocks.0.d_vec.len();
+        let mut self_container: Vec<u64> = vec![0; self_size];
+        let mut other_container: Vec<u64> = vec![0; other_size];
+
+        unsafe {
+            self.d_blocks
+                .0
+                .d_vec
+                .copy_to_cpu_async(self_container.as_mut_slice(), streams, 0);
+            other
+                .d_blocks
+                .0
+                .d_vec
+                .copy_to_cpu_async(other_container.as_mut_slice(), streams, 0);
+        }
+        streams.synchronize();
+
+        self_container == other_container
+    }
 }
 
 impl CudaUnsignedRadixCiphertext {
@@ -450,40 +573,174 @@ impl CudaRadixCiphertext {
     /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
     ///   not be dropped until streams is synchronised
     pub unsafe fn duplicate_async(&self, streams: &CudaStreams) -> Self {
-        let lwe_ciphertext_count = self.d_blocks.lwe_ciphertext_count();
+        self.as_view().duplicate_async(streams)
+    }
+
+    /// Computes the bitwise complement of each block of the ciphertext
+    ///
+    /// Each block encrypting `m` is turned into a block encrypting `message_modulus - 1 - m`,
+    /// by negating it and adding a constant, so no PBS is computed. This is the complement
+    /// step of the two's complement negation. The blocks are expected to have empty carries.
+    ///
+    /// The degree of each block is set to its maximum value, `message_modulus - 1`.
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// # // TODO GPU DRIFT UPDATE
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex::new(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(
+    /// # // TODO GPU DRIFT UPDATE
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    ///     num_blocks,
+    ///     &streams,
+    /// );
+    ///
+    /// let msg = 14u8;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    /// let d_ct_res = CudaUnsignedRadixCiphertext {
+    ///     ciphertext: d_ct.as_ref().bitnot(&streams),
+    /// };
+    ///
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, !msg);
+    /// ```
+    pub fn bitnot(&self, streams: &CudaStreams) -> Self {
+        let mut result = unsafe { self.duplicate_async(streams) };
+        unsafe {
+            result.bitnot_assign_async(streams);
+        }
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn bitnot_assign_async(&mut self, streams: &CudaStreams) {
+        if self.info.blocks.is_empty() {
+            return;
+        }
         let ciphertext_modulus = self.d_blocks.ciphertext_modulus();
 
-        let mut d_ct = CudaVec::new_async(self.d_blocks.0.d_vec.len(), streams, 0);
-        d_ct.copy_from_gpu_async(&self.d_blocks.0.d_vec, streams, 0);
+        // We do (-ciphertext) + (msg_mod -1) as it allows to avoid an allocation
+        cuda_lwe_ciphertext_negate_assign_async(&mut self.d_blocks, streams);
 
-        let d_blocks =
-            CudaLweCiphertextList::from_cuda_vec(d_ct, lwe_ciphertext_count, ciphertext_modulus);
+        let scalar_vector = self
+            .info
+            .blocks
+            .iter()
+            .map(|block| {
+                let encoding = ShortintEncoding {
+                    ciphertext_modulus,
+                    message_modulus: block.message_modulus,
+                    carry_modulus: block.carry_modulus,
+                    padding_bit: PaddingBit::Yes,
+                };
+                encoding.encode(Cleartext(block.message_modulus.0 - 1)).0
+            })
+            .collect::<Vec<_>>();
+        let mut d_scalar_vector = CudaVec::<u64>::new_async(scalar_vector.len(), streams, 0);
+        d_scalar_vector.copy_from_cpu_async(scalar_vector.as_slice(), streams, 0);
 
-        Self {
-            d_blocks,
-            info: self.info.clone(),
-        }
+        cuda_lwe_ciphertext_plaintext_add_assign_async(
+            &mut self.d_blocks,
+            &d_scalar_vector,
+            streams,
+        );
+        self.info = self.info.after_bitnot();
     }
 
-    fn is_equal(&self, other: &Self, streams: &CudaStreams) -> bool {
-        let self_size = self.d_blocks.0.d_vec.len();
-        let other_size = other.d_blocks.0.d_vec.len();
-        let mut self_container: Vec<u64> = vec![0; self_size];
-        let mut other_container: Vec<u64> = vec![0; other_size];
-
+    /// Shifts the blocks of the ciphertext by `num_blocks` positions towards the most
+    /// significant blocks, the least significant blocks are filled with trivial zeros
+    ///
+    /// This is a multiplication by `message_modulus^num_blocks` which only moves data in
+    /// device memory, no PBS is computed. Shifting by at least the number of blocks
+    /// results in a trivial zero.
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// # // TODO GPU DRIFT UPDATE
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex::new(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(
+    /// # // TODO GPU DRIFT UPDATE
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    ///     num_blocks,
+    ///     &streams,
+    /// );
+    ///
+    /// let msg = 3u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let mut d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    /// d_ct.as_mut().block_shift_left(2, &streams);
+    ///
+    /// let ct_res = d_ct.to_radix_ciphertext(&streams);
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg << 4);
+    /// ```
+    pub fn block_shift_left(&mut self, num_blocks: usize, streams: &CudaStreams) {
         unsafe {
-            self.d_blocks
-                .0
-                .d_vec
-                .copy_to_cpu_async(self_container.as_mut_slice(), streams, 0);
-            other
-                .d_blocks
-                .0
-                .d_vec
-                .copy_to_cpu_async(other_container.as_mut_slice(), streams, 0);
+            self.block_shift_left_async(num_blocks, streams);
         }
         streams.synchronize();
+    }
 
-        self_container == other_container
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn block_shift_left_async(&mut self, num_blocks: usize, streams: &CudaStreams) {
+        if num_blocks == 0 {
+            return;
+        }
+        let lwe_ciphertext_count = self.d_blocks.lwe_ciphertext_count();
+        let ciphertext_modulus = self.d_blocks.ciphertext_modulus();
+        let lwe_size = self.d_blocks.lwe_dimension().to_lwe_size();
+        let num_blocks = num_blocks.min(lwe_ciphertext_count.0);
+        let shift = num_blocks * lwe_size.0;
+
+        let mut shifted_ct_vec = CudaVec::new_async(self.d_blocks.0.d_vec.len(), streams, 0);
+        shifted_ct_vec.memset_async(0u64, streams, 0);
+        if num_blocks < lwe_ciphertext_count.0 {
+            shifted_ct_vec.copy_self_range_gpu_to_gpu_async(
+                shift..,
+                &self.d_blocks.0.d_vec,
+                streams,
+                0,
+            );
+        }
+
+        self.d_blocks = CudaLweCiphertextList::from_cuda_vec(
+            shifted_ct_vec,
+            lwe_ciphertext_count,
+            ciphertext_modulus,
+        );
+        self.info = self.info.after_block_shift_left(num_blocks);
+    }
+
+    fn is_equal(&self, other: &Self, streams: &CudaStreams) -> bool {
+        self.as_view().is_equal(other.as_view(), streams)
     }
 }
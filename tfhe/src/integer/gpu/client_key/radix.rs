@@ -2,9 +2,11 @@ use crate::core_crypto::gpu::lwe_bootstrap_key::CudaLweBootstrapKey;
 use crate::core_crypto::gpu::CudaStreams;
 use crate::core_crypto::prelude::{
     allocate_and_generate_new_lwe_packing_keyswitch_key, par_generate_lwe_bootstrap_key,
-    LweBootstrapKey,
+    LweBootstrapKey, UnsignedNumeric,
 };
+use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::compression_keys::{CompressionKey, CompressionPrivateKeys};
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
 use crate::integer::gpu::list_compression::server_keys::{
     CudaCompressionKey, CudaDecompressionKey,
 };
@@ -12,8 +14,82 @@ use crate::integer::gpu::server_key::CudaBootstrappingKey;
 use crate::integer::RadixClientKey;
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::EncryptionKeyChoice;
+use rayon::prelude::*;
+use std::time::{Duration, Instant};
+
+/// Time spent in each step of [RadixClientKey::encrypt_and_upload_batch]
+#[derive(Copy, Clone, Debug)]
+pub struct CudaBatchUploadTimings {
+    /// Time spent encrypting all the values on the CPU
+    pub encryption: Duration,
+    /// Time spent copying all the ciphertexts to the GPU
+    pub upload: Duration,
+}
 
 impl RadixClientKey {
+    /// Encrypts a batch of values and copies the resulting ciphertexts to the GPU
+    ///
+    /// Encryption is done in parallel on the CPU, then all ciphertexts are copied
+    /// to the GPU of the given `streams`. The time spent in each step is returned
+    /// alongside the device-resident ciphertexts, which is useful to profile
+    /// the setup cost of GPU computations.
+    ///
+    /// The returned ciphertexts are in the same order as `values`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// # // TODO GPU DRIFT UPDATE
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex::new(gpu_index));
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, _sks) = gen_keys_radix_gpu(
+    /// # // TODO GPU DRIFT UPDATE
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    ///     num_blocks,
+    ///     &streams,
+    /// );
+    ///
+    /// let values = [1u8, 2, 3, 255];
+    /// let (d_cts, _timings) = cks.encrypt_and_upload_batch(&values, &streams);
+    ///
+    /// for (d_ct, value) in d_cts.iter().zip(values) {
+    ///     let decrypted: u8 = cks.decrypt(&d_ct.to_radix_ciphertext(&streams));
+    ///     assert_eq!(decrypted, value);
+    /// }
+    /// ```
+    pub fn encrypt_and_upload_batch<T>(
+        &self,
+        values: &[T],
+        streams: &CudaStreams,
+    ) -> (Vec<CudaUnsignedRadixCiphertext>, CudaBatchUploadTimings)
+    where
+        T: DecomposableInto<u64> + UnsignedNumeric + Sync,
+    {
+        let start = Instant::now();
+        let cts = values
+            .par_iter()
+            .map(|value| self.encrypt(*value))
+            .collect::<Vec<_>>();
+        let encryption = start.elapsed();
+
+        let start = Instant::now();
+        let d_cts = cts
+            .iter()
+            .map(|ct| CudaUnsignedRadixCiphertext::from_radix_ciphertext(ct, streams))
+            .collect::<Vec<_>>();
+        streams.synchronize();
+        let upload = start.elapsed();
+
+        (d_cts, CudaBatchUploadTimings { encryption, upload })
+    }
+
     pub fn new_cuda_compression_decompression_keys(
         &self,
         private_compression_key: &CompressionPrivateKeys,
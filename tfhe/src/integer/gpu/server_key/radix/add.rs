@@ -1,9 +1,12 @@
 use crate::core_crypto::gpu::lwe_ciphertext_list::CudaLweCiphertextList;
+use crate::core_crypto::gpu::vec::CudaVec;
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::LweBskGroupingFactor;
+use crate::core_crypto::prelude::{LweBskGroupingFactor, LweCiphertextCount};
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+use crate::integer::gpu::ciphertext::info::CudaRadixCiphertextInfo;
 use crate::integer::gpu::ciphertext::{
-    CudaIntegerRadixCiphertext, CudaSignedRadixCiphertext, CudaUnsignedRadixCiphertext,
+    CudaIntegerRadixCiphertext, CudaRadixCiphertext, CudaSignedRadixCiphertext,
+    CudaUnsignedRadixCiphertext,
 };
 use crate::integer::gpu::server_key::{CudaBootstrappingKey, CudaServerKey};
 use crate::integer::gpu::{
@@ -223,6 +226,168 @@ impl CudaServerKey {
         }
         streams.synchronize();
     }
+    /// Computes homomorphically the elementwise additions of two batches of ciphertexts.
+    ///
+    /// The batches are packed into two contiguous lists of blocks, so all the additions are done
+    /// in a single kernel launch instead of one launch per pair of ciphertexts.
+    ///
+    /// Like [Self::unchecked_add], carries are not propagated, the caller is responsible
+    /// for making sure the carry space of the blocks cannot overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the batches do not have the same length, or if two ciphertexts at the same index
+    /// do not have the same number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// # // TODO GPU DRIFT UPDATE
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex::new(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// # // TODO GPU DRIFT UPDATE
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// let lhs = [14u64, 97, 3];
+    /// let rhs = [10u64, 5, 120];
+    ///
+    /// let d_lhs = lhs
+    ///     .iter()
+    ///     .map(|v| CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(*v), &streams))
+    ///     .collect::<Vec<_>>();
+    /// let d_rhs = rhs
+    ///     .iter()
+    ///     .map(|v| CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(*v), &streams))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// // Compute homomorphically all the additions at once:
+    /// let d_results = sks.unchecked_add_batch(&d_lhs, &d_rhs, &streams);
+    ///
+    /// for ((d_result, l), r) in d_results.iter().zip(lhs).zip(rhs) {
+    ///     let dec: u64 = cks.decrypt(&d_result.to_radix_ciphertext(&streams));
+    ///     assert_eq!(dec, l + r);
+    /// }
+    /// ```
+    pub fn unchecked_add_batch<T: CudaIntegerRadixCiphertext>(
+        &self,
+        lhs: &[T],
+        rhs: &[T],
+        streams: &CudaStreams,
+    ) -> Vec<T> {
+        let result = unsafe { self.unchecked_add_batch_async(lhs, rhs, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronized
+    pub unsafe fn unchecked_add_batch_async<T: CudaIntegerRadixCiphertext>(
+        &self,
+        lhs: &[T],
+        rhs: &[T],
+        streams: &CudaStreams,
+    ) -> Vec<T> {
+        assert_eq!(
+            lhs.len(),
+            rhs.len(),
+            "Cannot add batches with different lengths ({} and {})",
+            lhs.len(),
+            rhs.len()
+        );
+        for (i, (l, r)) in lhs.iter().zip(rhs.iter()).enumerate() {
+            assert_eq!(
+                l.as_ref().d_blocks.lwe_ciphertext_count(),
+                r.as_ref().d_blocks.lwe_ciphertext_count(),
+                "Mismatched number of blocks for the ciphertexts at index {i}"
+            );
+        }
+
+        let Some(first) = lhs.first() else {
+            return vec![];
+        };
+
+        let ciphertext_modulus = first.as_ref().d_blocks.ciphertext_modulus();
+        let lwe_size = first.as_ref().d_blocks.lwe_dimension().to_lwe_size();
+        let num_blocks_per_ct = lhs
+            .iter()
+            .map(|ct| ct.as_ref().d_blocks.lwe_ciphertext_count().0)
+            .collect::<Vec<_>>();
+        let total_num_blocks = num_blocks_per_ct.iter().sum::<usize>();
+
+        let pack = |batch: &[T]| {
+            let mut d_vec = CudaVec::new_async(total_num_blocks * lwe_size.0, streams, 0);
+            let mut start = 0;
+            for ct in batch {
+                let len = ct.as_ref().d_blocks.0.d_vec.len();
+                d_vec.copy_self_range_gpu_to_gpu_async(
+                    start..start + len,
+                    &ct.as_ref().d_blocks.0.d_vec,
+                    streams,
+                    0,
+                );
+                start += len;
+            }
+            let info = CudaRadixCiphertextInfo {
+                blocks: batch
+                    .iter()
+                    .flat_map(|ct| ct.as_ref().info.blocks.iter().copied())
+                    .collect(),
+            };
+            CudaRadixCiphertext::new(
+                CudaLweCiphertextList::from_cuda_vec(
+                    d_vec,
+                    LweCiphertextCount(total_num_blocks),
+                    ciphertext_modulus,
+                ),
+                info,
+            )
+        };
+
+        let mut packed_lhs = pack(lhs);
+        let packed_rhs = pack(rhs);
+
+        unchecked_add_integer_radix_assign_async(streams, &mut packed_lhs, &packed_rhs);
+
+        let mut start_block = 0;
+        num_blocks_per_ct
+            .into_iter()
+            .map(|num_blocks| {
+                let range = start_block * lwe_size.0..(start_block + num_blocks) * lwe_size.0;
+                let mut d_vec = CudaVec::new_async(num_blocks * lwe_size.0, streams, 0);
+                d_vec.copy_src_range_gpu_to_gpu_async(
+                    range,
+                    &packed_lhs.d_blocks.0.d_vec,
+                    streams,
+                    0,
+                );
+                let info = CudaRadixCiphertextInfo {
+                    blocks: packed_lhs.info.blocks[start_block..start_block + num_blocks].to_vec(),
+                };
+                start_block += num_blocks;
+
+                T::from(CudaRadixCiphertext::new(
+                    CudaLweCiphertextList::from_cuda_vec(
+                        d_vec,
+                        LweCiphertextCount(num_blocks),
+                        ciphertext_modulus,
+                    ),
+                    info,
+                ))
+            })
+            .collect()
+    }
+
     /// # Safety
     ///
     /// - `stream` __must__ be synchronized to guarantee computation has finished, and inputs must
@@ -1,10 +1,6 @@
-use crate::core_crypto::entities::Cleartext;
-use crate::core_crypto::gpu::algorithms::{
-    cuda_lwe_ciphertext_negate_assign, cuda_lwe_ciphertext_plaintext_add_assign,
-};
-use crate::core_crypto::gpu::vec::CudaVec;
 use crate::core_crypto::gpu::CudaStreams;
 use crate::core_crypto::prelude::LweBskGroupingFactor;
+use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
 use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
 use crate::integer::gpu::server_key::CudaBootstrappingKey;
 use crate::integer::gpu::{
@@ -75,26 +71,7 @@ impl CudaServerKey {
         ct: &mut T,
         streams: &CudaStreams,
     ) {
-        // We do (-ciphertext) + (msg_mod -1) as it allows to avoid an allocation
-        cuda_lwe_ciphertext_negate_assign(&mut ct.as_mut().d_blocks, streams);
-
-        let ct_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
-
-        let scalar = self.message_modulus.0 as u8 - 1;
-
-        let shift_plaintext = self.encoding().encode(Cleartext(u64::from(scalar))).0;
-
-        let scalar_vector = vec![shift_plaintext; ct_blocks];
-        let mut d_decomposed_scalar =
-            CudaVec::<u64>::new_async(ct.as_ref().d_blocks.lwe_ciphertext_count().0, streams, 0);
-        d_decomposed_scalar.copy_from_cpu_async(scalar_vector.as_slice(), streams, 0);
-
-        cuda_lwe_ciphertext_plaintext_add_assign(
-            &mut ct.as_mut().d_blocks,
-            &d_decomposed_scalar,
-            streams,
-        );
-        ct.as_mut().info = ct.as_ref().info.after_bitnot();
+        ct.as_mut().bitnot_assign_async(streams);
     }
 
     pub fn unchecked_bitnot_assign<T: CudaIntegerRadixCiphertext>(
@@ -789,4 +766,200 @@ impl CudaServerKey {
         }
         streams.synchronize();
     }
+
+    /// Computes homomorphically a bitand between two boolean blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// # // TODO GPU DRIFT UPDATE
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex::new(gpu_index));
+    ///
+    /// # // TODO GPU DRIFT UPDATE
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let (a, b, c, d) = (201u64, 17u64, 3u64, 5u64);
+    ///
+    /// // Copy to GPU
+    /// let d_a = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(a), &streams);
+    /// let d_b = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(b), &streams);
+    /// let d_c = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(c), &streams);
+    /// let d_d = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(d), &streams);
+    ///
+    /// // Compute homomorphically (a > b) & (c < d):
+    /// let d_gt = sks.gt(&d_a, &d_b, &streams);
+    /// let d_lt = sks.lt(&d_c, &d_d, &streams);
+    /// let d_res = sks.boolean_bitand(&d_gt, &d_lt, &streams);
+    ///
+    /// // Copy back to CPU
+    /// let res = d_res.to_boolean_block(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt_bool(&res);
+    /// assert_eq!(dec, (a > b) & (c < d));
+    /// ```
+    pub fn boolean_bitand(
+        &self,
+        lhs: &CudaBooleanBlock,
+        rhs: &CudaBooleanBlock,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock {
+        let result = self.bitand(&lhs.0, &rhs.0, streams);
+        CudaBooleanBlock::from_cuda_radix_ciphertext(result.ciphertext)
+    }
+
+    pub fn boolean_bitand_assign(
+        &self,
+        lhs: &mut CudaBooleanBlock,
+        rhs: &CudaBooleanBlock,
+        streams: &CudaStreams,
+    ) {
+        self.bitand_assign(&mut lhs.0, &rhs.0, streams);
+    }
+
+    /// Computes homomorphically a bitor between two boolean blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// # // TODO GPU DRIFT UPDATE
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex::new(gpu_index));
+    ///
+    /// # // TODO GPU DRIFT UPDATE
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, 1, &streams);
+    ///
+    /// let d_a = CudaBooleanBlock::from_boolean_block(&cks.encrypt_bool(true), &streams);
+    /// let d_b = CudaBooleanBlock::from_boolean_block(&cks.encrypt_bool(false), &streams);
+    ///
+    /// let d_res = sks.boolean_bitor(&d_a, &d_b, &streams);
+    ///
+    /// let dec = cks.decrypt_bool(&d_res.to_boolean_block(&streams));
+    /// assert!(dec);
+    /// ```
+    pub fn boolean_bitor(
+        &self,
+        lhs: &CudaBooleanBlock,
+        rhs: &CudaBooleanBlock,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock {
+        let result = self.bitor(&lhs.0, &rhs.0, streams);
+        CudaBooleanBlock::from_cuda_radix_ciphertext(result.ciphertext)
+    }
+
+    pub fn boolean_bitor_assign(
+        &self,
+        lhs: &mut CudaBooleanBlock,
+        rhs: &CudaBooleanBlock,
+        streams: &CudaStreams,
+    ) {
+        self.bitor_assign(&mut lhs.0, &rhs.0, streams);
+    }
+
+    /// Computes homomorphically a bitxor between two boolean blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// # // TODO GPU DRIFT UPDATE
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex::new(gpu_index));
+    ///
+    /// # // TODO GPU DRIFT UPDATE
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, 1, &streams);
+    ///
+    /// let d_a = CudaBooleanBlock::from_boolean_block(&cks.encrypt_bool(true), &streams);
+    /// let d_b = CudaBooleanBlock::from_boolean_block(&cks.encrypt_bool(true), &streams);
+    ///
+    /// let d_res = sks.boolean_bitxor(&d_a, &d_b, &streams);
+    ///
+    /// let dec = cks.decrypt_bool(&d_res.to_boolean_block(&streams));
+    /// assert!(!dec);
+    /// ```
+    pub fn boolean_bitxor(
+        &self,
+        lhs: &CudaBooleanBlock,
+        rhs: &CudaBooleanBlock,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock {
+        let result = self.bitxor(&lhs.0, &rhs.0, streams);
+        CudaBooleanBlock::from_cuda_radix_ciphertext(result.ciphertext)
+    }
+
+    pub fn boolean_bitxor_assign(
+        &self,
+        lhs: &mut CudaBooleanBlock,
+        rhs: &CudaBooleanBlock,
+        streams: &CudaStreams,
+    ) {
+        self.bitxor_assign(&mut lhs.0, &rhs.0, streams);
+    }
+
+    /// Computes homomorphically the bitnot of a boolean block
+    ///
+    /// Contrary to [Self::bitnot], which flips all the bits of the block message,
+    /// the result is kept in the `0`/`1` range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// # // TODO GPU DRIFT UPDATE
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex::new(gpu_index));
+    ///
+    /// # // TODO GPU DRIFT UPDATE
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, 1, &streams);
+    ///
+    /// for msg in [false, true] {
+    ///     let d_a = CudaBooleanBlock::from_boolean_block(&cks.encrypt_bool(msg), &streams);
+    ///
+    ///     let d_res = sks.boolean_bitnot(&d_a, &streams);
+    ///
+    ///     let dec = cks.decrypt_bool(&d_res.to_boolean_block(&streams));
+    ///     assert_eq!(dec, !msg);
+    /// }
+    /// ```
+    pub fn boolean_bitnot(
+        &self,
+        boolean_block: &CudaBooleanBlock,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock {
+        let result = self.scalar_bitxor(&boolean_block.0, 1u8, streams);
+        CudaBooleanBlock::from_cuda_radix_ciphertext(result.ciphertext)
+    }
+
+    pub fn boolean_bitnot_assign(
+        &self,
+        boolean_block: &mut CudaBooleanBlock,
+        streams: &CudaStreams,
+    ) {
+        self.scalar_bitxor_assign(&mut boolean_block.0, 1u8, streams);
+    }
 }
@@ -0,0 +1,190 @@
+use crate::core_crypto::gpu::glwe_ciphertext_list::CudaGlweCiphertextList;
+use crate::core_crypto::gpu::lwe_ciphertext_list::CudaLweCiphertextList;
+use crate::core_crypto::gpu::vec::CudaVec;
+use crate::core_crypto::gpu::{
+    cuda_keyswitch_lwe_ciphertext_async,
+    cuda_multi_bit_programmable_bootstrap_lwe_ciphertext_async,
+    cuda_programmable_bootstrap_lwe_ciphertext_async, CudaStreams,
+};
+use crate::core_crypto::prelude::LweCiphertextCount;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaRadixCiphertext};
+use crate::integer::gpu::server_key::{CudaBootstrappingKey, CudaServerKey};
+use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::PBSOrder;
+
+impl CudaServerKey {
+    /// Keyswitches each block of the ciphertext to the small LWE key, without bootstrapping
+    ///
+    /// The input blocks must be encrypted under the big LWE key, i.e. have a
+    /// [PBSOrder::KeyswitchBootstrap] order. The `pbs_order` of the output blocks is set to
+    /// [PBSOrder::BootstrapKeyswitch], as they now only need a bootstrap to get back under the
+    /// big key, see [Self::bootstrap].
+    ///
+    /// This allows to schedule the keyswitch and the bootstrap of a block separately.
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// # // TODO GPU DRIFT UPDATE
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::shortint::PBSOrder;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex::new(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(
+    /// # // TODO GPU DRIFT UPDATE
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    ///     num_blocks,
+    ///     &streams,
+    /// );
+    ///
+    /// let msg = 14u8;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    /// let d_ct_ks = sks.keyswitch(&d_ct, &streams);
+    /// assert!(d_ct_ks
+    ///     .as_ref()
+    ///     .info
+    ///     .blocks
+    ///     .iter()
+    ///     .all(|block| block.pbs_order == PBSOrder::BootstrapKeyswitch));
+    ///
+    /// let d_ct_res = sks.bootstrap(&d_ct_ks, &streams);
+    ///
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg);
+    /// ```
+    pub fn keyswitch<T: CudaIntegerRadixCiphertext>(&self, ct: &T, streams: &CudaStreams) -> T {
+        let input = ct.as_ref();
+        assert!(
+            input
+                .info
+                .blocks
+                .iter()
+                .all(|block| block.pbs_order == PBSOrder::KeyswitchBootstrap),
+            "The blocks to keyswitch must be encrypted under the big LWE key"
+        );
+
+        let num_blocks = input.d_blocks.lwe_ciphertext_count().0;
+        let mut d_blocks = CudaLweCiphertextList::new(
+            self.key_switching_key
+                .output_key_lwe_size()
+                .to_lwe_dimension(),
+            LweCiphertextCount(num_blocks),
+            self.ciphertext_modulus,
+            streams,
+        );
+        let indexes = (0..num_blocks as u64).collect::<Vec<_>>();
+
+        unsafe {
+            let mut d_indexes = CudaVec::<u64>::new_async(num_blocks, streams, 0);
+            d_indexes.copy_from_cpu_async(&indexes, streams, 0);
+
+            cuda_keyswitch_lwe_ciphertext_async(
+                &self.key_switching_key,
+                &input.d_blocks,
+                &mut d_blocks,
+                &d_indexes,
+                &d_indexes,
+                streams,
+            );
+            streams.synchronize();
+        }
+
+        let mut info = input.info.clone();
+        for block in info.blocks.iter_mut() {
+            block.pbs_order = PBSOrder::BootstrapKeyswitch;
+        }
+
+        T::from(CudaRadixCiphertext::new(d_blocks, info))
+    }
+
+    /// Bootstraps each block of a keyswitched ciphertext back to the big LWE key
+    ///
+    /// The input blocks must be encrypted under the small LWE key, i.e. have a
+    /// [PBSOrder::BootstrapKeyswitch] order, as returned by [Self::keyswitch]. The
+    /// bootstrap extracts the message of each block, carries are cleared, and the
+    /// `pbs_order` of the output blocks is set back to [PBSOrder::KeyswitchBootstrap].
+    pub fn bootstrap<T: CudaIntegerRadixCiphertext>(&self, ct: &T, streams: &CudaStreams) -> T {
+        let input = ct.as_ref();
+        assert!(
+            input
+                .info
+                .blocks
+                .iter()
+                .all(|block| block.pbs_order == PBSOrder::BootstrapKeyswitch),
+            "The blocks to bootstrap must be encrypted under the small LWE key"
+        );
+
+        let lut = self.generate_lookup_table(|x| x % self.message_modulus.0);
+        let d_accumulator = CudaGlweCiphertextList::from_glwe_ciphertext(&lut.acc, streams);
+
+        let num_blocks = input.d_blocks.lwe_ciphertext_count().0;
+        // A single LUT is used for all the blocks
+        let lut_indexes = vec![0u64; num_blocks];
+        let indexes = (0..num_blocks as u64).collect::<Vec<_>>();
+
+        let output_lwe_dimension = match &self.bootstrapping_key {
+            CudaBootstrappingKey::Classic(d_bsk) => d_bsk.output_lwe_dimension(),
+            CudaBootstrappingKey::MultiBit(d_bsk) => d_bsk.output_lwe_dimension(),
+        };
+        let mut d_blocks = CudaLweCiphertextList::new(
+            output_lwe_dimension,
+            LweCiphertextCount(num_blocks),
+            self.ciphertext_modulus,
+            streams,
+        );
+
+        unsafe {
+            let mut d_lut_indexes = CudaVec::<u64>::new_async(num_blocks, streams, 0);
+            d_lut_indexes.copy_from_cpu_async(&lut_indexes, streams, 0);
+            let mut d_indexes = CudaVec::<u64>::new_async(num_blocks, streams, 0);
+            d_indexes.copy_from_cpu_async(&indexes, streams, 0);
+
+            match &self.bootstrapping_key {
+                CudaBootstrappingKey::Classic(d_bsk) => {
+                    cuda_programmable_bootstrap_lwe_ciphertext_async(
+                        &input.d_blocks,
+                        &mut d_blocks,
+                        &d_accumulator,
+                        &d_lut_indexes,
+                        &d_indexes,
+                        &d_indexes,
+                        LweCiphertextCount(num_blocks),
+                        d_bsk,
+                        streams,
+                    );
+                }
+                CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
+                    cuda_multi_bit_programmable_bootstrap_lwe_ciphertext_async(
+                        &input.d_blocks,
+                        &mut d_blocks,
+                        &d_accumulator,
+                        &d_lut_indexes,
+                        &d_indexes,
+                        &d_indexes,
+                        d_multibit_bsk,
+                        streams,
+                    );
+                }
+            }
+            streams.synchronize();
+        }
+
+        let mut info = input.info.clone();
+        for block in info.blocks.iter_mut() {
+            block.degree = lut.degree;
+            block.noise_level = NoiseLevel::NOMINAL;
+            block.pbs_order = PBSOrder::KeyswitchBootstrap;
+        }
+
+        T::from(CudaRadixCiphertext::new(d_blocks, info))
+    }
+}
@@ -37,6 +37,7 @@ mod comparison;
 mod div_mod;
 mod even_odd;
 mod ilog2;
+mod keyswitch;
 mod mul;
 mod neg;
 mod oprf;
@@ -432,6 +433,84 @@ impl CudaServerKey {
         });
     }
 
+    /// Propagates the carries of all the ciphertexts of the batch
+    ///
+    /// Each ciphertext is propagated on its own stream of the first GPU of `streams`, so that the
+    /// propagations can run concurrently. All of them are launched before the streams are
+    /// synchronized.
+    ///
+    /// Ciphertexts that already have empty carries are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// # // TODO GPU DRIFT UPDATE
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex::new(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// # // TODO GPU DRIFT UPDATE
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// let msgs = [15u64, 100, 200];
+    /// let mut d_cts = msgs
+    ///     .iter()
+    ///     .map(|msg| {
+    ///         let ct = cks.encrypt(*msg);
+    ///         let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///         // Leaves the carries dirty
+    ///         sks.unchecked_add(&d_ct, &d_ct, &streams)
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// sks.full_propagate_batch(&mut d_cts, &streams);
+    ///
+    /// for (d_ct, msg) in d_cts.iter().zip(msgs) {
+    ///     let ct = d_ct.to_radix_ciphertext(&streams);
+    ///     let dec: u64 = cks.decrypt(&ct);
+    ///     assert_eq!(dec, (2 * msg) % 256);
+    /// }
+    /// ```
+    pub fn full_propagate_batch<T: CudaIntegerRadixCiphertext>(
+        &self,
+        cts: &mut [T],
+        streams: &CudaStreams,
+    ) {
+        let dirty_cts = cts
+            .iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .collect::<Vec<_>>();
+        if dirty_cts.is_empty() {
+            return;
+        }
+
+        // The work queued on `streams` may still write the ciphertexts
+        streams.synchronize();
+
+        let streams_vector = dirty_cts
+            .iter()
+            .map(|_| CudaStreams::new_single_gpu(streams.gpu_indexes[0]))
+            .collect::<Vec<_>>();
+
+        for (ct, ct_streams) in dirty_cts.into_iter().zip(streams_vector.iter()) {
+            unsafe {
+                self.full_propagate_assign_async(ct, ct_streams);
+            }
+        }
+
+        for ct_streams in &streams_vector {
+            ct_streams.synchronize();
+        }
+    }
+
     /// Prepend trivial zero LSB blocks to an existing [`CudaUnsignedRadixCiphertext`] or
     /// [`CudaSignedRadixCiphertext`](`crate::integer::gpu::ciphertext::CudaSignedRadixCiphertext`)
     /// and returns the result as a new ciphertext on GPU. This can be useful for casting
@@ -886,6 +965,102 @@ impl CudaServerKey {
         }
     }
 
+    /// Applies a univariate function, given as a table of its values, on each block
+    ///
+    /// `lut[m]` is the value the function takes for the message `m`, so the table must have
+    /// exactly `message_modulus` entries and each entry must fit in the message and carry
+    /// space, otherwise an error is returned. Carries of the input are propagated first if
+    /// needed, so that each block holds a single message.
+    ///
+    /// This is the GPU counterpart of the shortint `apply_lookup_table`, applied on all the
+    /// blocks of the radix ciphertext.
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// # // TODO GPU DRIFT UPDATE
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex::new(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(
+    /// # // TODO GPU DRIFT UPDATE
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    ///     num_blocks,
+    ///     &streams,
+    /// );
+    ///
+    /// // Each block holds 2 bits
+    /// let msg = 0b10_01_11_00u8;
+    /// let ct = cks.encrypt(msg);
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// // Increment each block, modulo the message modulus
+    /// let lut = [1, 2, 3, 0];
+    /// let d_ct_res = sks.apply_lookup_table(&d_ct, &lut, &streams).unwrap();
+    ///
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 0b11_10_00_01u8);
+    /// ```
+    pub fn apply_lookup_table<T>(
+        &self,
+        ct: &T,
+        lut: &[u64],
+        streams: &CudaStreams,
+    ) -> crate::Result<T>
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        if lut.len() as u64 != self.message_modulus.0 {
+            return Err(crate::error!(
+                "The lookup table must have {} entries, got {}",
+                self.message_modulus.0,
+                lut.len()
+            ));
+        }
+        let max_value = self.message_modulus.0 * self.carry_modulus.0 - 1;
+        if let Some(value) = lut.iter().find(|value| **value > max_value) {
+            return Err(crate::error!(
+                "The lookup table value {value} does not fit in the message and carry space, \
+                the maximum is {max_value}"
+            ));
+        }
+
+        let tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            let mut propagated = ct.duplicate(streams);
+            unsafe {
+                self.full_propagate_assign_async(&mut propagated, streams);
+            }
+            tmp_ct = propagated;
+            &tmp_ct
+        };
+
+        let lut = self.generate_lookup_table(|x| lut[(x % self.message_modulus.0) as usize]);
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let mut result = ct.duplicate(streams);
+        unsafe {
+            self.apply_lookup_table_async(
+                result.as_mut(),
+                ct.as_ref(),
+                &lut,
+                0..num_blocks,
+                streams,
+            );
+        }
+        streams.synchronize();
+
+        Ok(result)
+    }
+
     /// Applies the lookup table on the range of ciphertexts
     ///
     /// The output must have exactly block_range.len() blocks
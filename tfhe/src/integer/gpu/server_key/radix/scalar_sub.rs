@@ -140,8 +140,27 @@ impl CudaServerKey {
         Scalar: DecomposableInto<u8> + Numeric + TwosComplementNegation + CastInto<u64>,
         T: CudaIntegerRadixCiphertext,
     {
-        let mut result = unsafe { ct.duplicate_async(streams) };
-        self.scalar_sub_assign(&mut result, scalar, streams);
+        let result = unsafe { self.scalar_sub_async(ct, scalar, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn scalar_sub_async<Scalar, T>(
+        &self,
+        ct: &T,
+        scalar: Scalar,
+        streams: &CudaStreams,
+    ) -> T
+    where
+        Scalar: DecomposableInto<u8> + Numeric + TwosComplementNegation + CastInto<u64>,
+        T: CudaIntegerRadixCiphertext,
+    {
+        let mut result = ct.duplicate_async(streams);
+        self.scalar_sub_assign_async(&mut result, scalar, streams);
         result
     }
 
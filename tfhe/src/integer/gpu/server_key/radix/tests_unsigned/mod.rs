@@ -3,7 +3,10 @@ pub(crate) mod test_bitwise_op;
 pub(crate) mod test_cmux;
 pub(crate) mod test_comparison;
 pub(crate) mod test_div_mod;
+mod test_encrypt_and_upload;
+mod test_full_propagate;
 pub(crate) mod test_ilog2;
+mod test_keyswitch;
 pub(crate) mod test_mul;
 pub(crate) mod test_neg;
 pub(crate) mod test_rotate;
@@ -5,13 +5,16 @@ use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
 use crate::integer::gpu::CudaServerKey;
+use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_add_test, default_sum_ciphertexts_vec_test, unchecked_add_assign_test,
-    unchecked_add_test,
+    unchecked_add_test, NB_CTXT,
 };
 use crate::integer::server_key::radix_parallel::tests_unsigned::test_add::default_overflowing_add_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
 use crate::shortint::parameters::current_params::*;
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_unchecked_add);
 create_gpu_parameterized_test!(integer_unchecked_add_assign);
@@ -20,6 +23,7 @@ create_gpu_parameterized_test!(multi_device_integer_add);
 create_gpu_parameterized_test!(integer_sum_ciphertexts_vec);
 create_gpu_parameterized_test!(integer_default_overflowing_add);
 create_gpu_parameterized_test!(multi_device_integer_default_overflowing_add);
+create_gpu_parameterized_test!(integer_unchecked_add_batch);
 
 fn integer_unchecked_add<P>(param: P)
 where
@@ -91,3 +95,55 @@ where
         default_overflowing_add_test(param, executor);
     }
 }
+
+fn integer_unchecked_add_batch<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let (cks, _) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let streams = CudaStreams::new_multi_gpu();
+    let sks = CudaServerKey::new(cks.as_ref(), &streams);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    let batch_size = 16;
+    let clear_lhs = (0..batch_size)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let clear_rhs = (0..batch_size)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+
+    let upload = |clears: &[u64]| {
+        clears
+            .iter()
+            .map(|clear| {
+                CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(*clear), &streams)
+            })
+            .collect::<Vec<_>>()
+    };
+    let d_lhs = upload(&clear_lhs);
+    let d_rhs = upload(&clear_rhs);
+
+    let d_results = sks.unchecked_add_batch(&d_lhs, &d_rhs, &streams);
+
+    assert_eq!(d_results.len(), batch_size);
+    for ((d_result, l), r) in d_results.iter().zip(&clear_lhs).zip(&clear_rhs) {
+        let decrypted: u64 = cks.decrypt(&d_result.to_radix_ciphertext(&streams));
+        assert_eq!(decrypted, (l + r) % modulus, "Invalid result for {l} + {r}");
+    }
+
+    // Inputs are not modified
+    let decrypted: u64 = cks.decrypt(&d_lhs[0].to_radix_ciphertext(&streams));
+    assert_eq!(decrypted, clear_lhs[0]);
+
+    assert!(sks
+        .unchecked_add_batch::<CudaUnsignedRadixCiphertext>(&[], &[], &streams)
+        .is_empty());
+}
@@ -1,13 +1,19 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
 use crate::integer::gpu::CudaServerKey;
+use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_bitand_test, default_bitnot_test, default_bitor_test, default_bitxor_test,
     unchecked_bitand_test, unchecked_bitnot_test, unchecked_bitor_test, unchecked_bitxor_test,
+    NB_CTXT,
 };
+use crate::integer::{IntegerKeyKind, RadixClientKey};
 use crate::shortint::parameters::current_params::*;
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_unchecked_bitnot);
 create_gpu_parameterized_test!(integer_unchecked_bitand);
@@ -17,6 +23,41 @@ create_gpu_parameterized_test!(integer_bitnot);
 create_gpu_parameterized_test!(integer_bitand);
 create_gpu_parameterized_test!(integer_bitor);
 create_gpu_parameterized_test!(integer_bitxor);
+create_gpu_parameterized_test!(integer_radix_ciphertext_bitnot);
+
+fn integer_radix_ciphertext_bitnot<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let (cks, _) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let streams = CudaStreams::new_multi_gpu();
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = cks.parameters().message_modulus();
+    // message_modulus^vec_length
+    let modulus = message_modulus.0.pow(NB_CTXT as u32);
+
+    for clear in [0, modulus - 1, rng.gen::<u64>() % modulus] {
+        let ct = cks.encrypt(clear);
+        let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+
+        let d_ct_res = CudaUnsignedRadixCiphertext {
+            ciphertext: d_ct.as_ref().bitnot(&streams),
+        };
+
+        for block in d_ct_res.as_ref().info.blocks.iter() {
+            assert_eq!(block.degree.get(), message_modulus.0 - 1);
+        }
+
+        let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(decrypted, !clear % modulus, "Invalid bitnot of {clear}");
+    }
+}
 
 fn integer_unchecked_bitnot<P>(param: P)
 where
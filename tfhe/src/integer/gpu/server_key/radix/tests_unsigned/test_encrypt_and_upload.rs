@@ -0,0 +1,74 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
+use crate::integer::gpu::server_key::radix::tests_unsigned::create_gpu_parameterized_test;
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::NB_CTXT;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+use crate::shortint::parameters::current_params::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_gpu_parameterized_test!(integer_encrypt_and_upload_batch);
+create_gpu_parameterized_test!(integer_radix_ciphertext_view_to_radix_ciphertext);
+
+fn integer_encrypt_and_upload_batch<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let (cks, _) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let streams = CudaStreams::new_multi_gpu();
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    let clears = (0..16)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+
+    let (d_cts, _timings) = cks.encrypt_and_upload_batch(&clears, &streams);
+
+    assert_eq!(d_cts.len(), clears.len());
+    for (d_ct, clear) in d_cts.iter().zip(clears) {
+        let ct = d_ct.to_radix_ciphertext(&streams);
+        let decrypted: u64 = cks.decrypt(&ct);
+        assert_eq!(decrypted, clear);
+    }
+}
+
+fn integer_radix_ciphertext_view_to_radix_ciphertext<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let (cks, _) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let streams = CudaStreams::new_multi_gpu();
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    for clear in [0, modulus - 1, rng.gen::<u64>() % modulus] {
+        let ct = cks.encrypt(clear);
+        let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+        let d_ct_copy = d_ct.duplicate(&streams);
+
+        let view = d_ct.as_ref().as_view();
+        let ct_from_view = view.to_radix_ciphertext(&streams);
+        assert_eq!(ct_from_view, ct);
+
+        let decrypted: u64 = cks.decrypt(&ct_from_view);
+        assert_eq!(decrypted, clear);
+
+        // Reading through the view must leave the original untouched
+        assert!(d_ct.is_equal(&d_ct_copy, &streams));
+        assert_eq!(d_ct.to_radix_ciphertext(&streams), ct);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
+use crate::integer::gpu::server_key::radix::tests_unsigned::create_gpu_parameterized_test;
+use crate::integer::gpu::CudaServerKey;
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::NB_CTXT;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+use crate::shortint::parameters::current_params::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_gpu_parameterized_test!(integer_full_propagate_batch);
+
+fn integer_full_propagate_batch<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let (cks, _) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let streams = CudaStreams::new_multi_gpu();
+    let sks = CudaServerKey::new(cks.as_ref(), &streams);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    let clears = (0..8)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+
+    let mut d_cts = clears
+        .iter()
+        .map(|clear| {
+            let ct = cks.encrypt(*clear);
+            let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+            sks.unchecked_add(&d_ct, &d_ct, &streams)
+        })
+        .collect::<Vec<_>>();
+    // One ciphertext of the batch already has empty carries
+    d_cts.push(CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+        &cks.encrypt(clears[0]),
+        &streams,
+    ));
+
+    sks.full_propagate_batch(&mut d_cts, &streams);
+
+    let expected = clears
+        .iter()
+        .map(|clear| (2 * clear) % modulus)
+        .chain(std::iter::once(clears[0]));
+    for (d_ct, expected) in d_cts.iter().zip(expected) {
+        assert!(d_ct.block_carries_are_empty());
+        let ct = d_ct.to_radix_ciphertext(&streams);
+        let decrypted: u64 = cks.decrypt(&ct);
+        assert_eq!(decrypted, expected);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
+use crate::integer::gpu::server_key::radix::tests_unsigned::create_gpu_parameterized_test;
+use crate::integer::gpu::CudaServerKey;
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::NB_CTXT;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+use crate::shortint::parameters::current_params::*;
+use crate::shortint::parameters::*;
+use crate::shortint::PBSOrder;
+use rand::Rng;
+
+create_gpu_parameterized_test!(integer_keyswitch_then_bootstrap);
+
+fn integer_keyswitch_then_bootstrap<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let (cks, _) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let streams = CudaStreams::new_multi_gpu();
+    let sks = CudaServerKey::new(cks.as_ref(), &streams);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    for clear in [0, modulus - 1, rng.gen::<u64>() % modulus] {
+        let ct = cks.encrypt(clear);
+        let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+
+        let d_ct_ks = sks.keyswitch(&d_ct, &streams);
+        assert!(d_ct_ks
+            .as_ref()
+            .info
+            .blocks
+            .iter()
+            .all(|block| block.pbs_order == PBSOrder::BootstrapKeyswitch));
+
+        let d_ct_res = sks.bootstrap(&d_ct_ks, &streams);
+        assert!(d_ct_res
+            .as_ref()
+            .info
+            .blocks
+            .iter()
+            .all(|block| block.pbs_order == PBSOrder::KeyswitchBootstrap));
+
+        let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(decrypted, clear);
+    }
+}
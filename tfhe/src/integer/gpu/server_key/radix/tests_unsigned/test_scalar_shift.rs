@@ -1,18 +1,64 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
 use crate::integer::gpu::CudaServerKey;
+use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_scalar_left_shift_test, default_scalar_right_shift_test,
-    unchecked_scalar_left_shift_test, unchecked_scalar_right_shift_test,
+    unchecked_scalar_left_shift_test, unchecked_scalar_right_shift_test, NB_CTXT,
 };
+use crate::integer::{IntegerKeyKind, RadixClientKey};
 use crate::shortint::parameters::current_params::*;
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_unchecked_scalar_left_shift);
 create_gpu_parameterized_test!(integer_unchecked_scalar_right_shift);
 create_gpu_parameterized_test!(integer_scalar_left_shift);
 create_gpu_parameterized_test!(integer_scalar_right_shift);
+create_gpu_parameterized_test!(integer_block_shift_left);
+
+fn integer_block_shift_left<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let (cks, _) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let streams = CudaStreams::new_multi_gpu();
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = cks.parameters().message_modulus().0;
+    // message_modulus^vec_length
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    for num_blocks in 0..=NB_CTXT + 1 {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct = cks.encrypt(clear);
+        let mut d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+        d_ct.as_mut().block_shift_left(num_blocks, &streams);
+
+        assert_eq!(d_ct.as_ref().d_blocks.lwe_ciphertext_count().0, NB_CTXT);
+        assert!(d_ct.block_carries_are_empty());
+
+        let ct_res = d_ct.to_radix_ciphertext(&streams);
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        let expected = if num_blocks >= NB_CTXT {
+            0
+        } else {
+            (clear * message_modulus.pow(num_blocks as u32)) % modulus
+        };
+        assert_eq!(
+            decrypted, expected,
+            "Invalid block shift of {clear} by {num_blocks} blocks"
+        );
+    }
+}
 
 fn integer_unchecked_scalar_right_shift<P>(param: P)
 where
@@ -5,11 +5,12 @@ use super::backward_compatibility::key_switching_key::{
 use super::{ClientKey, CompressedServerKey, ServerKey};
 use crate::conformance::ParameterSetConformant;
 use crate::integer::client_key::secret_encryption_key::SecretEncryptionKeyView;
-use crate::integer::IntegerCiphertext;
+use crate::integer::{IntegerCiphertext, RadixCiphertext};
 use crate::shortint::key_switching_key::KeySwitchingKeyConformanceParams;
 use crate::shortint::parameters::ShortintKeySwitchingParameters;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use tfhe_versionable::Versionize;
 
 #[cfg(test)]
@@ -181,6 +182,167 @@ impl<'keys> KeySwitchingKeyView<'keys> {
     }
 }
 
+/// Key to move radix ciphertexts to a parameter set with a different message modulus
+///
+/// Contrary to the [KeySwitchingKey], the blocks are re-encoded in the radix base of the
+/// destination parameters, see [Self::change_radix_base].
+#[derive(Clone, Debug)]
+pub struct RadixBaseChangeKey {
+    pub(crate) key: crate::shortint::KeySwitchingKey,
+}
+
+impl RadixBaseChangeKey {
+    /// # Panics
+    ///
+    /// Panics if the message moduli are not powers of two, if the number of message bits of one
+    /// parameter set is not a multiple of the other (e.g. going from 2 bits to 3 bits), or if a
+    /// block of one parameter set cannot hold a whole block of the other, e.g. going from 2 bits
+    /// of message with 2 bits of carry to 4 bits of message is possible, but going to 8 bits of
+    /// message is not.
+    pub fn new<'input_key, InputEncryptionKey, ClientKeyType>(
+        input_key_pair: (InputEncryptionKey, &ServerKey),
+        output_key_pair: (&ClientKeyType, &ServerKey),
+        params: ShortintKeySwitchingParameters,
+    ) -> Self
+    where
+        InputEncryptionKey: Into<SecretEncryptionKeyView<'input_key>>,
+        ClientKeyType: AsRef<ClientKey>,
+    {
+        let input_secret_encryption_key: SecretEncryptionKeyView<'_> = input_key_pair.0.into();
+        let src_key = &input_key_pair.1.key;
+        let dst_key = &output_key_pair.1.key;
+
+        let src_msg_mod = src_key.message_modulus.0;
+        let dst_msg_mod = dst_key.message_modulus.0;
+        assert!(
+            src_msg_mod.is_power_of_two() && dst_msg_mod.is_power_of_two(),
+            "The message moduli ({src_msg_mod}, {dst_msg_mod}) need to be powers of two"
+        );
+        let (src_bits, dst_bits) = (src_msg_mod.ilog2(), dst_msg_mod.ilog2());
+        assert!(
+            src_bits % dst_bits == 0 || dst_bits % src_bits == 0,
+            "The number of message bits ({src_bits}, {dst_bits}) need to be multiples of one another"
+        );
+        if src_msg_mod < dst_msg_mod {
+            assert!(
+                src_msg_mod * src_key.carry_modulus.0 >= dst_msg_mod,
+                "A source block cannot hold a destination block message, \
+                not enough carry space"
+            );
+        } else {
+            assert!(
+                dst_msg_mod * dst_key.carry_modulus.0 >= src_msg_mod,
+                "A destination block cannot hold a source block message, \
+                not enough carry space"
+            );
+        }
+
+        Self {
+            key: crate::shortint::KeySwitchingKey::new(
+                (input_secret_encryption_key.key, Some(src_key)),
+                (&output_key_pair.0.as_ref().key, dst_key),
+                params,
+            ),
+        }
+    }
+
+    /// Re-encodes the radix ciphertext in the radix base of the destination parameters
+    ///
+    /// The decrypted value is unchanged:
+    ///
+    /// - when the destination message modulus is bigger, consecutive blocks are packed into one
+    ///   block before being cast, so the output has fewer blocks.
+    /// - when the destination message modulus is smaller, each block is cast and decomposed into
+    ///   several blocks, so the output has more blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the carries of the input are not empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::key_switching_key::RadixBaseChangeKey;
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::current_params::V1_0_PARAM_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M128;
+    /// use tfhe::shortint::parameters::{ShortintKeySwitchingParameters, PARAM_MESSAGE_2_CARRY_2};
+    ///
+    /// let (cks_1, sks_1) = gen_keys_radix(V1_0_PARAM_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M128, 8);
+    /// let (cks_2, sks_2) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, 4);
+    ///
+    /// let params = ShortintKeySwitchingParameters::new(
+    ///     cks_2.parameters().ks_base_log(),
+    ///     cks_2.parameters().ks_level(),
+    ///     cks_2.parameters().encryption_key_choice(),
+    /// );
+    /// let key = RadixBaseChangeKey::new((&cks_1, &sks_1), (&cks_2, &sks_2), params);
+    ///
+    /// let ct_1 = cks_1.encrypt(173u8);
+    /// let ct_2 = key.change_radix_base(&ct_1);
+    /// assert_eq!(ct_2.blocks().len(), 4);
+    ///
+    /// let clear: u8 = cks_2.decrypt(&ct_2);
+    /// assert_eq!(clear, 173);
+    /// ```
+    pub fn change_radix_base(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        assert!(
+            ct.block_carries_are_empty(),
+            "The input ciphertext must have empty carries"
+        );
+
+        let src_key = self.key.src_server_key.as_ref().unwrap();
+        let dst_key = &self.key.dest_server_key;
+        let src_msg_mod = src_key.message_modulus.0;
+        let dst_msg_mod = dst_key.message_modulus.0;
+
+        let blocks = match src_msg_mod.cmp(&dst_msg_mod) {
+            Ordering::Equal => ct
+                .blocks
+                .par_iter()
+                .map(|block| self.key.cast(block))
+                .collect::<Vec<_>>(),
+            Ordering::Less => {
+                // Each destination block holds `ratio` source blocks
+                let ratio = (dst_msg_mod.ilog2() / src_msg_mod.ilog2()) as usize;
+                ct.blocks
+                    .par_chunks(ratio)
+                    .map(|chunk| {
+                        let mut packed = chunk[0].clone();
+                        for (i, block) in chunk.iter().enumerate().skip(1) {
+                            let shift = u8::try_from(src_msg_mod.pow(i as u32)).unwrap();
+                            let shifted = src_key.unchecked_scalar_mul(block, shift);
+                            src_key.unchecked_add_assign(&mut packed, &shifted);
+                        }
+                        self.key.cast(&packed)
+                    })
+                    .collect::<Vec<_>>()
+            }
+            Ordering::Greater => {
+                // Each source block is split into `ratio` destination blocks
+                let ratio = src_msg_mod.ilog2() / dst_msg_mod.ilog2();
+                let dst_bits = dst_msg_mod.ilog2();
+                let extract_fns = (0..ratio)
+                    .map(|i| move |x: u64| (x >> (i * dst_bits)) % dst_msg_mod)
+                    .collect::<Vec<_>>();
+                let functions = extract_fns
+                    .iter()
+                    .map(|f| f as &(dyn Fn(u64) -> u64 + Sync))
+                    .collect::<Vec<_>>();
+                ct.blocks
+                    .par_iter()
+                    .flat_map(|block| {
+                        self.key
+                            .as_view()
+                            .cast_and_apply_functions(block, Some(&functions))
+                    })
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        RadixCiphertext::from(blocks)
+    }
+}
+
 // This is used to have the ability to build a keyswitching key without owning the ServerKey
 // It is a bit of a hack, but at this point it seems ok
 pub(crate) struct CompressedKeySwitchingKeyBuildHelper<'keys> {
@@ -1,4 +1,4 @@
-use crate::integer::key_switching_key::KeySwitchingKey;
+use crate::integer::key_switching_key::{KeySwitchingKey, RadixBaseChangeKey};
 use crate::integer::keycache::KEY_CACHE;
 use crate::integer::parameters::IntegerCompactCiphertextListExpansionMode;
 use crate::integer::{
@@ -12,7 +12,9 @@ use crate::shortint::parameters::{
 use crate::shortint::parameters::current_params::classic::gaussian::p_fail_2_minus_128::ks_pbs::{
     V1_0_PARAM_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M128,
     V1_0_PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128,
+    V1_0_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
 };
+use crate::shortint::parameters::current_params::classic::gaussian::p_fail_2_minus_64::ks_pbs::V1_0_PARAM_MESSAGE_4_CARRY_4_KS_PBS_GAUSSIAN_2M64;
 use crate::shortint::parameters::current_params::classic::tuniform::p_fail_2_minus_128::ks_pbs::V1_0_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
 use crate::shortint::parameters::current_params::compact_public_key_only::p_fail_2_minus_128::ks_pbs::V1_0_PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
 use crate::shortint::parameters::current_params::key_switching::p_fail_2_minus_128::ks_pbs::{
@@ -266,3 +268,73 @@ fn test_cpk_encrypt_cast_to_big_compute_big_ci_run_filter() {
         V1_0_PARAM_KEYSWITCH_PKE_TO_BIG_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
     )
 }
+
+#[test]
+fn test_change_radix_base_ci_run_filter() {
+    use rand::Rng;
+
+    let (cks_2_bits, sks_2_bits) = KEY_CACHE.get_from_params(
+        V1_0_PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128,
+        IntegerKeyKind::Radix,
+    );
+    let cks_2_bits = RadixClientKey::from((cks_2_bits, 8));
+    let (cks_4_bits, sks_4_bits) = KEY_CACHE.get_from_params(
+        V1_0_PARAM_MESSAGE_4_CARRY_4_KS_PBS_GAUSSIAN_2M64,
+        IntegerKeyKind::Radix,
+    );
+    let cks_4_bits = RadixClientKey::from((cks_4_bits, 4));
+
+    let ksk_params = |cks: &RadixClientKey| {
+        ShortintKeySwitchingParameters::new(
+            cks.parameters().ks_base_log(),
+            cks.parameters().ks_level(),
+            cks.parameters().encryption_key_choice(),
+        )
+    };
+    let to_4_bits = RadixBaseChangeKey::new(
+        (&cks_2_bits, &sks_2_bits),
+        (&cks_4_bits, &sks_4_bits),
+        ksk_params(&cks_4_bits),
+    );
+    let to_2_bits = RadixBaseChangeKey::new(
+        (&cks_4_bits, &sks_4_bits),
+        (&cks_2_bits, &sks_2_bits),
+        ksk_params(&cks_2_bits),
+    );
+
+    let mut rng = rand::thread_rng();
+    for clear in [0u16, u16::MAX, rng.gen::<u16>()] {
+        let ct = cks_2_bits.encrypt(clear);
+
+        let ct_4_bits = to_4_bits.change_radix_base(&ct);
+        assert_eq!(ct_4_bits.blocks().len(), 4);
+        assert!(ct_4_bits
+            .blocks()
+            .iter()
+            .all(|block| block.message_modulus.0 == 16));
+        let decrypted: u16 = cks_4_bits.decrypt(&ct_4_bits);
+        assert_eq!(decrypted, clear);
+
+        let ct_2_bits = to_2_bits.change_radix_base(&ct_4_bits);
+        assert_eq!(ct_2_bits.blocks().len(), 8);
+        assert!(ct_2_bits.block_carries_are_empty());
+        let decrypted: u16 = cks_2_bits.decrypt(&ct_2_bits);
+        assert_eq!(decrypted, clear);
+    }
+
+    // 2 bits blocks cannot be packed into 3 bits blocks, nor 3 bits blocks split into 2 bits ones
+    let (cks_3_bits, sks_3_bits) = KEY_CACHE.get_from_params(
+        V1_0_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+        IntegerKeyKind::Radix,
+    );
+    let cks_3_bits = RadixClientKey::from((cks_3_bits, 6));
+    for (src, dst) in [
+        ((&cks_2_bits, &sks_2_bits), (&cks_3_bits, &sks_3_bits)),
+        ((&cks_3_bits, &sks_3_bits), (&cks_2_bits, &sks_2_bits)),
+    ] {
+        let result = std::panic::catch_unwind(|| {
+            RadixBaseChangeKey::new(src, dst, ksk_params(dst.0));
+        });
+        assert!(result.is_err());
+    }
+}
@@ -58,6 +58,28 @@ create_parameterized_test!(
     }
 );
 
+create_parameterized_test!(radix_rerandomize_128_bits {
+        coverage => {
+            COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        },
+        no_coverage => {
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        }
+    }
+);
+
+create_parameterized_test!(radix_rerandomize_dirty_carries_128_bits {
+        coverage => {
+            COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        },
+        no_coverage => {
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128,
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        }
+    }
+);
+
 /// Test that the public key can encrypt a 128 bit number
 /// in radix decomposition, and that the client key can decrypt it
 fn big_radix_encrypt_decrypt_128_bits(param: ClassicPBSParameters) {
@@ -147,3 +169,67 @@ fn radix_encrypt_decrypt_compact_128_bits_list(params: ClassicPBSParameters) {
         }
     }
 }
+
+/// Test that re-randomizing a ciphertext changes its bytes
+/// but not the value it decrypts to
+fn radix_rerandomize_128_bits(param: ClassicPBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let public_key = PublicKey::new(&cks);
+
+    let mut rng = rand::thread_rng();
+    let num_block = (128f64 / (param.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+
+    let clear = rng.gen::<u128>();
+
+    let ct = cks.encrypt_radix(clear, num_block);
+    let mut rerandomized = ct.clone();
+    sks.rerandomize_parallelized(&mut rerandomized, &public_key);
+
+    assert_ne!(
+        bincode::serialize(&ct).unwrap(),
+        bincode::serialize(&rerandomized).unwrap()
+    );
+    for (block, rerandomized_block) in ct.blocks.iter().zip(rerandomized.blocks.iter()) {
+        assert_eq!(block.degree, rerandomized_block.degree);
+    }
+
+    let dec: u128 = cks.decrypt_radix(&rerandomized);
+    assert_eq!(clear, dec);
+
+    // Re-randomizing again still decrypts to the same value
+    sks.rerandomize_parallelized(&mut rerandomized, &public_key);
+    let dec: u128 = cks.decrypt_radix(&rerandomized);
+    assert_eq!(clear, dec);
+}
+
+/// Test that re-randomizing a ciphertext whose blocks hold carries, and whose noise
+/// is at the maximum, keeps the value it decrypts to
+fn radix_rerandomize_dirty_carries_128_bits(param: ClassicPBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let public_key = PublicKey::new(&cks);
+
+    let mut rng = rand::thread_rng();
+    let num_block = (128f64 / (param.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+
+    let max_noise_level = sks.key.max_noise_level.get();
+
+    let mut clear = rng.gen::<u128>();
+    let mut ct = cks.encrypt_radix(clear, num_block);
+    // Sum fresh ciphertexts without propagating, until the noise reaches the maximum
+    for _ in 1..max_noise_level {
+        let clear_rhs = rng.gen::<u128>();
+        let ct_rhs = cks.encrypt_radix(clear_rhs, num_block);
+        sks.unchecked_add_assign(&mut ct, &ct_rhs);
+        clear = clear.wrapping_add(clear_rhs);
+    }
+    assert!(!ct.block_carries_are_empty());
+    assert!(ct
+        .blocks
+        .iter()
+        .all(|block| block.noise_level().get() == max_noise_level));
+
+    sks.rerandomize_parallelized(&mut ct, &public_key);
+
+    let dec: u128 = cks.decrypt_radix(&ct);
+    assert_eq!(clear, dec);
+}
@@ -15,7 +15,7 @@ use crate::integer::client_key::ClientKey;
 use crate::shortint::ciphertext::{Degree, MaxDegree};
 /// Error returned when the carry buffer is full.
 pub use crate::shortint::CheckError;
-use crate::shortint::{CarryModulus, MessageModulus, PBSParameters};
+use crate::shortint::{CarryModulus, CiphertextModulus, MessageModulus, PBSParameters};
 pub use radix::scalar_mul::ScalarMultiplier;
 pub use radix::scalar_sub::TwosComplementNegation;
 pub use radix_parallel::{MatchValues, MiniUnsignedInteger, Reciprocable};
@@ -211,6 +211,25 @@ impl ServerKey {
         self.key.carry_modulus
     }
 
+    /// Returns the modulus of the ciphertexts this key operates on
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let (_cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, 4);
+    ///
+    /// assert_eq!(
+    ///     sks.ciphertext_modulus(),
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128.ciphertext_modulus
+    /// );
+    /// ```
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus {
+        self.key.ciphertext_modulus
+    }
+
     pub fn num_bits_to_represent_unsigned_value<Clear>(&self, clear: Clear) -> usize
     where
         Clear: UnsignedInteger,
@@ -390,4 +409,20 @@ mod test {
             assert_eq!(modulus - 6, res);
         }
     }
+
+    #[test]
+    fn test_server_key_ciphertext_modulus() {
+        let param = crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+        let cks = ClientKey::new(param);
+
+        let sks = ServerKey::new_radix_server_key(&cks);
+        assert_eq!(sks.ciphertext_modulus(), param.ciphertext_modulus);
+
+        let csks = CompressedServerKey::new_radix_compressed_server_key(&cks);
+        let decompressed_sks: ServerKey = csks.decompress();
+        assert_eq!(
+            decompressed_sks.ciphertext_modulus(),
+            param.ciphertext_modulus
+        );
+    }
 }
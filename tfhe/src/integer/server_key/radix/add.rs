@@ -12,6 +12,9 @@ impl ServerKey {
     ///
     /// The result is returned as a new ciphertext.
     ///
+    /// See [Self::checked_add] for a version returning an error instead of
+    /// exceeding the capacity of the blocks.
+    ///
     /// # Example
     ///
     /// ```rust
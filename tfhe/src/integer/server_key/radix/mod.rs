@@ -11,10 +11,13 @@ pub(super) mod slice;
 mod sub;
 
 use super::ServerKey;
+use crate::error::InvalidRangeError;
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::ciphertext::{IntegerCiphertext, IntegerRadixCiphertext, RadixCiphertext};
 use crate::integer::encryption::encrypt_words_radix_impl;
 use crate::integer::{BooleanBlock, SignedRadixCiphertext};
+use slice::normalize_range;
+use std::ops::RangeBounds;
 
 mod even_odd;
 #[cfg(test)]
@@ -106,6 +109,9 @@ impl ServerKey {
     ///
     /// Trivial means that the value is not encrypted
     ///
+    /// Only the server key is needed, which makes it possible to mix
+    /// clear constants with encrypted values during a computation.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -430,6 +436,94 @@ impl ServerKey {
         ct_res
     }
 
+    /// Concatenates the blocks of several [`RadixCiphertext`] into a single, wider one.
+    ///
+    /// The first ciphertext of the slice holds the least significant blocks of the result.
+    /// Inputs with non-empty carries are propagated first, as carries cannot cross into the
+    /// next ciphertext.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let ct_low = cks.encrypt(0x34u64);
+    /// let ct_high = cks.encrypt(0x12u64);
+    ///
+    /// let ct_res = sks.concat_radix_blocks(&[ct_low, ct_high]);
+    /// assert_eq!(ct_res.blocks().len(), 8);
+    ///
+    /// // Decrypt
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(0x1234, res);
+    /// ```
+    pub fn concat_radix_blocks(&self, cts: &[RadixCiphertext]) -> RadixCiphertext {
+        let num_blocks = cts.iter().map(|ct| ct.blocks.len()).sum();
+        let mut blocks = Vec::with_capacity(num_blocks);
+
+        for ct in cts {
+            if ct.block_carries_are_empty() {
+                blocks.extend_from_slice(&ct.blocks);
+            } else {
+                let mut ct = ct.clone();
+                self.full_propagate_parallelized(&mut ct);
+                blocks.extend(ct.blocks);
+            }
+        }
+
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Extracts the blocks in `range` of a [`RadixCiphertext`] as a new, narrower one.
+    ///
+    /// The range is expressed in blocks, not bits. If the input has non-empty carries it is
+    /// propagated first, so that carries held by blocks below the range are not lost.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let num_blocks = 8;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let ct = cks.encrypt(0x1234u64);
+    ///
+    /// let ct_high = sks.slice_radix_blocks(&ct, 4..8).unwrap();
+    /// assert_eq!(ct_high.blocks().len(), 4);
+    ///
+    /// // Decrypt
+    /// let res: u64 = cks.decrypt(&ct_high);
+    /// assert_eq!(0x12, res);
+    /// ```
+    pub fn slice_radix_blocks<R>(
+        &self,
+        ct: &RadixCiphertext,
+        range: R,
+    ) -> Result<RadixCiphertext, InvalidRangeError>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = normalize_range(&range, ct.blocks.len())?;
+
+        if ct.block_carries_are_empty() {
+            Ok(self.scalar_blockslice_aligned(ct, range.start, range.end))
+        } else {
+            let mut ct = ct.clone();
+            self.full_propagate_parallelized(&mut ct);
+            Ok(self.scalar_blockslice_aligned(&ct, range.start, range.end))
+        }
+    }
+
     /// Extends the most significant blocks using the sign bit.
     /// Used to cast [SignedRadixCiphertext]
     ///
@@ -597,6 +691,58 @@ impl ServerKey {
         crate::integer::RadixCiphertext::from(blocks)
     }
 
+    /// Cast a RadixCiphertext to a RadixCiphertext with a possibly different number of blocks,
+    /// saturating to the maximum value of the target if the value does not fit.
+    ///
+    /// Also returns a boolean block that is true if the value did not fit in the target,
+    /// i.e. if information was lost.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let num_blocks = 8;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg = 300u16;
+    ///
+    /// let ct1 = cks.encrypt(msg);
+    ///
+    /// let (ct_res, overflowed) = sks.saturating_cast_to_unsigned(ct1, 4);
+    /// assert_eq!(ct_res.blocks().len(), 4);
+    ///
+    /// // Decrypt
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, u8::MAX);
+    /// assert!(cks.decrypt_bool(&overflowed));
+    /// ```
+    pub fn saturating_cast_to_unsigned(
+        &self,
+        mut source: RadixCiphertext,
+        target_num_blocks: usize,
+    ) -> (RadixCiphertext, BooleanBlock) {
+        if !source.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut source);
+        }
+
+        if target_num_blocks >= source.blocks.len() {
+            let result = self.cast_to_unsigned(source, target_num_blocks);
+            return (result, self.create_trivial_boolean_block(false));
+        }
+
+        let dropped_blocks = RadixCiphertext::from(source.blocks.split_off(target_num_blocks));
+        let overflowed = self.scalar_ne_parallelized(&dropped_blocks, 0u64);
+
+        let max_value: RadixCiphertext = self.create_trivial_max_radix(target_num_blocks);
+        let result = self.unchecked_if_then_else_parallelized(&overflowed, &max_value, &source);
+
+        (result, overflowed)
+    }
+
     /// Cast a RadixCiphertext or SignedRadixCiphertext to a SignedRadixCiphertext
     /// with a possibly different number of blocks
     ///
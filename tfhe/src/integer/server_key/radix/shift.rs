@@ -1,6 +1,6 @@
 use crate::core_crypto::commons::utils::izip;
 use crate::core_crypto::prelude::CastFrom;
-use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ciphertext::{IntegerRadixCiphertext, RadixCiphertext};
 use crate::integer::ServerKey;
 use std::ops::Rem;
 
@@ -52,6 +52,102 @@ impl ServerKey {
         *ctxt = self.blockshift_right(ctxt, shift);
     }
 
+    /// Rotates the blocks to the left, that is, towards the most significant block.
+    ///
+    /// This is a rotation of `num_blocks * log2(message_modulus)` bits, done without any PBS.
+    ///
+    /// Carries move along with their block, so the input should have clean carries
+    /// for the result to be the rotation of the input value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg = 0b1100_0110u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.rotate_left_blocks(&ct, 1);
+    ///
+    /// // Decrypt:
+    /// let clear: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.rotate_left(2), clear);
+    /// ```
+    pub fn rotate_left_blocks<T>(&self, ctxt: &T, num_blocks: usize) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut result = ctxt.clone();
+        self.rotate_left_blocks_assign(&mut result, num_blocks);
+        result
+    }
+
+    pub fn rotate_left_blocks_assign<T>(&self, ctxt: &mut T, num_blocks: usize)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let len = ctxt.blocks().len();
+        if len == 0 {
+            return;
+        }
+        // rotate right as the blocks are from LSB to MSB
+        ctxt.blocks_mut().rotate_right(num_blocks % len);
+    }
+
+    /// Rotates the blocks to the right, that is, towards the least significant block.
+    ///
+    /// This is a rotation of `num_blocks * log2(message_modulus)` bits, done without any PBS.
+    ///
+    /// Carries move along with their block, so the input should have clean carries
+    /// for the result to be the rotation of the input value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg = 0b1100_0110u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.rotate_right_blocks(&ct, 1);
+    ///
+    /// // Decrypt:
+    /// let clear: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg.rotate_right(2), clear);
+    /// ```
+    pub fn rotate_right_blocks<T>(&self, ctxt: &T, num_blocks: usize) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut result = ctxt.clone();
+        self.rotate_right_blocks_assign(&mut result, num_blocks);
+        result
+    }
+
+    pub fn rotate_right_blocks_assign<T>(&self, ctxt: &mut T, num_blocks: usize)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let len = ctxt.blocks().len();
+        if len == 0 {
+            return;
+        }
+        // rotate left as the blocks are from LSB to MSB
+        ctxt.blocks_mut().rotate_left(num_blocks % len);
+    }
+
     /// Computes homomorphically a right shift.
     ///
     /// The result is returned as a new ciphertext.
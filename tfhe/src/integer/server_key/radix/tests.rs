@@ -17,6 +17,7 @@ use crate::integer::tests::{
     create_parameterized_test, create_parameterized_test_classical_params,
 };
 use crate::integer::{IntegerKeyKind, RadixCiphertext, ServerKey, SignedRadixCiphertext, U256};
+use crate::shortint::ciphertext::NoiseLevel;
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::current_params::*;
@@ -50,6 +51,7 @@ create_parameterized_test_classical_params!(integer_encrypt_decrypt_256_bits_spe
 create_parameterized_test_classical_params!(integer_encrypt_decrypt_256_bits);
 create_parameterized_test_classical_params!(integer_unchecked_add);
 create_parameterized_test_classical_params!(integer_smart_add);
+create_parameterized_test_classical_params!(integer_checked_add_until_capacity_is_exceeded);
 create_parameterized_test!(
     integer_smart_add_128_bits {
         coverage => {
@@ -114,9 +116,12 @@ create_parameterized_test!(
 );
 
 create_parameterized_test_classical_params!(integer_create_trivial_min_max);
+create_parameterized_test_classical_params!(integer_create_trivial_radix_add);
 create_parameterized_test_classical_params!(integer_signed_decryption_correctly_sign_extend);
 create_parameterized_test_classical_params!(integer_scalar_blockslice);
 create_parameterized_test_classical_params!(integer_scalar_blockslice_assign);
+create_parameterized_test_classical_params!(integer_concat_slice_radix_blocks_128_bits);
+create_parameterized_test_classical_params!(integer_noise_level_introspection);
 create_parameterized_test_classical_params!(integer_unchecked_scalar_slice);
 create_parameterized_test_classical_params!(integer_unchecked_scalar_slice_assign);
 create_parameterized_test_classical_params!(integer_default_scalar_slice);
@@ -306,6 +311,44 @@ fn integer_smart_add(param: ClassicPBSParameters) {
     smart_add_test(param, executor);
 }
 
+fn integer_checked_add_until_capacity_is_exceeded(param: ClassicPBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32);
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+        let mut ct_res = cks.encrypt_radix(clear_0, NB_CTXT);
+        let mut expected = clear_0;
+        let mut num_adds = 0;
+
+        loop {
+            let Ok(ct) = sks.checked_add(&ct_res, &ct_1) else {
+                // The assign version must refuse the add as well, without modifying the input
+                let mut ct_copy = ct_res.clone();
+                assert!(sks.checked_add_assign(&mut ct_copy, &ct_1).is_err());
+                assert_eq!(ct_copy, ct_res);
+                break;
+            };
+            assert_eq!(ct, sks.unchecked_add(&ct_res, &ct_1));
+            ct_res = ct;
+            expected = (expected + clear_1) % modulus;
+            num_adds += 1;
+        }
+        // The carries allow at least one add of fresh ciphertexts
+        assert!(num_adds >= 1);
+
+        // The last successful state still decrypts correctly
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec, expected);
+    }
+}
+
 fn integer_unchecked_bitand<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -759,6 +802,28 @@ fn integer_create_trivial_min_max(param: impl Into<PBSParameters>) {
     }
 }
 
+fn integer_create_trivial_radix_add(param: ClassicPBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32);
+
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct = cks.encrypt_radix(clear_0, NB_CTXT);
+        let trivial: RadixCiphertext = sks.create_trivial_radix(clear_1, NB_CTXT);
+        assert_eq!(trivial.decrypt_trivial::<u64>().unwrap(), clear_1);
+
+        let ct_res = sks.add_parallelized(&ct, &trivial);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+
+        assert_eq!((clear_0 + clear_1) % modulus, dec);
+    }
+}
+
 fn integer_signed_decryption_correctly_sign_extend(param: impl Into<PBSParameters>) {
     // Test that when decrypting a negative SignedRadixCiphertext of N bits to a
     // clear type of M bits where M > N, the sign extension is correctly done
@@ -788,6 +853,103 @@ fn integer_scalar_blockslice_assign(param: ClassicPBSParameters) {
     scalar_blockslice_assign_test(param, executor);
 }
 
+fn integer_noise_level_introspection(param: ClassicPBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus.0.pow(NB_CTXT as u32);
+    let max_noise_level = param.max_noise_level;
+
+    let clear = rng.gen::<u64>() % modulus;
+    let mut ct = cks.encrypt_radix(clear, NB_CTXT);
+    assert_eq!(ct.max_noise_level(), NoiseLevel::NOMINAL);
+    assert_eq!(ct.blocks_noise_levels(), vec![NoiseLevel::NOMINAL; NB_CTXT]);
+    assert!(!ct.needs_propagation(max_noise_level));
+
+    let ct2 = cks.encrypt_radix(0u64, NB_CTXT);
+    let mut previous_noise_level = ct.max_noise_level();
+    while !ct.needs_propagation(max_noise_level) {
+        // Adding an encryption of zero keeps the value, while the noise grows
+        sks.unchecked_add_assign(&mut ct, &ct2);
+
+        let noise_level = ct.max_noise_level();
+        assert!(noise_level > previous_noise_level);
+        assert!(ct
+            .blocks_noise_levels()
+            .iter()
+            .all(|level| *level == noise_level));
+        previous_noise_level = noise_level;
+    }
+    assert_eq!(ct.max_noise_level().get(), max_noise_level.get());
+
+    sks.full_propagate(&mut ct);
+    assert_eq!(ct.blocks_noise_levels(), vec![NoiseLevel::NOMINAL; NB_CTXT]);
+    assert!(!ct.needs_propagation(max_noise_level));
+
+    let dec: u64 = cks.decrypt_radix(&ct);
+    assert_eq!(dec, clear);
+}
+
+fn integer_concat_slice_radix_blocks_128_bits(param: ClassicPBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+    let num_block = (128f64 / (param.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+    let bits_in_block = param.message_modulus.0.ilog2() as usize;
+    // For most parameters this splits the 128 bits into two 64 bits halves
+    let half = num_block / 2;
+    let low_bits = half * bits_in_block;
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear = rng.gen::<u128>();
+
+        let ct = cks.encrypt_radix(clear, num_block);
+
+        let ct_low = sks.slice_radix_blocks(&ct, ..half).unwrap();
+        let ct_high = sks.slice_radix_blocks(&ct, half..).unwrap();
+        assert_eq!(ct_low.blocks.len(), half);
+        assert_eq!(ct_high.blocks.len(), num_block - half);
+
+        let dec_low: u128 = cks.decrypt_radix(&ct_low);
+        let dec_high: u128 = cks.decrypt_radix(&ct_high);
+        assert_eq!(dec_low, clear % (1u128 << low_bits));
+        assert_eq!(dec_high, clear >> low_bits);
+
+        let ct_res = sks.concat_radix_blocks(&[ct_low, ct_high]);
+        assert_eq!(ct_res.blocks.len(), num_block);
+
+        let dec: u128 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec, clear);
+    }
+
+    // Carries held by dropped low blocks must be propagated before slicing or concatenating
+    {
+        let clear_0 = u64::MAX as u128;
+        let clear_1 = 1u128;
+
+        let mut ct = cks.encrypt_radix(clear_0, num_block);
+        let ct2 = cks.encrypt_radix(clear_1, num_block);
+        sks.unchecked_add_assign(&mut ct, &ct2);
+        assert!(!ct.block_carries_are_empty());
+
+        let ct_high = sks.slice_radix_blocks(&ct, half..).unwrap();
+        assert!(ct_high.block_carries_are_empty());
+        let dec_high: u128 = cks.decrypt_radix(&ct_high);
+        assert_eq!(dec_high, (clear_0 + clear_1) >> low_bits);
+
+        let ct_res = sks.concat_radix_blocks(&[ct, ct2]);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res.blocks.len(), 2 * num_block);
+
+        let dec: U256 = cks.decrypt_radix(&ct_res);
+        let expected_high = clear_1 << (num_block * bits_in_block - 128);
+        assert_eq!(dec, U256::from((clear_0 + clear_1, expected_high)));
+    }
+
+    let ct = cks.encrypt_radix(0u64, num_block);
+    assert!(sks.slice_radix_blocks(&ct, 0..num_block + 1).is_err());
+}
+
 fn integer_unchecked_scalar_slice(param: ClassicPBSParameters) {
     let executor = CpuFunctionExecutor::new(&ServerKey::unchecked_scalar_bitslice);
     unchecked_scalar_bitslice_test(param, executor);
@@ -1,5 +1,5 @@
 use crate::integer::ciphertext::IntegerRadixCiphertext;
-use crate::integer::ServerKey;
+use crate::integer::{ServerKey, SignedRadixCiphertext};
 
 impl ServerKey {
     pub fn unchecked_abs_parallelized<T>(&self, ct: &T) -> T
@@ -45,4 +45,131 @@ impl ServerKey {
             self.unchecked_abs_parallelized(&cloned)
         }
     }
+
+    pub fn unchecked_signum_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = ct.blocks().len();
+        if T::IS_SIGNED {
+            // The arithmetic shift spreads the sign bit, giving -1 for negative values
+            // and 0 otherwise, or-ing it with (ct != 0) gives -1, 0 or 1
+            let num_bits_in_ciphertext = self.key.message_modulus.0.ilog2() * num_blocks as u32;
+            let (mask, is_non_zero) = rayon::join(
+                || {
+                    self.unchecked_scalar_right_shift_arithmetic_parallelized(
+                        ct,
+                        num_bits_in_ciphertext - 1,
+                    )
+                },
+                || self.unchecked_scalar_ne_parallelized(ct, 0u64),
+            );
+            let is_non_zero = is_non_zero.into_radix(num_blocks, self);
+            self.unchecked_bitor_parallelized(&mask, &is_non_zero)
+        } else {
+            self.unchecked_scalar_ne_parallelized(ct, 0u64)
+                .into_radix(num_blocks, self)
+        }
+    }
+
+    pub fn smart_signum_parallelized<T>(&self, ct: &mut T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_signum_parallelized(ct)
+    }
+
+    /// Returns the sign of the ciphertext
+    ///
+    /// - `-1` if the value is negative
+    /// - `0` if the value is zero
+    /// - `1` if the value is positive
+    ///
+    /// For unsigned ciphertexts, the result is either 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// for msg in [-7i8, 0, 12] {
+    ///     let ct = cks.encrypt_signed(msg);
+    ///     let ct_res = sks.signum_parallelized(&ct);
+    ///     let dec: i8 = cks.decrypt_signed(&ct_res);
+    ///     assert_eq!(dec, msg.signum());
+    /// }
+    /// ```
+    pub fn signum_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if ct.block_carries_are_empty() {
+            self.unchecked_signum_parallelized(ct)
+        } else {
+            let mut cloned = ct.clone();
+            self.full_propagate_parallelized(&mut cloned);
+            self.unchecked_signum_parallelized(&cloned)
+        }
+    }
+
+    pub fn unchecked_relu_parallelized(&self, ct: &SignedRadixCiphertext) -> SignedRadixCiphertext {
+        let mut result = ct.clone();
+        let Some(last_block) = ct.blocks.last() else {
+            return result;
+        };
+
+        // Only the sign bit of the last block is needed, so a single PBS
+        // gives the condition used to zero out all the blocks
+        let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
+        let lut = self.key.generate_lookup_table(|x| (x >> sign_bit_pos) & 1);
+        let is_negative = self.key.apply_lookup_table(last_block, &lut);
+
+        self.zero_out_if(&mut result, &is_negative, |x| x == 1);
+        result
+    }
+
+    pub fn smart_relu_parallelized(&self, ct: &mut SignedRadixCiphertext) -> SignedRadixCiphertext {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_relu_parallelized(ct)
+    }
+
+    /// Computes homomorphically the ReLU of the ciphertext, i.e. `max(ct, 0)`
+    ///
+    /// This only looks at the sign bit, which makes it cheaper than a comparison
+    /// followed by a select.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// for msg in [-7i8, 0, 12] {
+    ///     let ct = cks.encrypt_signed(msg);
+    ///     let ct_res = sks.relu_parallelized(&ct);
+    ///     let dec: i8 = cks.decrypt_signed(&ct_res);
+    ///     assert_eq!(dec, msg.max(0));
+    /// }
+    /// ```
+    pub fn relu_parallelized(&self, ct: &SignedRadixCiphertext) -> SignedRadixCiphertext {
+        if ct.block_carries_are_empty() {
+            self.unchecked_relu_parallelized(ct)
+        } else {
+            let mut cloned = ct.clone();
+            self.full_propagate_parallelized(&mut cloned);
+            self.unchecked_relu_parallelized(&cloned)
+        }
+    }
 }
@@ -123,6 +123,12 @@ impl ServerKey {
 
     /// Computes homomorphically an addition between two ciphertexts encrypting integer values.
     ///
+    /// Carries are propagated whenever the addition would exceed the capacity of the blocks.
+    /// When several additions are chained and the carry budget is known, additions can be
+    /// batched with [Self::checked_add_assign] (which never propagates), checking
+    /// [IntegerRadixCiphertext::block_carries_are_empty] and calling
+    /// [Self::full_propagate_parallelized] only once at the end.
+    ///
     /// # Warning
     ///
     /// - Multithreaded
@@ -379,6 +385,82 @@ impl ServerKey {
         self.overflowing_add_assign_parallelized(ct_left, ct_right)
     }
 
+    /// Adds `amount` to `acc` if `condition` is true, saturating the result to `cap`
+    ///
+    /// - If `condition` is true, `acc` becomes `min(acc + amount, cap)`, where the sum is computed
+    ///   without wrapping around.
+    /// - If `condition` is false, `acc` is left unchanged (even if it is greater than `cap`).
+    ///
+    /// If `cap` cannot be represented with the number of blocks of `acc`,
+    /// the result saturates to the maximum value `acc` can hold.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let cap = 100u64;
+    ///
+    /// let mut acc = cks.encrypt(80u64);
+    /// let amount = cks.encrypt(50u64);
+    ///
+    /// let condition = cks.encrypt_bool(true);
+    /// sks.saturating_accumulate_if_parallelized(&mut acc, &amount, &condition, cap);
+    /// let dec: u64 = cks.decrypt(&acc);
+    /// assert_eq!(dec, cap);
+    ///
+    /// let condition = cks.encrypt_bool(false);
+    /// sks.saturating_accumulate_if_parallelized(&mut acc, &amount, &condition, cap);
+    /// let dec: u64 = cks.decrypt(&acc);
+    /// assert_eq!(dec, cap);
+    /// ```
+    pub fn saturating_accumulate_if_parallelized(
+        &self,
+        acc: &mut RadixCiphertext,
+        amount: &RadixCiphertext,
+        condition: &BooleanBlock,
+        cap: u64,
+    ) {
+        let num_blocks = acc.blocks.len();
+        let num_bits = num_blocks as u32 * self.message_modulus().0.ilog2();
+        let cap = if num_bits < u64::BITS {
+            cap.min((1u64 << num_bits) - 1)
+        } else {
+            cap
+        };
+
+        let mut tmp_amount = amount.clone();
+        rayon::join(
+            || {
+                if !acc.block_carries_are_empty() {
+                    self.full_propagate_parallelized(acc);
+                }
+            },
+            || {
+                if !tmp_amount.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut tmp_amount);
+                }
+            },
+        );
+
+        // Only add the amount when the condition holds
+        self.zero_out_if_condition_is_false(&mut tmp_amount, condition.as_ref());
+
+        let (sum, overflowed) = self.unsigned_overflowing_add_parallelized(acc, &tmp_amount);
+        let is_above_cap = self.scalar_gt_parallelized(&sum, cap);
+        let mut must_saturate = self.boolean_bitor(&overflowed, &is_above_cap);
+        // If the condition is false, acc is kept as is even if it was above the cap
+        self.boolean_bitand_assign(&mut must_saturate, condition);
+
+        let cap = self.create_trivial_radix(cap, num_blocks);
+        *acc = self.unchecked_if_then_else_parallelized(&must_saturate, &cap, &sum);
+    }
+
     pub fn signed_overflowing_add_parallelized(
         &self,
         ct_left: &SignedRadixCiphertext,
@@ -387,6 +469,85 @@ impl ServerKey {
         self.overflowing_add_parallelized(ct_left, ct_right)
     }
 
+    /// Computes the addition of two unsigned ciphertexts, saturating at the max value
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg1 = 200u8;
+    /// let msg2 = 100u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unsigned_saturating_add_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_add(msg2));
+    /// ```
+    pub fn unsigned_saturating_add_parallelized(
+        &self,
+        ct_left: &RadixCiphertext,
+        ct_right: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let (result, overflowed) = self.unsigned_overflowing_add_parallelized(ct_left, ct_right);
+        let max: RadixCiphertext = self.create_trivial_max_radix(result.blocks.len());
+        self.unchecked_if_then_else_parallelized(&overflowed, &max, &result)
+    }
+
+    /// Computes the addition of two signed ciphertexts, saturating at the min or max value
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg1 = -100i8;
+    /// let msg2 = -50i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_saturating_add_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_add(msg2));
+    /// ```
+    pub fn signed_saturating_add_parallelized(
+        &self,
+        ct_left: &SignedRadixCiphertext,
+        ct_right: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let ((result, overflowed), rhs_is_negative) = rayon::join(
+            || self.signed_overflowing_add_parallelized(ct_left, ct_right),
+            || self.scalar_lt_parallelized(ct_right, 0i64),
+        );
+
+        // The addition can only overflow if both operands have the same sign,
+        // so the sign of rhs tells in which direction it overflowed
+        let num_blocks = result.blocks.len();
+        let (min, max): (SignedRadixCiphertext, SignedRadixCiphertext) = (
+            self.create_trivial_min_radix(num_blocks),
+            self.create_trivial_max_radix(num_blocks),
+        );
+        let bound = self.unchecked_if_then_else_parallelized(&rhs_is_negative, &min, &max);
+        self.unchecked_if_then_else_parallelized(&overflowed, &bound, &result)
+    }
+
     pub fn unchecked_signed_overflowing_add_parallelized(
         &self,
         ct_left: &SignedRadixCiphertext,
@@ -1,5 +1,5 @@
 use crate::integer::ciphertext::IntegerRadixCiphertext;
-use crate::integer::ServerKey;
+use crate::integer::{BooleanBlock, ServerKey};
 use rayon::prelude::*;
 
 impl ServerKey {
@@ -555,4 +555,126 @@ impl ServerKey {
             .iter_mut()
             .for_each(|block| self.key.bitnot_assign(block));
     }
+
+    /// Returns the bit at position `index` of the ciphertext, as a [BooleanBlock]
+    ///
+    /// Index 0 is the least significant bit. The block holding the bit is shifted
+    /// and masked with a single PBS.
+    ///
+    /// If `index` is not smaller than the number of bits of the ciphertext,
+    /// a trivial encryption of `false` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg = 0b1010_0110u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let bit = sks.get_bit_parallelized(&ct, 5);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_bool(&bit);
+    /// assert_eq!(dec_result, (msg >> 5) & 1 == 1);
+    /// ```
+    pub fn get_bit_parallelized<T>(&self, ct: &T, index: u32) -> BooleanBlock
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let bits_in_block = self.message_modulus().0.ilog2();
+        let block_index = (index / bits_in_block) as usize;
+        if block_index >= ct.blocks().len() {
+            return self.create_trivial_boolean_block(false);
+        }
+
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        let shift = index % bits_in_block;
+        let lut = self.key.generate_lookup_table(|x| (x >> shift) & 1);
+        let block = self.key.apply_lookup_table(&ct.blocks()[block_index], &lut);
+        BooleanBlock::new_unchecked(block)
+    }
+
+    /// Sets the bit at position `index` of the ciphertext to the encrypted `value`
+    ///
+    /// Index 0 is the least significant bit. The block holding the bit is updated
+    /// with a single bivariate PBS which acts as a CMUX between the block with the bit
+    /// cleared and the block with the bit set.
+    ///
+    /// If `index` is not smaller than the number of bits of the ciphertext,
+    /// the ciphertext is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg = 0b1010_0110u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let value = cks.encrypt_bool(true);
+    ///
+    /// let ct_res = sks.set_bit_parallelized(&ct, 3, &value);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg | (1 << 3));
+    /// ```
+    pub fn set_bit_parallelized<T>(&self, ct: &T, index: u32, value: &BooleanBlock) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut ct_res = ct.clone();
+        self.set_bit_assign_parallelized(&mut ct_res, index, value);
+        ct_res
+    }
+
+    pub fn set_bit_assign_parallelized<T>(&self, ct: &mut T, index: u32, value: &BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let bits_in_block = self.message_modulus().0.ilog2();
+        let block_index = (index / bits_in_block) as usize;
+        if block_index >= ct.blocks().len() {
+            return;
+        }
+
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        let shift = index % bits_in_block;
+        let mask = 1u64 << shift;
+        let lut = self.key.generate_lookup_table_bivariate(|block, bit| {
+            if bit == 0 {
+                block & !mask
+            } else {
+                block | mask
+            }
+        });
+        self.key.unchecked_apply_lookup_table_bivariate_assign(
+            &mut ct.blocks_mut()[block_index],
+            value.as_ref(),
+            &lut,
+        );
+    }
 }
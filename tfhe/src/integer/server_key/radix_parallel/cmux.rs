@@ -49,7 +49,18 @@ where
     /// (e.g result from a comparison).
     ///
     /// Note that while the returned ciphertext encrypts the same value as
-    /// either true_ct or false_ct, it won't exactly be true_ct or false_ct.
+    /// either true_ct or false_ct, it won't exactly be true_ct or false_ct,
+    /// unless the condition is trivial: the selected input is then returned as is
+    /// (with its carries propagated if needed).
+    ///
+    /// Results of comparisons already have a clean carry and a degree of at most 1,
+    /// so they are used as is, without any refresh. If the condition is trivial
+    /// (e.g. a scalar comparison whose result is known from the clear value),
+    /// the selection is done without any PBS on the condition.
+    ///
+    /// With clean inputs and an encrypted condition, the selection costs 3 PBS per block:
+    /// one bivariate PBS per block of each input to zero out the non-selected one,
+    /// and one PBS per block to clean the sum of the two.
     ///
     /// ```rust
     /// use tfhe::integer::gen_keys_radix;
@@ -82,6 +93,18 @@ where
         true_ct: &T,
         false_ct: &T,
     ) -> Self::Output {
+        if let Ok(condition) = condition.decrypt_trivial() {
+            let mut result = if condition {
+                true_ct.clone()
+            } else {
+                false_ct.clone()
+            };
+            if !result.block_carries_are_empty() {
+                self.full_propagate_parallelized(&mut result);
+            }
+            return result;
+        }
+
         let mut ct_clones = [None, None];
         let mut ct_refs = [true_ct, false_ct];
 
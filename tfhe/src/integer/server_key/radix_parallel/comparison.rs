@@ -3,6 +3,7 @@ use crate::core_crypto::prelude::UnsignedInteger;
 use crate::integer::ciphertext::boolean_value::BooleanBlock;
 use crate::integer::ciphertext::IntegerRadixCiphertext;
 use crate::integer::prelude::ServerKeyDefaultCMux;
+use crate::integer::RadixCiphertext;
 use crate::shortint::{Ciphertext, MessageModulus};
 use rayon::prelude::*;
 
@@ -1060,4 +1061,139 @@ impl ServerKey {
 
         self.unchecked_min_parallelized(lhs, rhs)
     }
+
+    /// Orders two values in place, so that `lhs` holds the min and `rhs` the max
+    ///
+    /// Returns a boolean block encrypting whether the values were swapped, that is,
+    /// whether `lhs > rhs` before the call. Equal values are never swapped.
+    ///
+    /// Expects both inputs to have clean carries
+    ///
+    /// See [Self::compare_and_swap_parallelized] for an example
+    pub fn unchecked_compare_and_swap_parallelized<T>(
+        &self,
+        lhs: &mut T,
+        rhs: &mut T,
+    ) -> BooleanBlock
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let swapped = self.unchecked_gt_parallelized(lhs, rhs);
+
+        // Both selections share the same comparison
+        let (min, max) = rayon::join(
+            || self.unchecked_if_then_else_parallelized(&swapped, rhs, lhs),
+            || self.unchecked_if_then_else_parallelized(&swapped, lhs, rhs),
+        );
+        *lhs = min;
+        *rhs = max;
+
+        swapped
+    }
+
+    /// Orders two values in place, so that `lhs` holds the min and `rhs` the max
+    ///
+    /// Returns a boolean block encrypting whether the values were swapped, that is,
+    /// whether `lhs > rhs` before the call. Equal values are never swapped,
+    /// which allows building stable sorting networks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 200u8;
+    /// let b = 17u8;
+    ///
+    /// let mut ct_a = cks.encrypt(a);
+    /// let mut ct_b = cks.encrypt(b);
+    ///
+    /// let swapped = sks.compare_and_swap_parallelized(&mut ct_a, &mut ct_b);
+    ///
+    /// let min: u8 = cks.decrypt(&ct_a);
+    /// let max: u8 = cks.decrypt(&ct_b);
+    /// assert_eq!(min, b);
+    /// assert_eq!(max, a);
+    /// assert!(cks.decrypt_bool(&swapped));
+    /// ```
+    pub fn compare_and_swap_parallelized<T>(&self, lhs: &mut T, rhs: &mut T) -> BooleanBlock
+    where
+        T: IntegerRadixCiphertext,
+    {
+        rayon::join(
+            || {
+                if !lhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(lhs);
+                }
+            },
+            || {
+                if !rhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(rhs);
+                }
+            },
+        );
+
+        self.unchecked_compare_and_swap_parallelized(lhs, rhs)
+    }
+
+    /// Compares homomorphically two values that are each split into a high and a low part
+    ///
+    /// Returns a boolean block encrypting whether `(a_hi, a_lo) < (b_hi, b_lo)`,
+    /// where the pairs are compared lexicographically, i.e. as the values
+    /// `a_hi * 2^k + a_lo` and `b_hi * 2^k + b_lo` with `k` the number of bits of the low parts.
+    ///
+    /// This avoids having to concatenate the parts into a wider ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a_hi` and `b_hi` (or `a_lo` and `b_lo`) do not have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Each part is 4 * 2 = 8 bits
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 0x12_F0u16;
+    /// let b = 0x13_01u16;
+    ///
+    /// let a_hi = cks.encrypt(u64::from(a >> 8));
+    /// let a_lo = cks.encrypt(u64::from(a & 0xFF));
+    /// let b_hi = cks.encrypt(u64::from(b >> 8));
+    /// let b_lo = cks.encrypt(u64::from(b & 0xFF));
+    ///
+    /// let ct_res = sks.compare_split_parallelized(&a_hi, &a_lo, &b_hi, &b_lo);
+    ///
+    /// let dec = cks.decrypt_bool(&ct_res);
+    /// assert_eq!(dec, a < b);
+    /// ```
+    pub fn compare_split_parallelized(
+        &self,
+        a_hi: &RadixCiphertext,
+        a_lo: &RadixCiphertext,
+        b_hi: &RadixCiphertext,
+        b_lo: &RadixCiphertext,
+    ) -> BooleanBlock {
+        let ((hi_is_lt, hi_is_eq), lo_is_lt) = rayon::join(
+            || {
+                rayon::join(
+                    || self.lt_parallelized(a_hi, b_hi),
+                    || self.eq_parallelized(a_hi, b_hi),
+                )
+            },
+            || self.lt_parallelized(a_lo, b_lo),
+        );
+
+        // The low parts only decide when the high parts are equal
+        let lo_decides = self.boolean_bitand(&hi_is_eq, &lo_is_lt);
+        self.boolean_bitor(&hi_is_lt, &lo_decides)
+    }
 }
@@ -90,6 +90,99 @@ impl ServerKey {
         (quotient, remainder)
     }
 
+    /// Computes the euclidean quotient and remainder of the division
+    ///
+    /// The results match [i64::div_euclid] and [i64::rem_euclid]:
+    /// the remainder is always positive, i.e. `0 <= r < |d|`.
+    ///
+    /// Inputs must have empty carries, see [Self::div_rem_euclid_parallelized]
+    pub fn unchecked_div_rem_euclid_parallelized(
+        &self,
+        numerator: &SignedRadixCiphertext,
+        divisor: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, SignedRadixCiphertext) {
+        let (quotient, remainder) = self.unchecked_div_rem_parallelized(numerator, divisor);
+
+        // The truncating remainder has the sign of the numerator, when it is negative:
+        // - if the divisor is positive: q_e = q - 1 and r_e = r + d
+        // - if the divisor is negative: q_e = q + 1 and r_e = r - d
+        let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
+        let (remainder_is_negative, remainder_and_divisor_are_negative) = rayon::join(
+            || {
+                let lut = self.key.generate_lookup_table(|x| (x >> sign_bit_pos) & 1);
+                self.key
+                    .apply_lookup_table(remainder.blocks().last().unwrap(), &lut)
+            },
+            || {
+                let both_sign_bits_set = |x, y| {
+                    let x_sign_bit = (x >> sign_bit_pos) & 1;
+                    let y_sign_bit = (y >> sign_bit_pos) & 1;
+                    x_sign_bit & y_sign_bit
+                };
+                let lut = self.key.generate_lookup_table_bivariate(both_sign_bits_set);
+                self.key.unchecked_apply_lookup_table_bivariate(
+                    remainder.blocks().last().unwrap(),
+                    divisor.blocks().last().unwrap(),
+                    &lut,
+                )
+            },
+        );
+
+        // condition is 0 when r >= 0, 1 when r < 0 and d > 0, 2 when r < 0 and d < 0
+        let mut condition = remainder_is_negative;
+        let mut remainder_plus_divisor = remainder.clone();
+        let mut remainder_minus_divisor = remainder.clone();
+        let mut quotient_minus_one = quotient.clone();
+        let mut quotient_plus_one = quotient.clone();
+        rayon::scope(|s| {
+            s.spawn(|_| {
+                self.key
+                    .add_assign(&mut condition, &remainder_and_divisor_are_negative);
+            });
+            s.spawn(|_| self.add_assign_parallelized(&mut remainder_plus_divisor, divisor));
+            s.spawn(|_| self.sub_assign_parallelized(&mut remainder_minus_divisor, divisor));
+            s.spawn(|_| self.scalar_sub_assign_parallelized(&mut quotient_minus_one, 1));
+            s.spawn(|_| self.scalar_add_assign_parallelized(&mut quotient_plus_one, 1));
+        });
+
+        let (quotient, remainder) = rayon::join(
+            || {
+                let quotient = self.unchecked_programmable_if_then_else_parallelized(
+                    &condition,
+                    &quotient_minus_one,
+                    &quotient,
+                    |x| x == 1,
+                    true,
+                );
+                self.unchecked_programmable_if_then_else_parallelized(
+                    &condition,
+                    &quotient_plus_one,
+                    &quotient,
+                    |x| x == 2,
+                    true,
+                )
+            },
+            || {
+                let remainder = self.unchecked_programmable_if_then_else_parallelized(
+                    &condition,
+                    &remainder_plus_divisor,
+                    &remainder,
+                    |x| x == 1,
+                    true,
+                );
+                self.unchecked_programmable_if_then_else_parallelized(
+                    &condition,
+                    &remainder_minus_divisor,
+                    &remainder,
+                    |x| x == 2,
+                    true,
+                )
+            },
+        );
+
+        (quotient, remainder)
+    }
+
     fn unsigned_unchecked_div_rem_parallelized(
         &self,
         numerator: &RadixCiphertext,
@@ -642,6 +735,74 @@ impl ServerKey {
         self.unchecked_div_rem_parallelized(numerator, divisor)
     }
 
+    /// Computes homomorphically the euclidean quotient and remainder of the division
+    /// between two signed ciphertexts
+    ///
+    /// The results match [i64::div_euclid] and [i64::rem_euclid]:
+    /// the remainder is always positive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg1 = -97i8;
+    /// let msg2 = 14i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// // Compute homomorphically the euclidean quotient and remainder:
+    /// let (q_res, r_res) = sks.div_rem_euclid_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let q: i8 = cks.decrypt_signed(&q_res);
+    /// let r: i8 = cks.decrypt_signed(&r_res);
+    /// assert_eq!(q, msg1.div_euclid(msg2));
+    /// assert_eq!(r, msg1.rem_euclid(msg2));
+    /// ```
+    pub fn div_rem_euclid_parallelized(
+        &self,
+        numerator: &SignedRadixCiphertext,
+        divisor: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, SignedRadixCiphertext) {
+        let mut tmp_numerator;
+        let mut tmp_divisor;
+
+        let (numerator, divisor) = match (
+            numerator.block_carries_are_empty(),
+            divisor.block_carries_are_empty(),
+        ) {
+            (true, true) => (numerator, divisor),
+            (true, false) => {
+                tmp_divisor = divisor.clone();
+                self.full_propagate_parallelized(&mut tmp_divisor);
+                (numerator, &tmp_divisor)
+            }
+            (false, true) => {
+                tmp_numerator = numerator.clone();
+                self.full_propagate_parallelized(&mut tmp_numerator);
+                (&tmp_numerator, divisor)
+            }
+            (false, false) => {
+                tmp_divisor = divisor.clone();
+                tmp_numerator = numerator.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_numerator),
+                    || self.full_propagate_parallelized(&mut tmp_divisor),
+                );
+                (&tmp_numerator, &tmp_divisor)
+            }
+        };
+
+        self.unchecked_div_rem_euclid_parallelized(numerator, divisor)
+    }
+
     pub fn smart_div_rem_parallelized<T>(&self, numerator: &mut T, divisor: &mut T) -> (T, T)
     where
         T: IntegerRadixCiphertext,
@@ -0,0 +1,73 @@
+use crate::integer::{RadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes homomorphically the dot product of two slices of unsigned ciphertexts
+    ///
+    /// Each pair of elements is first casted to `output_num_blocks` blocks, then multiplied,
+    /// and the products are summed. Choosing `output_num_blocks` large enough
+    /// (e.g. twice the input number of blocks plus the blocks needed to hold the number of terms)
+    /// avoids overflows, otherwise the result wraps around.
+    ///
+    /// The multiplications are done in parallel, and the products are reduced
+    /// using [Self::sum_ciphertexts_parallelized].
+    ///
+    /// Empty slices return a trivial encryption of 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lhs` and `rhs` do not have the same length
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let lhs = [200u64, 3, 17];
+    /// let rhs = [100u64, 255, 4];
+    ///
+    /// let ct_lhs = lhs.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    /// let ct_rhs = rhs.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    ///
+    /// // The result is computed on 16 bits
+    /// let ct_res = sks.dot_product_parallelized(&ct_lhs, &ct_rhs, 2 * size);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// let expected: u64 = lhs.iter().zip(rhs.iter()).map(|(l, r)| l * r).sum();
+    /// assert_eq!(dec, expected);
+    /// ```
+    pub fn dot_product_parallelized(
+        &self,
+        lhs: &[RadixCiphertext],
+        rhs: &[RadixCiphertext],
+        output_num_blocks: usize,
+    ) -> RadixCiphertext {
+        assert_eq!(
+            lhs.len(),
+            rhs.len(),
+            "Cannot compute the dot product of slices with different lengths ({} and {})",
+            lhs.len(),
+            rhs.len()
+        );
+
+        let products = lhs
+            .par_iter()
+            .zip(rhs.par_iter())
+            .map(|(l, r)| {
+                let (l, r) = rayon::join(
+                    || self.cast_to_unsigned(l.clone(), output_num_blocks),
+                    || self.cast_to_unsigned(r.clone(), output_num_blocks),
+                );
+                self.mul_parallelized(&l, &r)
+            })
+            .collect::<Vec<_>>();
+
+        self.sum_ciphertexts_parallelized(&products)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(output_num_blocks))
+    }
+}
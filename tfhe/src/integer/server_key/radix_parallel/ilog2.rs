@@ -339,6 +339,23 @@ impl ServerKey {
         self.cast_to_unsigned(result, counter_num_blocks)
     }
 
+    /// Returns the power-of-two bucket of the number, that is, its base 2 logarithm
+    /// rounded down, with an input of zero mapped to bucket 0.
+    ///
+    /// See [Self::log_bucket_parallelized] for an example
+    ///
+    /// Expects ct to have clean carries
+    pub fn unchecked_log_bucket_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let (mut result, is_non_zero) = rayon::join(
+            || self.unchecked_ilog2_parallelized(ct),
+            || self.unchecked_scalar_ne_parallelized(ct, 0),
+        );
+        // ilog2 of 0 wraps to the max value of the counter,
+        // saturate it to the first bucket instead
+        self.zero_out_if_condition_is_false(&mut result, &is_non_zero.0);
+        result
+    }
+
     //==============================================================================================
     //  Smart
     //==============================================================================================
@@ -688,4 +705,46 @@ impl ServerKey {
             || self.scalar_gt_parallelized(ct, 0),
         )
     }
+
+    /// Returns the power-of-two bucket of the number, that is, its base 2 logarithm
+    /// rounded down, with an input of zero mapped to bucket 0.
+    ///
+    /// Values in `[2^k, 2^(k+1))` land in bucket `k`, both 0 and 1 land in bucket 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 37u8;
+    ///
+    /// let ct1 = cks.encrypt(msg);
+    /// let ct2 = cks.encrypt(0u8);
+    ///
+    /// let b1 = sks.log_bucket_parallelized(&ct1);
+    /// let b2 = sks.log_bucket_parallelized(&ct2);
+    ///
+    /// // Decrypt:
+    /// let b1: u32 = cks.decrypt(&b1);
+    /// let b2: u32 = cks.decrypt(&b2);
+    /// assert_eq!(b1, msg.ilog2());
+    /// assert_eq!(b2, 0);
+    /// ```
+    pub fn log_bucket_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.clone();
+            self.full_propagate_parallelized(&mut tmp);
+            &tmp
+        };
+
+        self.unchecked_log_bucket_parallelized(ct)
+    }
 }
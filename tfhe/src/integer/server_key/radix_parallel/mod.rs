@@ -6,9 +6,14 @@ mod block_shift;
 pub(crate) mod cmux;
 mod comparison;
 mod div_mod;
+mod dot_product;
+mod modular;
 mod modulus_switch_compression;
 mod mul;
 mod neg;
+mod pow;
+mod reduce;
+mod rerandomize;
 mod rotate;
 mod scalar_add;
 mod scalar_bitwise_op;
@@ -246,4 +251,99 @@ impl ServerKey {
             }
         })
     }
+
+    /// Runs `f` inside the given thread `pool`
+    ///
+    /// All the parallelized operations (carry propagation, reductions, etc.) called from `f`
+    /// execute their parallel work in `pool` instead of rayon's global thread pool.
+    /// This allows to bound the number of threads used by a computation.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let pool = rayon::ThreadPoolBuilder::new()
+    ///     .num_threads(2)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let msg = 10u64;
+    /// let ct1 = cks.encrypt(msg);
+    /// let ct2 = cks.encrypt(msg);
+    ///
+    /// // At most 2 threads are used to compute the addition
+    /// let ct_res = sks.run_in_pool(&pool, |sks| sks.add_parallelized(&ct1, &ct2));
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg + msg, res);
+    /// ```
+    pub fn run_in_pool<R, F>(&self, pool: &rayon::ThreadPool, f: F) -> R
+    where
+        F: FnOnce(&Self) -> R + Send,
+        R: Send,
+    {
+        pool.install(|| f(self))
+    }
+
+    /// Applies `f` to each block of the ciphertext in parallel
+    ///
+    /// The blocks returned by `f` are assembled, in the same order, into a ciphertext of
+    /// the same type as `ct`. Degree and noise level of the output blocks are the
+    /// ones set by `f`, which usually works with the shortint server key (see [Self::as_ref]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` returns a block which does not have the same LWE dimension as its input
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg = 0b01_10_00_11u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Increment each block independently, modulo the message modulus
+    /// let shortint_key: &tfhe::shortint::ServerKey = sks.as_ref();
+    /// let lut = shortint_key.generate_lookup_table(|x| (x + 1) % 4);
+    /// let ct_res =
+    ///     sks.map_blocks_parallelized(&ct, |block| shortint_key.apply_lookup_table(block, &lut));
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 0b10_11_01_00);
+    /// ```
+    pub fn map_blocks_parallelized<T, F>(&self, ct: &T, f: F) -> T
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(&Ciphertext) -> Ciphertext + Sync,
+    {
+        let blocks = ct
+            .blocks()
+            .par_iter()
+            .map(|block| {
+                let new_block = f(block);
+                assert_eq!(
+                    new_block.ct.lwe_size(),
+                    block.ct.lwe_size(),
+                    "The mapped block must have the same LWE dimension as its input"
+                );
+                new_block
+            })
+            .collect::<Vec<_>>();
+
+        T::from_blocks(blocks)
+    }
 }
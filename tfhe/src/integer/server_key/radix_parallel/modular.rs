@@ -0,0 +1,168 @@
+use crate::integer::prelude::ServerKeyDefaultCMux;
+use crate::integer::{RadixCiphertext, ServerKey};
+
+impl ServerKey {
+    /// Computes `(lhs * rhs) % modulus`
+    ///
+    /// - Expects both inputs to be < modulus.
+    /// - The multiplication is done on twice as many blocks as the inputs so that it cannot
+    ///   overflow.
+    fn mod_mul_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: u64,
+    ) -> RadixCiphertext {
+        let num_blocks = lhs.blocks.len();
+
+        let (lhs, rhs) = rayon::join(
+            || self.extend_radix_with_trivial_zero_blocks_msb(lhs, num_blocks),
+            || self.extend_radix_with_trivial_zero_blocks_msb(rhs, num_blocks),
+        );
+
+        let product = self.mul_parallelized(&lhs, &rhs);
+        let mut result = self.scalar_rem_parallelized(&product, modulus);
+        self.trim_radix_blocks_msb_assign(&mut result, num_blocks);
+        result
+    }
+
+    /// Computes homomorphically the modular inverse of a ciphertext modulo a clear prime
+    ///
+    /// The inverse is computed using Fermat's little theorem, i.e.
+    /// `ct^-1 = ct^(prime - 2) mod prime`.
+    ///
+    /// The input does not need to be reduced modulo `prime`,
+    /// the result is always in `[0, prime)`.
+    ///
+    /// # Notes
+    ///
+    /// - `prime` is not checked to be a prime number, if it is not the result is meaningless.
+    /// - If the input is a multiple of `prime` (e.g. zero), it has no inverse and the returned
+    ///   value will be zero.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `prime` is less than 2
+    /// - Panics if `prime - 1` cannot be represented with the number of blocks of the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let prime = 13u64;
+    /// let msg = 7u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.mod_inverse_parallelized(&ct, prime);
+    ///
+    /// let inverse: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!((msg * inverse) % prime, 1);
+    /// ```
+    pub fn mod_inverse_parallelized(&self, ct: &RadixCiphertext, prime: u64) -> RadixCiphertext {
+        assert!(prime >= 2, "prime must be at least 2, got {prime}");
+
+        let num_blocks = ct.blocks.len();
+        let num_bits = num_blocks as u32 * self.message_modulus().0.ilog2();
+        assert!(
+            num_bits >= u64::BITS || (prime - 1) < (1u64 << num_bits),
+            "prime ({prime}) does not fit in a ciphertext of {num_bits} bits"
+        );
+
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        let base = self.scalar_rem_parallelized(ct, prime);
+        if prime == 2 {
+            // 1 is its own inverse, 0 has no inverse and maps to 0
+            return base;
+        }
+
+        // Square-and-multiply, as exponent >= 1 its msb is set,
+        // so we can start from the base directly
+        let exponent = prime - 2;
+        let mut result = base.clone();
+        for i in (0..exponent.ilog2()).rev() {
+            result = self.mod_mul_parallelized(&result, &result, prime);
+            if (exponent >> i) & 1 == 1 {
+                result = self.mod_mul_parallelized(&result, &base, prime);
+            }
+        }
+
+        result
+    }
+
+    /// Increments homomorphically a ciphertext modulo a clear `modulus`
+    ///
+    /// The ciphertext is incremented by one, and `modulus` is subtracted if the incremented
+    /// value reached it, so that a ciphertext encrypting `modulus - 1` wraps to zero.
+    /// This makes it possible to implement cyclic counters.
+    ///
+    /// The input is expected to be in `[0, modulus)`, otherwise the result is meaningless.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `modulus` is 0
+    /// - Panics if `modulus` is greater than the number of values the ciphertext can represent
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let modulus = 10u64;
+    /// let mut ct = cks.encrypt(8u64);
+    ///
+    /// sks.increment_mod_parallelized(&mut ct, modulus);
+    /// let dec: u64 = cks.decrypt(&ct);
+    /// assert_eq!(dec, 9);
+    ///
+    /// sks.increment_mod_parallelized(&mut ct, modulus);
+    /// let dec: u64 = cks.decrypt(&ct);
+    /// assert_eq!(dec, 0);
+    /// ```
+    pub fn increment_mod_parallelized(&self, ct: &mut RadixCiphertext, modulus: u64) {
+        assert!(modulus >= 1, "modulus must be at least 1");
+
+        let num_blocks = ct.blocks.len();
+        let num_bits = num_blocks as u32 * self.message_modulus().0.ilog2();
+        let is_full_range = num_bits < u64::BITS && modulus == 1u64 << num_bits;
+        assert!(
+            num_bits >= u64::BITS || modulus <= 1u64 << num_bits,
+            "modulus ({modulus}) does not fit in a ciphertext of {num_bits} bits"
+        );
+
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        self.scalar_add_assign_parallelized(ct, 1u64);
+        if is_full_range {
+            // The addition already wrapped around
+            return;
+        }
+
+        let (has_reached_modulus, reduced) = rayon::join(
+            || self.scalar_ge_parallelized(ct, modulus),
+            || self.scalar_sub_parallelized(ct, modulus),
+        );
+        *ct = self.if_then_else_parallelized(&has_reached_modulus, &reduced, ct);
+    }
+}
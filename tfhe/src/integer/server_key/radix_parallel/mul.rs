@@ -3,7 +3,25 @@ use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCipher
 use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use rayon::prelude::*;
 
+/// Smallest number of blocks that can be split by the Karatsuba algorithm,
+/// below that the sums of the halves are not smaller than the operands
+const KARATSUBA_MIN_BLOCKS: usize = 4;
+
+/// Splits the blocks of `ct` in a low part of `mid` blocks and a high part
+fn split_radix_at(ct: &RadixCiphertext, mid: usize) -> (RadixCiphertext, RadixCiphertext) {
+    let (low, high) = ct.blocks.split_at(mid);
+    (
+        RadixCiphertext::from(low.to_vec()),
+        RadixCiphertext::from(high.to_vec()),
+    )
+}
+
 impl ServerKey {
+    /// Number of blocks from which multiplications use the Karatsuba algorithm
+    ///
+    /// See [Self::unchecked_karatsuba_mul_parallelized]
+    pub const KARATSUBA_MUL_THRESHOLD: usize = 64;
+
     /// Computes homomorphically a multiplication between a ciphertext encrypting an integer value
     /// and another encrypting a shortint value.
     ///
@@ -399,6 +417,293 @@ impl ServerKey {
         }
     }
 
+    /// Multiplies `lhs` by `rhs` by summing all the partial block products,
+    /// the result has the same number of blocks as the inputs.
+    ///
+    /// Expects both inputs to have clean carries.
+    fn schoolbook_mul_low<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let terms = self.compute_terms_for_mul_low(lhs, rhs);
+
+        self.unchecked_sum_ciphertexts_vec_parallelized(terms)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(lhs.blocks().len()))
+    }
+
+    /// Computes the full product of `lhs` and `rhs`,
+    /// the result has twice as many blocks as the inputs.
+    ///
+    /// Operands with at least `threshold` blocks are split in two halves and the product is
+    /// recombined from the three half products of the Karatsuba algorithm.
+    ///
+    /// Expects both inputs to have clean carries and the same number of blocks.
+    fn karatsuba_mul_full(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        threshold: usize,
+    ) -> RadixCiphertext {
+        let num_blocks = lhs.blocks.len();
+
+        if num_blocks < threshold.max(KARATSUBA_MIN_BLOCKS) {
+            // Blocks added by the extension are trivial zeros,
+            // which the partial products skip
+            let (lhs, rhs) = rayon::join(
+                || self.extend_radix_with_trivial_zero_blocks_msb(lhs, num_blocks),
+                || self.extend_radix_with_trivial_zero_blocks_msb(rhs, num_blocks),
+            );
+            return self.schoolbook_mul_low(&lhs, &rhs);
+        }
+
+        // lhs = a1 * B^half + a0, rhs = b1 * B^half + b0
+        // lhs * rhs = a1b1 * B^(2 * half) + (a0b1 + a1b0) * B^half + a0b0
+        // with a0b1 + a1b0 = (a0 + a1)(b0 + b1) - a0b0 - a1b1
+        let half = num_blocks / 2;
+        let high_len = num_blocks - half;
+        let (a0, a1) = split_radix_at(lhs, half);
+        let (b0, b1) = split_radix_at(rhs, half);
+
+        let ((z0, z2), z1) = rayon::join(
+            || {
+                rayon::join(
+                    || self.karatsuba_mul_full(&a0, &b0, threshold),
+                    || self.karatsuba_mul_full(&a1, &b1, threshold),
+                )
+            },
+            || {
+                // The sums need one more block to hold their carry
+                let (sum_a, sum_b) = rayon::join(
+                    || self.add_halves(&a0, &a1, high_len + 1),
+                    || self.add_halves(&b0, &b1, high_len + 1),
+                );
+                self.karatsuba_mul_full(&sum_a, &sum_b, threshold)
+            },
+        );
+
+        let mid_len = z1.blocks.len();
+        let (z0_ext, z2_ext) = rayon::join(
+            || self.extend_radix_with_trivial_zero_blocks_msb(&z0, mid_len - z0.blocks.len()),
+            || self.extend_radix_with_trivial_zero_blocks_msb(&z2, mid_len - z2.blocks.len()),
+        );
+        let mut mid = self.sub_parallelized(&z1, &z0_ext);
+        self.sub_assign_parallelized(&mut mid, &z2_ext);
+
+        // a0b0 and a1b1 * B^(2 * half) do not overlap,
+        // so they are concatenated without any computation
+        let mut result = z0;
+        result.blocks.extend(z2.blocks);
+
+        // a0b1 + a1b0 fits in num_blocks + 1 blocks, the remaining ones are zeros
+        self.trim_radix_blocks_msb_assign(&mut mid, mid_len - (num_blocks + 1));
+        self.extend_radix_with_trivial_zero_blocks_msb_assign(&mut mid, num_blocks - 1);
+        let mid = self.blockshift(&mid, half);
+
+        self.add_assign_parallelized(&mut result, &mid);
+        result
+    }
+
+    /// Zero-extends both halves to `num_blocks` and adds them
+    fn add_halves(
+        &self,
+        low: &RadixCiphertext,
+        high: &RadixCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let (low, high) = rayon::join(
+            || self.extend_radix_with_trivial_zero_blocks_msb(low, num_blocks - low.blocks.len()),
+            || self.extend_radix_with_trivial_zero_blocks_msb(high, num_blocks - high.blocks.len()),
+        );
+        self.add_parallelized(&low, &high)
+    }
+
+    /// Computes the product of `lhs` and `rhs` truncated to their number of blocks.
+    ///
+    /// Only `a0b0` needs the full product, the cross terms only contribute
+    /// to the low blocks, so they are truncated products themselves.
+    fn karatsuba_mul_low(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        threshold: usize,
+    ) -> RadixCiphertext {
+        let num_blocks = lhs.blocks.len();
+
+        if num_blocks < threshold.max(KARATSUBA_MIN_BLOCKS) {
+            return self.schoolbook_mul_low(lhs, rhs);
+        }
+
+        // lhs * rhs mod B^n = a0b0 + ((a0b1 + a1b0) mod B^(n - half)) * B^half
+        let half = num_blocks / 2;
+        let high_len = num_blocks - half;
+        let (a0, a1) = split_radix_at(lhs, half);
+        let (b0, b1) = split_radix_at(rhs, half);
+
+        let (mut z0, cross_terms) = rayon::join(
+            || self.karatsuba_mul_full(&a0, &b0, threshold),
+            || {
+                let (a0, b0) = rayon::join(
+                    || self.extend_radix_with_trivial_zero_blocks_msb(&a0, high_len - half),
+                    || self.extend_radix_with_trivial_zero_blocks_msb(&b0, high_len - half),
+                );
+                rayon::join(
+                    || self.karatsuba_mul_low(&a0, &b1, threshold),
+                    || self.karatsuba_mul_low(&a1, &b0, threshold),
+                )
+            },
+        );
+
+        self.extend_radix_with_trivial_zero_blocks_msb_assign(&mut z0, num_blocks - 2 * half);
+        let terms = <[_; 2]>::from(cross_terms)
+            .into_iter()
+            .map(|mut cross| {
+                self.extend_radix_with_trivial_zero_blocks_msb_assign(&mut cross, half);
+                self.blockshift(&cross, half)
+            })
+            .chain(std::iter::once(z0))
+            .collect::<Vec<_>>();
+
+        self.unchecked_sum_ciphertexts_vec_parallelized(terms)
+            .expect("terms are not empty")
+    }
+
+    /// Computes homomorphically a multiplication between two ciphertexts encrypting integer values,
+    /// using the Karatsuba algorithm.
+    ///
+    /// Instead of computing all the partial block products, operands with at least
+    /// `threshold` blocks are recursively split in two halves, which replaces
+    /// one of the four half products by additions and subtractions.
+    /// Operands smaller than `threshold` use the schoolbook multiplication.
+    ///
+    /// The result is the same as [Self::unchecked_mul_parallelized], which uses this algorithm
+    /// for ciphertexts with at least [Self::KARATSUBA_MUL_THRESHOLD] blocks.
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext, the input ciphertexts must have clean carries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lhs` and `rhs` do not have the same number of blocks
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 8;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let clear_1 = 45_123u64;
+    /// let clear_2 = 7_001u64;
+    ///
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// // Split operands of 4 blocks or more
+    /// let ct_res = sks.unchecked_karatsuba_mul_parallelized(&ctxt_1, &ctxt_2, 4);
+    ///
+    /// // Decrypt
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!((clear_1 * clear_2) % (1 << 16), res);
+    /// ```
+    pub fn unchecked_karatsuba_mul_parallelized<T>(&self, lhs: &T, rhs: &T, threshold: usize) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            lhs.blocks().len(),
+            rhs.blocks().len(),
+            "lhs and rhs must have the same number of blocks ({} vs {})",
+            lhs.blocks().len(),
+            rhs.blocks().len()
+        );
+
+        // The low blocks of the product do not depend on the sign representation
+        let lhs = RadixCiphertext::from(lhs.blocks().to_vec());
+        let rhs = RadixCiphertext::from(rhs.blocks().to_vec());
+
+        let result = self.karatsuba_mul_low(&lhs, &rhs, threshold);
+        T::from_blocks(result.blocks)
+    }
+
+    /// Computes homomorphically a multiplication between two ciphertexts encrypting integer values,
+    /// using the Karatsuba algorithm.
+    ///
+    /// This is the same as [Self::unchecked_karatsuba_mul_parallelized], but the carries of
+    /// the inputs are propagated first if needed, and the output has clean carries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lhs` and `rhs` do not have the same number of blocks
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 8;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let clear_1 = 45_123u64;
+    /// let clear_2 = 7_001u64;
+    ///
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// // Split operands of 4 blocks or more
+    /// let ct_res = sks.karatsuba_mul_parallelized(&ctxt_1, &ctxt_2, 4);
+    ///
+    /// // Decrypt
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!((clear_1 * clear_2) % (1 << 16), res);
+    /// ```
+    pub fn karatsuba_mul_parallelized<T>(&self, lhs: &T, rhs: &T, threshold: usize) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (lhs, rhs),
+            (true, false) => {
+                tmp_rhs = rhs.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (lhs, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = lhs.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, rhs)
+            }
+            (false, false) => {
+                tmp_lhs = lhs.clone();
+                tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_karatsuba_mul_parallelized(lhs, rhs, threshold)
+    }
+
     /// Computes homomorphically a multiplication between two ciphertexts encrypting integer values.
     ///
     /// This function computes the operation without checking if it exceeds the capacity of the
@@ -450,13 +755,15 @@ impl ServerKey {
             return;
         }
 
-        let terms = self.compute_terms_for_mul_low(lhs, rhs);
-
-        if let Some(result) = self.unchecked_sum_ciphertexts_vec_parallelized(terms) {
-            *lhs = result;
-        } else {
-            self.create_trivial_zero_assign_radix(lhs);
+        if lhs.blocks().len() >= Self::KARATSUBA_MUL_THRESHOLD
+            && lhs.blocks().len() == rhs.blocks().len()
+        {
+            *lhs =
+                self.unchecked_karatsuba_mul_parallelized(lhs, rhs, Self::KARATSUBA_MUL_THRESHOLD);
+            return;
         }
+
+        *lhs = self.schoolbook_mul_low(lhs, rhs);
     }
 
     /// Computes homomorphically a multiplication between two ciphertexts encrypting integer values.
@@ -0,0 +1,296 @@
+use crate::integer::bigint::{U1024, U2048};
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, U256, U512};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes homomorphically `base` raised to the power of a clear `exponent`
+    ///
+    /// The result is computed using square-and-multiply, and wraps around
+    /// (same as `wrapping_pow` in Rust).
+    ///
+    /// `exponent == 0` returns a trivial encryption of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let msg = 3u8;
+    /// let exponent = 5u32;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_pow_parallelized(&ct, exponent);
+    ///
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.wrapping_pow(exponent));
+    /// ```
+    pub fn scalar_pow_parallelized<T>(&self, base: &T, exponent: u32) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = base.blocks().len();
+        if exponent == 0 {
+            return self.create_trivial_radix(1u64, num_blocks);
+        }
+
+        let mut tmp_base;
+        let base = if base.block_carries_are_empty() {
+            base
+        } else {
+            tmp_base = base.clone();
+            self.full_propagate_parallelized(&mut tmp_base);
+            &tmp_base
+        };
+
+        // Square-and-multiply, as exponent >= 1 its msb is set,
+        // so we can start from the base directly
+        let mut result = base.clone();
+        for i in (0..exponent.ilog2()).rev() {
+            result = self.mul_parallelized(&result, &result);
+            if (exponent >> i) & 1 == 1 {
+                result = self.mul_parallelized(&result, base);
+            }
+        }
+
+        result
+    }
+
+    /// Computes homomorphically `base` raised to the power of an encrypted `exponent`
+    ///
+    /// The result is computed using square-and-multiply, where the multiplication
+    /// by the current power of the base is selected by each encrypted bit of the exponent.
+    /// It wraps around (same as `wrapping_pow` in Rust).
+    ///
+    /// The cost of this function grows with the number of blocks of the `exponent`,
+    /// so using an exponent with fewer blocks than the base is encouraged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let msg = 3u8;
+    /// let exponent = 5u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// // The exponent only uses 2 blocks (4 bits)
+    /// let ct_exponent = cks.as_ref().encrypt_radix(exponent, 2);
+    ///
+    /// let ct_res = sks.pow_parallelized(&ct, &ct_exponent);
+    ///
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg.wrapping_pow(exponent as u32));
+    /// ```
+    pub fn pow_parallelized<T>(&self, base: &T, exponent: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = base.blocks().len();
+
+        let mut tmp_base;
+        let base = if base.block_carries_are_empty() {
+            base
+        } else {
+            tmp_base = base.clone();
+            self.full_propagate_parallelized(&mut tmp_base);
+            &tmp_base
+        };
+
+        let mut tmp_exponent;
+        let exponent = if exponent.block_carries_are_empty() {
+            exponent
+        } else {
+            tmp_exponent = exponent.clone();
+            self.full_propagate_parallelized(&mut tmp_exponent);
+            &tmp_exponent
+        };
+
+        let exponent_bits = self.unchecked_extract_bits_parallelized(exponent);
+
+        let one: T = self.create_trivial_radix(1u64, num_blocks);
+        let mut result = one.clone();
+        let mut power = base.clone();
+        let num_bits = exponent_bits.len();
+        for (i, bit) in exponent_bits.iter().enumerate() {
+            // Squaring the current power does not depend on the selected factor,
+            // so both are computed in parallel
+            let (new_result, next_power) = rayon::join(
+                || {
+                    let factor = self.unchecked_if_then_else_parallelized(bit, &power, &one);
+                    self.mul_parallelized(&result, &factor)
+                },
+                || (i + 1 < num_bits).then(|| self.mul_parallelized(&power, &power)),
+            );
+            result = new_result;
+            if let Some(next_power) = next_power {
+                power = next_power;
+            }
+        }
+
+        result
+    }
+
+    /// Computes homomorphically `base` raised to the power of an encrypted `exponent`,
+    /// modulo a clear `modulus`
+    ///
+    /// The result is computed using square-and-multiply, each product being reduced
+    /// modulo `modulus`, so that intermediate values never wrap around. The products
+    /// are computed on just enough blocks to hold `(modulus - 1)^2`, and as the modulus is
+    /// clear, the reduction is a multiplication by its precomputed reciprocal.
+    ///
+    /// `exponent == 0` returns `1 % modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is 0, or if `modulus - 1` does not fit in the number of bits of `base`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let msg = 7u8;
+    /// let exponent = 13u8;
+    /// let modulus = 23u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// // The exponent only uses 2 blocks (4 bits)
+    /// let ct_exponent = cks.as_ref().encrypt_radix(exponent, 2);
+    ///
+    /// let ct_res = sks.pow_mod_parallelized(&ct, &ct_exponent, modulus);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// // 7^13 mod 23
+    /// assert_eq!(dec, 20);
+    /// ```
+    pub fn pow_mod_parallelized(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+        modulus: u64,
+    ) -> RadixCiphertext {
+        assert_ne!(
+            modulus, 0,
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        let bits_per_block = self.message_modulus().0.ilog2();
+        let num_blocks = base.blocks.len();
+        let modulus_bits = u64::BITS - (modulus - 1).leading_zeros();
+        assert!(
+            modulus_bits <= bits_per_block * num_blocks as u32,
+            "The modulus {modulus} does not fit in the {} bits of the base",
+            bits_per_block * num_blocks as u32
+        );
+
+        let mut tmp_base;
+        let base = if base.block_carries_are_empty() {
+            base
+        } else {
+            tmp_base = base.clone();
+            self.full_propagate_parallelized(&mut tmp_base);
+            &tmp_base
+        };
+
+        let mut tmp_exponent;
+        let exponent = if exponent.block_carries_are_empty() {
+            exponent
+        } else {
+            tmp_exponent = exponent.clone();
+            self.full_propagate_parallelized(&mut tmp_exponent);
+            &tmp_exponent
+        };
+
+        // Products of two values < modulus need twice the bits of the modulus
+        let num_work_blocks = (2 * modulus_bits).div_ceil(bits_per_block).max(1) as usize;
+
+        let (power, exponent_bits) = rayon::join(
+            || {
+                let reduced = self.unchecked_scalar_rem_by_u64_parallelized(base, modulus);
+                self.cast_to_unsigned(reduced, num_work_blocks)
+            },
+            || self.unchecked_extract_bits_parallelized(exponent),
+        );
+
+        let one: RadixCiphertext = self.create_trivial_radix(1u64, num_work_blocks);
+        let mut result: RadixCiphertext = self.create_trivial_radix(1 % modulus, num_work_blocks);
+        let mut power = power;
+        let num_bits = exponent_bits.len();
+        for (i, bit) in exponent_bits.iter().enumerate() {
+            let (new_result, next_power) = rayon::join(
+                || {
+                    let factor = self.unchecked_if_then_else_parallelized(bit, &power, &one);
+                    let product = self.mul_parallelized(&result, &factor);
+                    self.unchecked_scalar_rem_by_u64_parallelized(&product, modulus)
+                },
+                || {
+                    (i + 1 < num_bits).then(|| {
+                        let square = self.mul_parallelized(&power, &power);
+                        self.unchecked_scalar_rem_by_u64_parallelized(&square, modulus)
+                    })
+                },
+            );
+            result = new_result;
+            if let Some(next_power) = next_power {
+                power = next_power;
+            }
+        }
+
+        self.cast_to_unsigned(result, num_blocks)
+    }
+
+    /// Extracts each bit of the ciphertext as a [BooleanBlock], lsb first
+    ///
+    /// The carries of the ciphertext must be empty.
+    fn unchecked_extract_bits_parallelized(&self, ct: &RadixCiphertext) -> Vec<BooleanBlock> {
+        let bits_per_block = self.message_modulus().0.ilog2();
+        let bit_luts = (0..bits_per_block)
+            .map(|i| self.key.generate_lookup_table(|x| (x >> i) & 1))
+            .collect::<Vec<_>>();
+        ct.blocks
+            .par_iter()
+            .flat_map(|block| {
+                bit_luts
+                    .par_iter()
+                    .map(|lut| BooleanBlock::new_unchecked(self.key.apply_lookup_table(block, lut)))
+            })
+            .collect::<Vec<_>>()
+    }
+
+    /// Computes the remainder of the division by a clear `u64` divisor,
+    /// for a numerator of any size
+    ///
+    /// The scalar division requires a divisor type with at least as many bits as the
+    /// numerator, so the divisor is converted to the smallest such type.
+    fn unchecked_scalar_rem_by_u64_parallelized(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor: u64,
+    ) -> RadixCiphertext {
+        let numerator_bits = self.message_modulus().0.ilog2() * numerator.blocks.len() as u32;
+        match numerator_bits {
+            0..=64 => self.unchecked_scalar_rem_parallelized(numerator, divisor),
+            65..=128 => self.unchecked_scalar_rem_parallelized(numerator, u128::from(divisor)),
+            129..=256 => self.unchecked_scalar_rem_parallelized(numerator, U256::from(divisor)),
+            257..=512 => self.unchecked_scalar_rem_parallelized(numerator, U512::from(divisor)),
+            513..=1024 => self.unchecked_scalar_rem_parallelized(numerator, U1024::from(divisor)),
+            _ => self.unchecked_scalar_rem_parallelized(numerator, U2048::from(divisor)),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::integer::{IntegerRadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Reduces the `items` with the binary operation `op`, using a parallel reduction tree.
+    ///
+    /// At each level of the tree, consecutive pairs of items are combined in parallel,
+    /// the left item of the pair is always given as the first operand of `op`.
+    /// The relative order of the operands is thus preserved, which means `op` only needs to
+    /// be associative, it is not required to be commutative.
+    ///
+    /// - Returns `identity` if `items` is empty.
+    /// - `identity` is never given to `op`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerRadixCiphertext, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, 1);
+    ///
+    /// let msgs = [1u64, 2, 3];
+    /// let cts = msgs.iter().map(|m| cks.encrypt(*m)).collect::<Vec<_>>();
+    ///
+    /// // Concatenating the blocks is associative but not commutative
+    /// let concatenated = sks.reduce_ordered_parallelized(
+    ///     cts,
+    ///     RadixCiphertext::from(vec![]),
+    ///     |_, lhs: RadixCiphertext, rhs: RadixCiphertext| {
+    ///         let mut blocks = lhs.into_blocks();
+    ///         blocks.extend(rhs.into_blocks());
+    ///         RadixCiphertext::from(blocks)
+    ///     },
+    /// );
+    ///
+    /// // Blocks are in little endian order
+    /// let dec: u64 = cks.decrypt(&concatenated);
+    /// assert_eq!(dec, 1 + (2 << 2) + (3 << 4));
+    /// ```
+    pub fn reduce_ordered_parallelized<T, F>(&self, items: Vec<T>, identity: T, op: F) -> T
+    where
+        T: Send,
+        F: Fn(&Self, T, T) -> T + Sync,
+    {
+        self.reduce_ordered_impl(items, op).unwrap_or(identity)
+    }
+
+    /// Folds the ciphertexts with the smart binary operation `op`, preserving their order.
+    ///
+    /// Like [Self::reduce_ordered_parallelized], adjacent pairs of ciphertexts are combined in
+    /// parallel, with the left ciphertext always given as the first operand of `op`,
+    /// so `op` has to be associative but does not need to be commutative.
+    ///
+    /// - Returns `None` if `cts` is empty
+    /// - Returns a clone of the ciphertext if `cts` has only one element
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, 1);
+    ///
+    /// let msgs = [3u64, 1, 2];
+    /// let cts = msgs.iter().map(|m| cks.encrypt(*m)).collect::<Vec<_>>();
+    ///
+    /// // Concatenating the blocks is associative but not commutative
+    /// let concatenated = sks
+    ///     .smart_fold_seq_parallelized(
+    ///         &cts,
+    ///         |_, lhs: &mut RadixCiphertext, rhs: &mut RadixCiphertext| {
+    ///             let mut blocks = lhs.blocks().to_vec();
+    ///             blocks.extend_from_slice(rhs.blocks());
+    ///             RadixCiphertext::from(blocks)
+    ///         },
+    ///     )
+    ///     .unwrap();
+    ///
+    /// // Blocks are in little endian order
+    /// let dec: u64 = cks.decrypt(&concatenated);
+    /// assert_eq!(dec, 3 + (1 << 2) + (2 << 4));
+    /// ```
+    pub fn smart_fold_seq_parallelized<T, F>(&self, cts: &[T], op: F) -> Option<T>
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(&Self, &mut T, &mut T) -> T + Sync,
+    {
+        self.reduce_ordered_impl(cts.to_vec(), |sks, mut lhs, mut rhs| {
+            op(sks, &mut lhs, &mut rhs)
+        })
+    }
+
+    fn reduce_ordered_impl<T, F>(&self, mut items: Vec<T>, op: F) -> Option<T>
+    where
+        T: Send,
+        F: Fn(&Self, T, T) -> T + Sync,
+    {
+        while items.len() > 1 {
+            // Group consecutive items by pairs, if the number of items is odd,
+            // the last one has no pair and is forwarded to the next level as is
+            let mut pairs = Vec::with_capacity(items.len().div_ceil(2));
+            let mut iter = items.into_iter();
+            while let Some(lhs) = iter.next() {
+                pairs.push((lhs, iter.next()));
+            }
+
+            items = pairs
+                .into_par_iter()
+                .map(|(lhs, rhs)| match rhs {
+                    Some(rhs) => op(self, lhs, rhs),
+                    None => lhs,
+                })
+                .collect();
+        }
+
+        items.pop()
+    }
+}
@@ -0,0 +1,81 @@
+use crate::core_crypto::algorithms::lwe_ciphertext_add_assign;
+use crate::integer::{PublicKey, RadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Re-randomizes a ciphertext by adding a fresh encryption of zero to each block
+    ///
+    /// The encrypted value is unchanged, but the resulting ciphertext is unlinkable
+    /// to the input one. As the server key holds no encryption material, fresh
+    /// encryptions of zero are produced with the given `public_key`.
+    ///
+    /// Each block gets the noise of one additional encryption; blocks whose noise
+    /// level is already at the maximum are first cleaned with a PBS. If the blocks
+    /// hold carries, they are propagated first so that cleaning a block does not drop them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `public_key` was not created with the same message and carry moduli
+    /// as this server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, PublicKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    /// let pk = PublicKey::new(&cks);
+    ///
+    /// let msg = 157u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let mut ct_res = ct.clone();
+    /// sks.rerandomize_parallelized(&mut ct_res, &pk);
+    /// assert_ne!(ct_res, ct);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg);
+    /// ```
+    pub fn rerandomize_parallelized(&self, ct: &mut RadixCiphertext, public_key: &PublicKey) {
+        assert_eq!(
+            public_key.key.parameters.message_modulus(),
+            self.message_modulus(),
+            "Public key and server key have different message moduli"
+        );
+        assert_eq!(
+            public_key.key.parameters.carry_modulus(),
+            self.carry_modulus(),
+            "Public key and server key have different carry moduli"
+        );
+
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        ct.blocks.par_iter_mut().for_each(|block| {
+            assert_eq!(
+                block.pbs_order, public_key.key.pbs_order,
+                "Ciphertext and public key have different PBS orders"
+            );
+
+            let zero = public_key.key.unchecked_encrypt(0);
+            if self
+                .key
+                .max_noise_level
+                .validate(block.noise_level() + zero.noise_level())
+                .is_err()
+            {
+                self.key.message_extract_assign(block);
+            }
+
+            lwe_ciphertext_add_assign(&mut block.ct, &zero.ct);
+            block.set_noise_level(
+                block.noise_level() + zero.noise_level(),
+                self.key.max_noise_level,
+            );
+        });
+    }
+}
@@ -296,6 +296,59 @@ impl ServerKey {
         );
     }
 
+    /// Increments by one a big-endian counter made of several unsigned ciphertexts
+    ///
+    /// The counter is interpreted as a single big integer, where `counter[0]` holds the most
+    /// significant part, and the last element the least significant one. The carry of the
+    /// increment is propagated across the elements.
+    ///
+    /// When all the elements are at their max value, the counter wraps around and all the
+    /// elements are set to 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message, each element is a byte
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// // 0x00FF
+    /// let mut counter = vec![cks.encrypt(0x00u64), cks.encrypt(0xFFu64)];
+    ///
+    /// sks.increment_counter_parallelized(&mut counter);
+    ///
+    /// let dec: Vec<u64> = counter.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(dec, vec![0x01, 0x00]);
+    /// ```
+    pub fn increment_counter_parallelized(&self, counter: &mut [RadixCiphertext]) {
+        if counter.is_empty() {
+            return;
+        }
+
+        // Concatenate the elements into one radix, least significant element first
+        let num_blocks_per_element = counter
+            .iter()
+            .rev()
+            .map(|element| element.blocks.len())
+            .collect::<Vec<_>>();
+        let blocks = counter
+            .iter_mut()
+            .rev()
+            .flat_map(|element| std::mem::take(&mut element.blocks))
+            .collect::<Vec<_>>();
+        let mut big_counter = RadixCiphertext::from(blocks);
+
+        self.scalar_add_assign_parallelized(&mut big_counter, 1u8);
+
+        let mut blocks = big_counter.blocks.into_iter();
+        for (element, num_blocks) in counter.iter_mut().rev().zip(num_blocks_per_element) {
+            element.blocks = blocks.by_ref().take(num_blocks).collect();
+        }
+    }
+
     pub(crate) fn add_assign_scalar_blocks_parallelized<T>(
         &self,
         lhs: &mut T,
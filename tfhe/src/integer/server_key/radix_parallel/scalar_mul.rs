@@ -1,7 +1,7 @@
 use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
 use crate::integer::ciphertext::IntegerRadixCiphertext;
 use crate::integer::server_key::radix::scalar_mul::ScalarMultiplier;
-use crate::integer::ServerKey;
+use crate::integer::{RadixCiphertext, ServerKey};
 use rayon::prelude::*;
 
 impl ServerKey {
@@ -189,4 +189,54 @@ impl ServerKey {
 
         self.unchecked_scalar_mul_assign_parallelized(lhs, scalar);
     }
+
+    /// Multiplies each ciphertext by its corresponding clear factor
+    ///
+    /// The multiplications are done in parallel, each one like [Self::scalar_mul_parallelized].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` and `factors` do not have the same length
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let modulus = 1 << 8;
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let msgs = [230u64, 17, 3];
+    /// let factors = [376u64, 5, 0];
+    ///
+    /// let cts = msgs.iter().map(|msg| cks.encrypt(*msg)).collect::<Vec<_>>();
+    ///
+    /// let cts_res = sks.scalar_mul_vec_parallelized(&cts, &factors);
+    ///
+    /// for ((ct_res, msg), factor) in cts_res.iter().zip(msgs).zip(factors) {
+    ///     let clear: u64 = cks.decrypt(ct_res);
+    ///     assert_eq!(msg * factor % modulus, clear);
+    /// }
+    /// ```
+    pub fn scalar_mul_vec_parallelized(
+        &self,
+        cts: &[RadixCiphertext],
+        factors: &[u64],
+    ) -> Vec<RadixCiphertext> {
+        assert_eq!(
+            cts.len(),
+            factors.len(),
+            "Cannot multiply {} ciphertexts by {} factors",
+            cts.len(),
+            factors.len()
+        );
+
+        cts.par_iter()
+            .zip(factors.par_iter())
+            .map(|(ct, factor)| self.scalar_mul_parallelized(ct, *factor))
+            .collect()
+    }
 }
@@ -290,8 +290,10 @@ impl ServerKey {
         let shift_within_block = n % num_bits_in_message;
         let num_blocks = ct.blocks().len();
 
-        // rotate left as the blocks are from LSB to MSB
-        ct.blocks_mut().rotate_left(rotations);
+        self.rotate_right_blocks_assign(ct, rotations);
+
+        // When n is a multiple of the number of bits in a block,
+        // rotating the blocks is all there is to do, without any PBS
 
         let message_modulus = self.key.message_modulus.0;
         if shift_within_block != 0 {
@@ -618,8 +620,10 @@ impl ServerKey {
         let shift_within_block = n % num_bits_in_message;
         let num_blocks = ct.blocks().len();
 
-        // rotate right as the blocks are from LSB to MSB
-        ct.blocks_mut().rotate_right(rotations);
+        self.rotate_left_blocks_assign(ct, rotations);
+
+        // When n is a multiple of the number of bits in a block,
+        // rotating the blocks is all there is to do, without any PBS
 
         if shift_within_block != 0 {
             let lut = self
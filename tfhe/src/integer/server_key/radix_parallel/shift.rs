@@ -107,6 +107,13 @@ impl ServerKey {
     ///
     /// The result is returned as a new ciphertext.
     ///
+    /// The shift is computed by a barrel shifter, with one conditional shift stage per bit
+    /// of the shift amount. Like Rust's `wrapping_shr`, the shift amount is reduced modulo the
+    /// number of bits of `ct`, so shifting by the bit width or more does not give zero. When
+    /// the number of bits is not a power of two, the amount is reduced modulo the next power of
+    /// two, and reduced amounts that are still greater than or equal to the number of bits give
+    /// zero.
+    ///
     /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
     /// check that the input ciphertexts block carries are empty and clears them if it's not the
     /// case and the operation requires it. It outputs a ciphertext whose block carries are always
@@ -255,6 +262,13 @@ impl ServerKey {
     ///
     /// The result is returned as a new ciphertext.
     ///
+    /// The shift is computed by a barrel shifter, with one conditional shift stage per bit
+    /// of the shift amount. Like Rust's `wrapping_shl`, the shift amount is reduced modulo the
+    /// number of bits of `ct`, so shifting by the bit width or more does not give zero. When
+    /// the number of bits is not a power of two, the amount is reduced modulo the next power of
+    /// two, and reduced amounts that are still greater than or equal to the number of bits give
+    /// zero.
+    ///
     /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
     /// check that the input ciphertexts block carries are empty and clears them if it's not the
     /// case and the operation requires it. It outputs a ciphertext whose block carries are always
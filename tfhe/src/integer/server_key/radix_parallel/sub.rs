@@ -793,4 +793,85 @@ impl ServerKey {
 
         self.unchecked_signed_overflowing_sub_parallelized_with_choice(lhs, rhs, algorithm)
     }
+
+    /// Computes the subtraction of two unsigned ciphertexts, saturating at zero
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg1 = 100u8;
+    /// let msg2 = 200u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unsigned_saturating_sub_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_sub(msg2));
+    /// ```
+    pub fn unsigned_saturating_sub_parallelized(
+        &self,
+        ctxt_left: &RadixCiphertext,
+        ctxt_right: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let (mut result, overflowed) =
+            self.unsigned_overflowing_sub_parallelized(ctxt_left, ctxt_right);
+        // An unsigned subtraction can only overflow below zero
+        self.zero_out_if_condition_equals(&mut result, overflowed.as_ref(), 1);
+        result
+    }
+
+    /// Computes the subtraction of two signed ciphertexts, saturating at the min or max value
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg1 = 100i8;
+    /// let msg2 = -50i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_saturating_sub_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_sub(msg2));
+    /// ```
+    pub fn signed_saturating_sub_parallelized(
+        &self,
+        ctxt_left: &SignedRadixCiphertext,
+        ctxt_right: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let ((result, overflowed), rhs_is_negative) = rayon::join(
+            || self.signed_overflowing_sub_parallelized(ctxt_left, ctxt_right),
+            || self.scalar_lt_parallelized(ctxt_right, 0i64),
+        );
+
+        // Subtracting a negative value can only overflow above the max,
+        // subtracting a non-negative value can only overflow below the min
+        let num_blocks = result.blocks.len();
+        let (min, max): (SignedRadixCiphertext, SignedRadixCiphertext) = (
+            self.create_trivial_min_radix(num_blocks),
+            self.create_trivial_max_radix(num_blocks),
+        );
+        let bound = self.unchecked_if_then_else_parallelized(&rhs_is_negative, &max, &min);
+        self.unchecked_if_then_else_parallelized(&overflowed, &bound, &result)
+    }
 }
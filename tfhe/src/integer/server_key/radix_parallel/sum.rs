@@ -177,6 +177,10 @@ impl ServerKey {
 
     /// Computes the sum of the ciphertexts in parallel.
     ///
+    /// Blocks are accumulated in the carry space of the ciphertexts for as long as
+    /// the degree and noise level allow it, so carries are only propagated once the
+    /// budget is exhausted. This needs far fewer PBS than chaining additions.
+    ///
     /// - Returns None if ciphertexts is empty
     ///
     /// See [Self::unchecked_sum_ciphertexts_parallelized] for constraints
@@ -189,14 +193,11 @@ impl ServerKey {
             .into_iter()
             .map(Clone::clone)
             .collect::<Vec<T>>();
-        ciphertexts
-            .par_iter_mut()
-            .filter(|ct| ct.block_carries_are_empty())
-            .for_each(|ct| {
-                if !ct.block_carries_are_empty() {
-                    self.full_propagate_parallelized(&mut *ct);
-                }
-            });
+        ciphertexts.par_iter_mut().for_each(|ct| {
+            if !ct.block_carries_are_empty() {
+                self.full_propagate_parallelized(&mut *ct);
+            }
+        });
 
         self.unchecked_sum_ciphertexts_vec_parallelized(ciphertexts)
     }
@@ -331,14 +332,11 @@ impl ServerKey {
             .into_iter()
             .map(Clone::clone)
             .collect::<Vec<_>>();
-        ciphertexts
-            .par_iter_mut()
-            .filter(|ct| ct.block_carries_are_empty())
-            .for_each(|ct| {
-                if !ct.block_carries_are_empty() {
-                    self.full_propagate_parallelized(&mut *ct);
-                }
-            });
+        ciphertexts.par_iter_mut().for_each(|ct| {
+            if !ct.block_carries_are_empty() {
+                self.full_propagate_parallelized(&mut *ct);
+            }
+        });
 
         self.unchecked_unsigned_overflowing_sum_ciphertexts_vec_parallelized(ciphertexts)
     }
@@ -365,4 +363,136 @@ impl ServerKey {
 
         self.unchecked_unsigned_overflowing_sum_ciphertexts_parallelized(ciphertexts.as_ref())
     }
+
+    /// Computes the sum of the unsigned ciphertexts in parallel.
+    ///
+    /// Returns the wrapped sum, and a boolean that is true if the sum overflowed at any point,
+    /// that is, if any partial sum (including the final one) did not fit in a ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `cts` is empty
+    /// - Panics if not all ciphertexts have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let msgs = [200u64, 50, 10];
+    /// let cts = msgs.iter().map(|m| cks.encrypt(*m)).collect::<Vec<_>>();
+    ///
+    /// let (ct_res, overflowed) = sks.sum_with_overflow_parallelized(&cts);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, (200 + 50 + 10) % 256);
+    /// assert!(cks.decrypt_bool(&overflowed));
+    /// ```
+    pub fn sum_with_overflow_parallelized(
+        &self,
+        cts: &[RadixCiphertext],
+    ) -> (RadixCiphertext, BooleanBlock) {
+        // As all values are unsigned, the running sum can only grow,
+        // so the overflow of any partial sum is visible in the overflow of the whole sum
+        self.unsigned_overflowing_sum_ciphertexts_parallelized(cts)
+            .expect("Cannot sum an empty slice of ciphertexts")
+    }
+
+    /// Computes homomorphically the moving average of the ciphertexts over a sliding window
+    ///
+    /// The i-th output is the average of `cts[i..i + window]`, i.e. each value of the window
+    /// has the same weight `1 / window`, and the result is rounded towards zero.
+    /// The window sums are computed from prefix sums, on enough extra blocks so that they
+    /// cannot overflow, then the outputs have the same number of blocks as the inputs.
+    ///
+    /// At the boundaries, only windows that are fully inside the input are computed,
+    /// so the output has `cts.len() - window + 1` elements, and is empty if
+    /// `cts.len() < window`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `window` is 0
+    /// - Panics if the ciphertexts do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let msgs = [200u64, 250, 10, 30];
+    /// let cts = msgs.iter().map(|m| cks.encrypt(*m)).collect::<Vec<_>>();
+    ///
+    /// let averages = sks.moving_average_parallelized(&cts, 2);
+    /// assert_eq!(averages.len(), 3);
+    ///
+    /// let decrypted = averages
+    ///     .iter()
+    ///     .map(|ct| cks.decrypt::<u64>(ct))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(decrypted, vec![225, 130, 20]);
+    /// ```
+    pub fn moving_average_parallelized(
+        &self,
+        cts: &[RadixCiphertext],
+        window: usize,
+    ) -> Vec<RadixCiphertext> {
+        assert!(window > 0, "The window must contain at least one element");
+        if cts.len() < window {
+            return vec![];
+        }
+
+        let num_blocks = cts[0].blocks.len();
+        assert!(
+            cts[1..].iter().all(|ct| ct.blocks.len() == num_blocks),
+            "Not all ciphertexts have the same number of blocks"
+        );
+
+        // The sum of `window` values needs ceil(log2(window)) more bits
+        let msg_bits = self.message_modulus().0.ilog2() as usize;
+        let extra_bits = window.next_power_of_two().ilog2() as usize;
+        let extra_blocks = extra_bits.div_ceil(msg_bits);
+
+        let extended = cts
+            .par_iter()
+            .map(|ct| {
+                let mut ct = ct.clone();
+                if !ct.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut ct);
+                }
+                self.extend_radix_with_trivial_zero_blocks_msb_assign(&mut ct, extra_blocks);
+                ct
+            })
+            .collect::<Vec<_>>();
+
+        // prefix_sums[i] = cts[0] + ... + cts[i - 1]
+        // The prefix sums may wrap around, but as only differences of
+        // prefix sums are used, the window sums are still correct
+        let mut prefix_sums = Vec::with_capacity(extended.len() + 1);
+        prefix_sums
+            .push(self.create_trivial_zero_radix::<RadixCiphertext>(num_blocks + extra_blocks));
+        for ct in &extended {
+            let next = self.add_parallelized(&prefix_sums[prefix_sums.len() - 1], ct);
+            prefix_sums.push(next);
+        }
+
+        (0..=cts.len() - window)
+            .into_par_iter()
+            .map(|i| {
+                let window_sum = self.sub_parallelized(&prefix_sums[i + window], &prefix_sums[i]);
+                let mut average = self.scalar_div_parallelized(&window_sum, window as u64);
+                self.trim_radix_blocks_msb_assign(&mut average, extra_blocks);
+                average
+            })
+            .collect()
+    }
 }
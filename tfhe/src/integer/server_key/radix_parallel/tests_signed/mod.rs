@@ -429,6 +429,23 @@ pub(crate) fn signed_div_rem_floor_under_modulus(lhs: i64, rhs: i64, modulus: i6
     (q, r)
 }
 
+pub(crate) fn signed_div_rem_euclid_under_modulus(lhs: i64, rhs: i64, modulus: i64) -> (i64, i64) {
+    let mut q = signed_div_under_modulus(lhs, rhs, modulus);
+    let mut r = signed_rem_under_modulus(lhs, rhs, modulus);
+
+    if r < 0 {
+        if rhs > 0 {
+            q = signed_sub_under_modulus(q, 1, modulus);
+            r = signed_add_under_modulus(r, rhs, modulus);
+        } else {
+            q = signed_add_under_modulus(q, 1, modulus);
+            r = signed_sub_under_modulus(r, rhs, modulus);
+        }
+    }
+
+    (q, r)
+}
+
 /// helper function to do a rotate left when the type used to store
 /// the value is bigger than the actual intended bit size
 pub(crate) fn rotate_left_helper(value: i64, n: u32, actual_bit_size: u32) -> i64 {
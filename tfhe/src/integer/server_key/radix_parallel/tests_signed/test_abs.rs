@@ -18,6 +18,8 @@ use std::sync::Arc;
 create_parameterized_test!(integer_signed_default_absolute_value);
 create_parameterized_test!(integer_signed_unchecked_absolute_value);
 create_parameterized_test!(integer_signed_smart_absolute_value);
+create_parameterized_test!(integer_signed_default_signum);
+create_parameterized_test!(integer_signed_default_relu);
 
 fn integer_signed_default_absolute_value<P>(param: P)
 where
@@ -35,6 +37,22 @@ where
     signed_unchecked_absolute_value_test(param, executor);
 }
 
+fn integer_signed_default_signum<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::signum_parallelized);
+    signed_default_signum_test(param, executor);
+}
+
+fn integer_signed_default_relu<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::relu_parallelized);
+    signed_default_relu_test(param, executor);
+}
+
 fn integer_signed_smart_absolute_value<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -179,3 +197,89 @@ where
         assert_eq!(ct_res2, ct_res);
     }
 }
+
+pub(crate) fn signed_default_signum_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a SignedRadixCiphertext, SignedRadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for clear_0 in [-modulus, -1, 0, 1, modulus - 1] {
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ct_res = executor.execute(&ctxt_0);
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec_res, clear_0.signum(), "Invalid signum of {clear_0}");
+    }
+
+    for _ in 0..nb_tests {
+        let mut clear_0 = rng.gen::<i64>() % modulus;
+        let clear_to_add = rng.gen::<i64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt_signed(clear_0);
+        sks.unchecked_scalar_add_assign(&mut ctxt_0, clear_to_add);
+        clear_0 = signed_add_under_modulus(clear_0, clear_to_add, modulus);
+
+        let ct_res = executor.execute(&ctxt_0);
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec_res, clear_0.signum(), "Invalid signum of {clear_0}");
+
+        let ct_res2 = executor.execute(&ctxt_0);
+        assert_eq!(ct_res2, ct_res);
+    }
+}
+
+pub(crate) fn signed_default_relu_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a SignedRadixCiphertext, SignedRadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for clear_0 in [-modulus, -1, 0, 1, modulus - 1] {
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ct_res = executor.execute(&ctxt_0);
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec_res, clear_0.max(0), "Invalid relu of {clear_0}");
+    }
+
+    for _ in 0..nb_tests {
+        let mut clear_0 = rng.gen::<i64>() % modulus;
+        let clear_to_add = rng.gen::<i64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt_signed(clear_0);
+        sks.unchecked_scalar_add_assign(&mut ctxt_0, clear_to_add);
+        clear_0 = signed_add_under_modulus(clear_0, clear_to_add, modulus);
+
+        let ct_res = executor.execute(&ctxt_0);
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec_res, clear_0.max(0), "Invalid relu of {clear_0}");
+
+        let ct_res2 = executor.execute(&ctxt_0);
+        assert_eq!(ct_res2, ct_res);
+    }
+}
@@ -1,7 +1,8 @@
 use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
 use crate::integer::server_key::radix_parallel::tests_signed::{
-    signed_div_rem_floor_under_modulus, signed_div_under_modulus, signed_rem_under_modulus, NB_CTXT,
+    signed_div_rem_euclid_under_modulus, signed_div_rem_floor_under_modulus,
+    signed_div_under_modulus, signed_rem_under_modulus, NB_CTXT,
 };
 use crate::integer::server_key::radix_parallel::tests_unsigned::{
     nb_tests_smaller_for_params, CpuFunctionExecutor,
@@ -53,6 +54,21 @@ create_parameterized_test!(
         }
     }
 );
+create_parameterized_test!(
+    integer_signed_div_rem_euclid {
+        coverage => {
+            COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            COVERAGE_PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+        },
+        no_coverage => {
+            // Does not support 1_1
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+            V1_0_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+            V1_0_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+            V1_0_PARAM_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+        }
+    }
+);
 fn integer_signed_unchecked_div_rem<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -69,6 +85,14 @@ where
     signed_unchecked_div_rem_floor_test(param, executor);
 }
 
+fn integer_signed_div_rem_euclid<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::div_rem_euclid_parallelized);
+    signed_default_div_rem_euclid_test(param, executor);
+}
+
 pub(crate) fn signed_unchecked_div_rem_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
@@ -232,3 +256,89 @@ where
         assert_eq!(r, expected_r);
     }
 }
+
+pub(crate) fn signed_default_div_rem_euclid_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a SignedRadixCiphertext, &'a SignedRadixCiphertext),
+        (SignedRadixCiphertext, SignedRadixCiphertext),
+    >,
+{
+    let param = param.into();
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    if modulus > 8 {
+        // All sign combinations, with and without a remainder
+        let values = [
+            (7, 3, 2, 1),
+            (-7, 3, -3, 2),
+            (7, -3, -2, 1),
+            (-7, -3, 3, 2),
+            (6, 3, 2, 0),
+            (-6, 3, -2, 0),
+            (6, -3, -2, 0),
+            (-6, -3, 2, 0),
+        ];
+        for (clear_0, clear_1, expected_q, expected_r) in values {
+            let ctxt_0 = cks.encrypt_signed(clear_0);
+            let ctxt_1 = cks.encrypt_signed(clear_1);
+
+            let (q_res, r_res) = executor.execute((&ctxt_0, &ctxt_1));
+            let q: i64 = cks.decrypt_signed(&q_res);
+            let r: i64 = cks.decrypt_signed(&r_res);
+
+            // Uses the hardcoded values to also test our clear function
+            let (q2, r2) = signed_div_rem_euclid_under_modulus(clear_0, clear_1, modulus);
+
+            assert_eq!(
+                (q2, r2),
+                (clear_0.div_euclid(clear_1), clear_0.rem_euclid(clear_1))
+            );
+            assert_eq!(q2, expected_q);
+            assert_eq!(r2, expected_r);
+            assert_eq!(q, expected_q, "Invalid quotient for {clear_0} / {clear_1}");
+            assert_eq!(r, expected_r, "Invalid remainder for {clear_0} % {clear_1}");
+        }
+    }
+
+    // Div is the slowest operation
+    for _ in 0..5 {
+        let clear_0 = rng.gen::<i64>() % modulus;
+        let clear_1 = loop {
+            let value = rng.gen::<i64>() % modulus;
+            if value != 0 {
+                break value;
+            }
+        };
+
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let (q_res, r_res) = executor.execute((&ctxt_0, &ctxt_1));
+        let q: i64 = cks.decrypt_signed(&q_res);
+        let r: i64 = cks.decrypt_signed(&r_res);
+        let (expected_q, expected_r) =
+            signed_div_rem_euclid_under_modulus(clear_0, clear_1, modulus);
+
+        assert_eq!(
+            q, expected_q,
+            "Invalid quotient for {clear_0} / {clear_1}, expected {expected_q} got {q}"
+        );
+        assert_eq!(
+            r, expected_r,
+            "Invalid remainder for {clear_0} % {clear_1}, expected {expected_r} got {r}"
+        );
+        assert!(r >= 0);
+    }
+}
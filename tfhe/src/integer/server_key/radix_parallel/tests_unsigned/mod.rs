@@ -6,8 +6,12 @@ pub(crate) mod test_comparison;
 mod test_count_zeros_ones;
 pub(crate) mod test_div_mod;
 pub(crate) mod test_ilog2;
+mod test_map_blocks;
+mod test_modular;
 pub(crate) mod test_mul;
 pub(crate) mod test_neg;
+mod test_pow;
+mod test_reduce;
 pub(crate) mod test_rotate;
 pub(crate) mod test_scalar_add;
 pub(crate) mod test_scalar_bitwise_op;
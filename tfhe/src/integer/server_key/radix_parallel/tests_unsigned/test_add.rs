@@ -20,7 +20,11 @@ use std::sync::Arc;
 create_parameterized_test!(integer_unchecked_add);
 create_parameterized_test!(integer_unchecked_add_assign);
 create_parameterized_test!(integer_smart_add);
+create_parameterized_test!(integer_batched_add_single_propagation);
+create_parameterized_test!(integer_full_propagate_parallelized_matches_sequential);
 create_parameterized_test!(integer_default_add);
+#[cfg(feature = "pbs-stats")]
+create_parameterized_test!(integer_smart_add_pbs_budget);
 create_parameterized_test!(integer_extensive_trivial_default_add);
 create_parameterized_test!(integer_default_overflowing_add);
 create_parameterized_test!(integer_extensive_trivial_default_overflowing_add);
@@ -60,6 +64,7 @@ create_parameterized_test!(integer_extensive_trivial_overflowing_advanced_add_as
 create_parameterized_test!(
     integer_extensive_trivial_advanced_overflowing_add_assign_with_carry_sequential
 );
+create_parameterized_test!(integer_saturating_accumulate_if);
 
 fn integer_unchecked_add<P>(param: P)
 where
@@ -85,6 +90,150 @@ where
     smart_add_test(param, executor);
 }
 
+#[cfg(feature = "pbs-stats")]
+fn integer_smart_add_pbs_budget<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    use crate::with_pbs_budget;
+
+    const NUM_ADDS: usize = 10;
+
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = &sks;
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    // Enough additions are chained for carries to be propagated at some point
+    let chained_adds = |clear_0: u64, clear_1: u64| {
+        let mut ct_0 = cks.encrypt(clear_0);
+        let mut ct_1 = cks.encrypt(clear_1);
+        move || {
+            let mut ct_res = sks.smart_add_parallelized(&mut ct_0, &mut ct_1);
+            for _ in 1..NUM_ADDS {
+                ct_res = sks.smart_add_parallelized(&mut ct_res, &mut ct_1);
+            }
+            ct_res
+        }
+    };
+
+    let clear_0 = rng.gen::<u64>() % modulus;
+    let clear_1 = rng.gen::<u64>() % modulus;
+    let err = with_pbs_budget(0, chained_adds(clear_0, clear_1)).unwrap_err();
+    let pbs_count = err.pbs_count;
+    assert!(pbs_count > 0);
+    assert_eq!(err.budget, 0);
+
+    // The number of PBS does not depend on the encrypted values
+    for _ in 0..nb_tests_smaller_for_params(param) {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_res = with_pbs_budget(pbs_count, chained_adds(clear_0, clear_1)).unwrap();
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = clear_0.wrapping_add(clear_1 * NUM_ADDS as u64) % modulus;
+        assert_eq!(dec_res, expected);
+
+        let err = with_pbs_budget(pbs_count - 1, chained_adds(clear_0, clear_1)).unwrap_err();
+        assert_eq!(err.pbs_count, pbs_count);
+    }
+}
+
+fn integer_full_propagate_parallelized_matches_sequential<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+
+    let bits_in_block = cks.parameters().message_modulus().0.ilog2();
+    // The largest number of blocks fitting in a u64, e.g. 32 blocks for 2_2 parameters
+    let max_num_blocks = (u64::BITS / bits_in_block) as usize;
+
+    for num_blocks in [NB_CTXT, max_num_blocks] {
+        let num_bits = bits_in_block * num_blocks as u32;
+        let mask = u64::MAX >> (u64::BITS - num_bits);
+
+        for _ in 0..nb_tests {
+            let clear_0 = rng.gen::<u64>() & mask;
+            let clear_1 = rng.gen::<u64>() & mask;
+
+            let ct_0 = cks.encrypt_radix(clear_0, num_blocks);
+            let ct_1 = cks.encrypt_radix(clear_1, num_blocks);
+
+            // Every block may hold a carry
+            let mut ct_sequential = sks.unchecked_add(&ct_0, &ct_1);
+            let mut ct_parallel = ct_sequential.clone();
+
+            sks.full_propagate(&mut ct_sequential);
+            sks.full_propagate_parallelized(&mut ct_parallel);
+            assert!(ct_sequential.block_carries_are_empty());
+            assert!(ct_parallel.block_carries_are_empty());
+
+            let dec_sequential: u64 = cks.decrypt_radix(&ct_sequential);
+            let dec_parallel: u64 = cks.decrypt_radix(&ct_parallel);
+            assert_eq!(
+                dec_parallel, dec_sequential,
+                "Invalid carry propagation of {clear_0} + {clear_1} on {num_blocks} blocks"
+            );
+            assert_eq!(dec_parallel, clear_0.wrapping_add(clear_1) & mask);
+        }
+    }
+}
+
+fn integer_batched_add_single_propagation<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let clears = (0..4)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let ctxts = clears
+            .iter()
+            .map(|clear| cks.encrypt(*clear))
+            .collect::<Vec<_>>();
+
+        // Accumulate without propagating, as long as the carry budget allows it
+        // (at least one addition always fits)
+        let mut result = ctxts[0].clone();
+        let mut expected = clears[0];
+        for (ct, clear) in ctxts[1..].iter().zip(clears[1..].iter()) {
+            if sks.checked_add_assign(&mut result, ct).is_err() {
+                break;
+            }
+            expected = expected.wrapping_add(*clear) % modulus;
+        }
+
+        assert!(
+            !result.block_carries_are_empty(),
+            "Carries should be dirty after unchecked additions"
+        );
+
+        sks.full_propagate_parallelized(&mut result);
+        panic_if_any_block_is_not_clean(&result, &cks);
+
+        let dec: u64 = cks.decrypt(&result);
+        assert_eq!(dec, expected);
+    }
+}
+
 fn integer_default_add<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -245,6 +394,57 @@ where
     extensive_trivial_default_overflowing_add_test(param, executor);
 }
 
+fn integer_saturating_accumulate_if<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let clear_acc = rng.gen::<u64>() % modulus;
+        let clear_amount = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+        // Sometimes the cap cannot be represented, in which case saturation is at the max value
+        let cap = rng.gen::<u64>() % (modulus + modulus / 2);
+
+        let mut acc = cks.encrypt(clear_acc);
+        let amount = cks.encrypt(clear_amount);
+        let condition = cks.encrypt_bool(clear_condition);
+
+        sks.saturating_accumulate_if_parallelized(&mut acc, &amount, &condition, cap);
+        let dec: u64 = cks.decrypt(&acc);
+
+        let expected = if clear_condition {
+            (clear_acc + clear_amount).min(cap).min(modulus - 1)
+        } else {
+            clear_acc
+        };
+        assert_eq!(
+            dec, expected,
+            "Invalid result for saturating accumulate of {clear_amount} into {clear_acc} \
+            with cap {cap} and condition {clear_condition}"
+        );
+    }
+
+    // Accumulating past the cap under a true condition
+    let cap = modulus / 2;
+    let mut acc = cks.encrypt(cap - 1);
+    let amount = cks.encrypt(2u64);
+    let condition = cks.encrypt_bool(true);
+    for _ in 0..3 {
+        sks.saturating_accumulate_if_parallelized(&mut acc, &amount, &condition, cap);
+        let dec: u64 = cks.decrypt(&acc);
+        assert_eq!(dec, cap);
+    }
+}
+
 impl ExpectedNoiseLevels {
     fn after_unchecked_add(&mut self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> &Self {
         self.set_with(
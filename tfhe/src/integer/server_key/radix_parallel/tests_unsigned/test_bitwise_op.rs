@@ -22,6 +22,43 @@ create_parameterized_test!(integer_unchecked_bitand);
 create_parameterized_test!(integer_unchecked_bitor);
 create_parameterized_test!(integer_unchecked_bitnot);
 create_parameterized_test!(integer_unchecked_bitxor);
+#[cfg(feature = "pbs-stats")]
+create_parameterized_test!(integer_unchecked_bitand_pbs_count);
+
+#[cfg(feature = "pbs-stats")]
+fn integer_unchecked_bitand_pbs_count<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    use crate::assert_pbs_count;
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::integer::server_key::radix_parallel::tests_cases_unsigned::NB_CTXT;
+    use crate::integer::{IntegerKeyKind, RadixClientKey};
+
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = &sks;
+
+    let ct_0 = cks.encrypt(0u64);
+    let ct_1 = cks.encrypt(u64::MAX);
+
+    // One bivariate PBS per block
+    let ct_res = assert_pbs_count(
+        || sks.unchecked_bitand_parallelized(&ct_0, &ct_1),
+        NB_CTXT as u64,
+    );
+    let dec: u64 = cks.decrypt(&ct_res);
+    assert_eq!(dec, 0);
+
+    // Adding clean ciphertexts does not need any PBS
+    let _ = assert_pbs_count(|| sks.unchecked_add_parallelized(&ct_0, &ct_1), 0);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        assert_pbs_count(|| sks.unchecked_bitand_parallelized(&ct_0, &ct_1), 0)
+    }));
+    assert!(result.is_err());
+}
 
 fn integer_smart_bitand<P>(param: P)
 where
@@ -15,6 +15,8 @@ use std::sync::Arc;
 
 create_parameterized_test!(integer_smart_if_then_else);
 create_parameterized_test!(integer_default_if_then_else);
+#[cfg(feature = "pbs-stats")]
+create_parameterized_test!(integer_if_then_else_pbs_count);
 
 fn integer_smart_if_then_else<P>(param: P)
 where
@@ -35,6 +37,63 @@ where
     let executor = CpuFunctionExecutor::new(&func);
     default_if_then_else_test(param, executor);
 }
+
+#[cfg(feature = "pbs-stats")]
+fn integer_if_then_else_pbs_count<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    use crate::assert_pbs_count;
+    use crate::shortint::server_key::pbs_stats::count_pbs_in_scope;
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = &sks;
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    let clear_0 = rng.gen::<u64>() % modulus;
+    let clear_1 = rng.gen::<u64>() % modulus;
+
+    let ctxt_0 = cks.encrypt(clear_0);
+    let ctxt_1 = cks.encrypt(clear_1);
+
+    // The result of a comparison is a clean boolean block, so the cmux
+    // costs no more than the selection itself, without refreshing the condition
+    let condition = sks.ge_parallelized(&ctxt_0, &ctxt_1);
+    assert!(condition.as_ref().carry_is_empty());
+    assert!(condition.as_ref().degree.get() <= 1);
+
+    let (_, selection_pbs) = count_pbs_in_scope(|| {
+        sks.unchecked_if_then_else_parallelized(&condition, &ctxt_0, &ctxt_1)
+    });
+    let ct_res = assert_pbs_count(
+        || sks.if_then_else_parallelized(&condition, &ctxt_0, &ctxt_1),
+        selection_pbs,
+    );
+    let dec_res: u64 = cks.decrypt(&ct_res);
+    assert_eq!(dec_res, clear_0.max(clear_1));
+
+    // The comparison result is known from the clear value, so it is trivial,
+    // the cmux used to pay for the whole selection and now does not need any PBS
+    let condition = sks.scalar_ge_parallelized(&ctxt_0, modulus);
+    assert!(condition.is_trivial());
+
+    let (_, selection_pbs) = count_pbs_in_scope(|| {
+        sks.unchecked_if_then_else_parallelized(&condition, &ctxt_0, &ctxt_1)
+    });
+    assert!(selection_pbs > 0);
+    let ct_res = assert_pbs_count(
+        || sks.if_then_else_parallelized(&condition, &ctxt_0, &ctxt_1),
+        0,
+    );
+    let dec_res: u64 = cks.decrypt(&ct_res);
+    assert_eq!(dec_res, clear_1);
+}
+
 pub(crate) fn smart_if_then_else_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
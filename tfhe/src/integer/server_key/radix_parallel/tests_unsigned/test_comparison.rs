@@ -3,7 +3,9 @@ use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
-use crate::integer::server_key::radix_parallel::tests_unsigned::{CpuFunctionExecutor, NB_CTXT};
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor, NB_CTXT,
+};
 use crate::integer::tests::create_parameterized_test;
 use crate::integer::{BooleanBlock, IntegerKeyKind, RadixClientKey, ServerKey, U256};
 #[cfg(tarpaulin)]
@@ -760,6 +762,115 @@ mod coverage {
     define_comparison_test_functions!(ge, u8);
 }
 
+create_parameterized_test!(integer_compare_split);
+
+fn integer_compare_split<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    // Each half holds 32 bits, so that the split values are 64 bits
+    let msg_bits = cks.parameters().message_modulus().0.ilog2() as usize;
+    let num_blocks = 32usize.div_ceil(msg_bits);
+    let cks = RadixClientKey::from((cks, num_blocks));
+
+    let mut rng = rand::thread_rng();
+
+    let encrypt_split = |value: u64| {
+        (
+            cks.encrypt(value >> 32),
+            cks.encrypt(value & u64::from(u32::MAX)),
+        )
+    };
+
+    for _ in 0..nb_tests {
+        let a = rng.gen::<u64>();
+        // Also test values that only differ in one of the halves
+        let b = match rng.gen_range(0..3) {
+            0 => rng.gen::<u64>(),
+            1 => (a & !u64::from(u32::MAX)) | u64::from(rng.gen::<u32>()),
+            _ => (a & u64::from(u32::MAX)) | (u64::from(rng.gen::<u32>()) << 32),
+        };
+
+        let (a_hi, a_lo) = encrypt_split(a);
+        let (b_hi, b_lo) = encrypt_split(b);
+
+        let ct_res = sks.compare_split_parallelized(&a_hi, &a_lo, &b_hi, &b_lo);
+        assert_eq!(
+            cks.decrypt_bool(&ct_res),
+            a < b,
+            "Invalid result for {a} < {b}"
+        );
+
+        let ct_res = sks.compare_split_parallelized(&a_hi, &a_lo, &a_hi, &a_lo);
+        assert!(!cks.decrypt_bool(&ct_res), "Invalid result for {a} < {a}");
+    }
+}
+
+create_parameterized_test!(integer_compare_and_swap);
+
+fn integer_compare_and_swap<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let a = rng.gen::<u64>() % modulus;
+        // Also test equal values, which must not be swapped
+        let b = if rng.gen_bool(0.25) {
+            a
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let mut ct_a = cks.encrypt(a);
+        let mut ct_b = cks.encrypt(b);
+
+        let swapped = sks.compare_and_swap_parallelized(&mut ct_a, &mut ct_b);
+        assert!(ct_a.block_carries_are_empty());
+        assert!(ct_b.block_carries_are_empty());
+
+        let min: u64 = cks.decrypt(&ct_a);
+        let max: u64 = cks.decrypt(&ct_b);
+        assert_eq!(min, a.min(b), "Invalid min for compare_and_swap({a}, {b})");
+        assert_eq!(max, a.max(b), "Invalid max for compare_and_swap({a}, {b})");
+        assert_eq!(
+            cks.decrypt_bool(&swapped),
+            a > b,
+            "Invalid swap flag for compare_and_swap({a}, {b})"
+        );
+
+        // Non clean inputs
+        let c = rng.gen::<u64>() % modulus;
+        let mut ct_a = sks.unchecked_scalar_add(&cks.encrypt(a), c);
+        let mut ct_b = cks.encrypt(b);
+        let a = (a + c) % modulus;
+
+        let swapped = sks.compare_and_swap_parallelized(&mut ct_a, &mut ct_b);
+        let min: u64 = cks.decrypt(&ct_a);
+        let max: u64 = cks.decrypt(&ct_b);
+        assert_eq!(min, a.min(b), "Invalid min for compare_and_swap({a}, {b})");
+        assert_eq!(max, a.max(b), "Invalid max for compare_and_swap({a}, {b})");
+        assert_eq!(
+            cks.decrypt_bool(&swapped),
+            a > b,
+            "Invalid swap flag for compare_and_swap({a}, {b})"
+        );
+    }
+}
+
 create_parameterized_test!(integer_extensive_trivial_default_comparisons);
 
 fn integer_extensive_trivial_default_comparisons(params: impl Into<PBSParameters>) {
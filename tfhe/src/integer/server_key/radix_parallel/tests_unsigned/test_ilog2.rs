@@ -29,6 +29,13 @@ create_parameterized_test!(integer_default_checked_ilog2 {
     V1_0_PARAM_MULTI_BIT_GROUP_2_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64,
     V1_0_PARAM_MULTI_BIT_GROUP_3_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64
 });
+create_parameterized_test!(integer_default_log_bucket {
+    // This uses comparisons, so require more than 1 bit
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    V1_0_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+    V1_0_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+    V1_0_PARAM_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64
+});
 
 fn integer_default_trailing_zeros<P>(param: P)
 where
@@ -78,6 +85,14 @@ where
     default_checked_ilog2_test(param, executor);
 }
 
+fn integer_default_log_bucket<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::log_bucket_parallelized);
+    default_log_bucket_test(param, executor);
+}
+
 pub(crate) fn default_count_consecutive_bits_test<P, T>(
     direction: Direction,
     bit_value: BitValue,
@@ -503,3 +518,64 @@ where
         assert!(is_ok);
     }
 }
+
+pub(crate) fn default_log_bucket_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    let num_bits = NB_CTXT as u32 * cks.parameters().message_modulus().0.ilog2();
+
+    // Each bucket boundary, the value just below it, and zero
+    let input_values = std::iter::once(0u64)
+        .chain((0..num_bits).flat_map(|i| [1u64 << i, (1u64 << i).saturating_sub(1)]))
+        .chain(std::iter::once(modulus - 1))
+        .chain((0..nb_tests_smaller).map(|_| rng.gen_range(0..modulus)))
+        .collect::<Vec<_>>();
+
+    for clear in input_values {
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted_result: u32 = cks.decrypt(&ct_res);
+        let expected_result = clear.checked_ilog2().unwrap_or(0);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for log_bucket of {clear}, \
+             expected {expected_result}, got {decrypted_result}"
+        );
+
+        // Non clean input
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        let ctxt = sks.unchecked_scalar_add(&ctxt, clear_2);
+        let clear = clear.wrapping_add(clear_2) % modulus;
+
+        let ct_res = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted_result: u32 = cks.decrypt(&ct_res);
+        let expected_result = clear.checked_ilog2().unwrap_or(0);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for log_bucket of {clear}, \
+             expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::NB_CTXT;
+use crate::integer::server_key::radix_parallel::tests_unsigned::nb_tests_smaller_for_params;
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::current_params::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_parameterized_test!(integer_map_blocks);
+
+fn integer_map_blocks<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = cks.parameters().message_modulus().0;
+    // message_modulus^vec_length
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    let lut = sks.key.generate_lookup_table(|x| (x + 1) % message_modulus);
+
+    for _ in 0..nb_tests {
+        let clear = rng.gen::<u64>() % modulus;
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res =
+            sks.map_blocks_parallelized(&ctxt, |block| sks.key.apply_lookup_table(block, &lut));
+        assert_eq!(ct_res.blocks.len(), ctxt.blocks.len());
+
+        // Each block is incremented independently, without carries
+        let mut expected = 0;
+        for i in (0..NB_CTXT).rev() {
+            let digit = (clear / message_modulus.pow(i as u32)) % message_modulus;
+            expected = expected * message_modulus + (digit + 1) % message_modulus;
+        }
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, expected, "Invalid result for {clear}");
+    }
+}
@@ -0,0 +1,109 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::NB_CTXT;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, random_non_zero_value,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::current_params::*;
+use crate::shortint::parameters::*;
+use rand::prelude::*;
+
+create_parameterized_test!(integer_mod_inverse);
+create_parameterized_test!(integer_increment_mod);
+
+const PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 31, 61, 127, 251, 8191, 65521];
+
+fn integer_mod_inverse<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    let primes = PRIMES
+        .iter()
+        .copied()
+        .filter(|p| *p <= modulus)
+        .collect::<Vec<_>>();
+
+    for _ in 0..nb_tests {
+        let prime = *primes.choose(&mut rng).unwrap();
+        let clear = random_non_zero_value(&mut rng, prime);
+
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.mod_inverse_parallelized(&ctxt, prime);
+        let inverse: u64 = cks.decrypt(&ct_res);
+
+        assert!(
+            inverse < prime,
+            "Inverse {inverse} is not reduced modulo {prime}"
+        );
+        assert_eq!(
+            (clear * inverse) % prime,
+            1,
+            "Invalid inverse of {clear} modulo {prime}, got {inverse}"
+        );
+    }
+
+    // Zero has no inverse, zero is returned
+    let prime = *primes.choose(&mut rng).unwrap();
+    let ctxt = cks.encrypt(0u64);
+    let ct_res = sks.mod_inverse_parallelized(&ctxt, prime);
+    let inverse: u64 = cks.decrypt(&ct_res);
+    assert_eq!(
+        inverse, 0,
+        "Invalid inverse of 0 modulo {prime}, got {inverse}"
+    );
+}
+
+fn integer_increment_mod<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let ct_modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let modulus = random_non_zero_value(&mut rng, ct_modulus);
+        let clear = rng.gen_range(0..modulus);
+
+        let mut ctxt = cks.encrypt(clear);
+        sks.increment_mod_parallelized(&mut ctxt, modulus);
+        let dec: u64 = cks.decrypt(&ctxt);
+        assert_eq!(
+            dec,
+            (clear + 1) % modulus,
+            "Invalid increment of {clear} modulo {modulus}"
+        );
+    }
+
+    // Incrementing past the modulus wraps to zero, then keeps counting
+    for modulus in [1, 3, ct_modulus - 1, ct_modulus] {
+        let mut ctxt = cks.encrypt(modulus - 1);
+        sks.increment_mod_parallelized(&mut ctxt, modulus);
+        let dec: u64 = cks.decrypt(&ctxt);
+        assert_eq!(dec, 0, "{} + 1 did not wrap modulo {modulus}", modulus - 1);
+
+        sks.increment_mod_parallelized(&mut ctxt, modulus);
+        let dec: u64 = cks.decrypt(&ctxt);
+        assert_eq!(dec, 1 % modulus, "Invalid increment of 0 modulo {modulus}");
+    }
+}
@@ -1,15 +1,19 @@
+use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_default_block_mul_test, default_mul_test, default_overflowing_mul_test,
     smart_block_mul_test, smart_mul_test, unchecked_block_mul_test,
     unchecked_mul_corner_cases_test, unchecked_mul_test,
 };
-use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor,
+};
 use crate::integer::tests::create_parameterized_test;
-use crate::integer::ServerKey;
+use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::current_params::*;
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_parameterized_test!(integer_unchecked_mul_corner_cases);
 create_parameterized_test!(integer_unchecked_block_mul);
@@ -19,6 +23,7 @@ create_parameterized_test!(integer_smart_mul);
 create_parameterized_test!(integer_default_mul);
 create_parameterized_test!(integer_default_unsigned_overflowing_mul);
 create_parameterized_test!(integer_unchecked_mul);
+create_parameterized_test!(integer_karatsuba_mul);
 
 fn integer_unchecked_mul<P>(param: P)
 where
@@ -83,3 +88,62 @@ where
     let executor = CpuFunctionExecutor::new(&ServerKey::block_mul_parallelized);
     default_default_block_mul_test(param, executor);
 }
+
+fn integer_karatsuba_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let num_blocks = 8;
+    let cks = RadixClientKey::from((cks, num_blocks));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(num_blocks as u32);
+
+    // 4 recurses down to the smallest splittable size,
+    // 8 is the crossover where only the top level is split,
+    // 9 is below the crossover and falls back to the schoolbook multiplication
+    for threshold in [4, num_blocks, num_blocks + 1] {
+        for _ in 0..nb_tests {
+            let clear_0 = rng.gen::<u64>() % modulus;
+            let clear_1 = rng.gen::<u64>() % modulus;
+
+            let ct_0 = cks.encrypt(clear_0);
+            let ct_1 = cks.encrypt(clear_1);
+
+            let ct_res = sks.unchecked_karatsuba_mul_parallelized(&ct_0, &ct_1, threshold);
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(ct_res.blocks.len(), num_blocks);
+
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            let expected = clear_0.wrapping_mul(clear_1) % modulus;
+            assert_eq!(
+                dec_res, expected,
+                "Invalid karatsuba mul result for {clear_0} * {clear_1} (threshold {threshold})"
+            );
+
+            let schoolbook_res = sks.unchecked_mul_parallelized(&ct_0, &ct_1);
+            let dec_schoolbook: u64 = cks.decrypt(&schoolbook_res);
+            assert_eq!(dec_res, dec_schoolbook);
+        }
+    }
+
+    // The default version cleans the carries of its inputs
+    let clear_0 = rng.gen::<u64>() % modulus;
+    let clear_1 = rng.gen::<u64>() % modulus;
+
+    let ct_0 = cks.encrypt(clear_0);
+    let ct_1 = cks.encrypt(clear_1);
+    let dirty = sks.unchecked_add(&ct_0, &ct_1);
+
+    let ct_res = sks.karatsuba_mul_parallelized(&dirty, &ct_1, 4);
+    assert!(ct_res.block_carries_are_empty());
+    let dec_res: u64 = cks.decrypt(&ct_res);
+    let expected = ((clear_0 + clear_1) % modulus).wrapping_mul(clear_1) % modulus;
+    assert_eq!(dec_res, expected);
+}
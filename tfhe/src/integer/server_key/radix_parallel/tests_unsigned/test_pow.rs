@@ -0,0 +1,160 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::NB_CTXT;
+use crate::integer::server_key::radix_parallel::tests_unsigned::nb_tests_smaller_for_params;
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::current_params::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_parameterized_test!(integer_scalar_pow);
+create_parameterized_test!(integer_pow);
+create_parameterized_test!(integer_pow_mod);
+
+fn integer_scalar_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    for exponent in [0u32, 1] {
+        let clear = rng.gen::<u64>() % modulus;
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.scalar_pow_parallelized(&ctxt, exponent);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            clear.wrapping_pow(exponent) % modulus,
+            "Invalid result for {clear}.pow({exponent})"
+        );
+    }
+
+    for _ in 0..nb_tests {
+        let clear = rng.gen::<u64>() % modulus;
+        let exponent = rng.gen_range(2..32u32);
+
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.scalar_pow_parallelized(&ctxt, exponent);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            clear.wrapping_pow(exponent) % modulus,
+            "Invalid result for {clear}.pow({exponent})"
+        );
+    }
+}
+
+fn integer_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    // The exponent uses half as many blocks as the base
+    let exponent_num_blocks = NB_CTXT / 2;
+    let exponent_modulus = cks
+        .parameters()
+        .message_modulus()
+        .0
+        .pow(exponent_num_blocks as u32);
+
+    for _ in 0..nb_tests {
+        let clear = rng.gen::<u64>() % modulus;
+        let exponent = rng.gen::<u64>() % exponent_modulus;
+
+        let ctxt = cks.encrypt(clear);
+        let ctxt_exponent = cks.as_ref().encrypt_radix(exponent, exponent_num_blocks);
+
+        let ct_res = sks.pow_parallelized(&ctxt, &ctxt_exponent);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            clear.wrapping_pow(exponent as u32) % modulus,
+            "Invalid result for {clear}.pow({exponent})"
+        );
+    }
+
+    // Exponent of zero
+    let clear = rng.gen::<u64>() % modulus;
+    let ctxt = cks.encrypt(clear);
+    let ctxt_exponent = cks.as_ref().encrypt_radix(0u64, exponent_num_blocks);
+    let ct_res = sks.pow_parallelized(&ctxt, &ctxt_exponent);
+    let dec_res: u64 = cks.decrypt(&ct_res);
+    assert_eq!(dec_res, 1, "Invalid result for {clear}.pow(0)");
+}
+
+fn integer_pow_mod<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    // The exponent uses half as many blocks as the base
+    let exponent_num_blocks = NB_CTXT / 2;
+    let exponent_modulus = cks
+        .parameters()
+        .message_modulus()
+        .0
+        .pow(exponent_num_blocks as u32);
+
+    let clear_pow_mod = |base: u64, exponent: u64, m: u64| {
+        (0..exponent).fold(1 % m, |acc, _| {
+            ((acc as u128 * base as u128) % m as u128) as u64
+        })
+    };
+
+    for _ in 0..nb_tests {
+        let clear = rng.gen::<u64>() % modulus;
+        let exponent = rng.gen::<u64>() % exponent_modulus;
+        let m = rng.gen_range(1..=modulus);
+
+        let ctxt = cks.encrypt(clear);
+        let ctxt_exponent = cks.as_ref().encrypt_radix(exponent, exponent_num_blocks);
+
+        let ct_res = sks.pow_mod_parallelized(&ctxt, &ctxt_exponent, m);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            clear_pow_mod(clear, exponent, m),
+            "Invalid result for {clear}.pow({exponent}) % {m}"
+        );
+    }
+
+    // Exponent of zero, and modulus of one
+    let clear = rng.gen::<u64>() % modulus;
+    let ctxt = cks.encrypt(clear);
+    let ctxt_exponent = cks.as_ref().encrypt_radix(0u64, exponent_num_blocks);
+    for m in [1, 7] {
+        let ct_res = sks.pow_mod_parallelized(&ctxt, &ctxt_exponent, m);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, 1 % m, "Invalid result for {clear}.pow(0) % {m}");
+    }
+}
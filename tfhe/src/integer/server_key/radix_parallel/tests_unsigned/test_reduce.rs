@@ -0,0 +1,164 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerCiphertext, IntegerKeyKind, RadixCiphertext, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::current_params::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_parameterized_test!(integer_reduce_ordered_parallelized);
+create_parameterized_test!(integer_smart_fold_seq_parallelized);
+create_parameterized_test!(integer_reduce_ordered_parallelized_in_pool);
+
+fn integer_reduce_ordered_parallelized<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters().message_modulus().0;
+
+    // Concatenating blocks is associative but not commutative,
+    // so any re-ordering of the operands would be visible in the result
+    let concat = |_: &ServerKey, lhs: RadixCiphertext, rhs: RadixCiphertext| {
+        let mut blocks = lhs.blocks;
+        blocks.extend(rhs.blocks);
+        RadixCiphertext::from(blocks)
+    };
+
+    for len in [0, 1, 2, 3, 7, 16, 17] {
+        let clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let cts = clears
+            .iter()
+            .map(|clear| RadixCiphertext::from(vec![cks.encrypt_one_block(*clear)]))
+            .collect::<Vec<_>>();
+
+        let result = sks.reduce_ordered_parallelized(cts, RadixCiphertext::from(vec![]), concat);
+
+        assert_eq!(result.blocks.len(), len);
+        let decrypted = result
+            .blocks
+            .iter()
+            .map(|block| cks.decrypt_one_block(block))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            decrypted, clears,
+            "Invalid order for reduction of {len} items"
+        );
+    }
+}
+
+fn integer_smart_fold_seq_parallelized<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters().message_modulus().0;
+
+    let concat = |_: &ServerKey, lhs: &mut RadixCiphertext, rhs: &mut RadixCiphertext| {
+        let mut blocks = lhs.blocks().to_vec();
+        blocks.extend_from_slice(rhs.blocks());
+        RadixCiphertext::from(blocks)
+    };
+
+    for len in [0, 1, 2, 3, 7, 16, 17] {
+        let clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let mut cts = clears
+            .iter()
+            .map(|clear| RadixCiphertext::from(vec![cks.encrypt_one_block(*clear)]))
+            .collect::<Vec<_>>();
+
+        let result = sks.smart_fold_seq_parallelized(&cts, concat);
+
+        // Sequential left to right fold
+        let expected = cts.split_first_mut().map(|(first, rest)| {
+            rest.iter_mut()
+                .fold(first.clone(), |mut acc, ct| concat(&sks, &mut acc, ct))
+        });
+
+        match (result, expected) {
+            (None, None) => assert_eq!(len, 0),
+            (Some(result), Some(expected)) => {
+                assert_eq!(result.blocks.len(), len);
+                let decrypted = result
+                    .blocks
+                    .iter()
+                    .map(|block| cks.decrypt_one_block(block))
+                    .collect::<Vec<_>>();
+                let expected = expected
+                    .blocks
+                    .iter()
+                    .map(|block| cks.decrypt_one_block(block))
+                    .collect::<Vec<_>>();
+                assert_eq!(decrypted, expected, "Invalid order for fold of {len} items");
+                assert_eq!(decrypted, clears);
+            }
+            _ => panic!("Fold of {len} items disagrees with the sequential fold"),
+        }
+    }
+}
+
+fn integer_reduce_ordered_parallelized_in_pool<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const POOL_NUM_THREADS: usize = 2;
+    const POOL_THREAD_NAME: &str = "test-reduce-pool";
+
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters().message_modulus().0;
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(POOL_NUM_THREADS)
+        .thread_name(|i| format!("{POOL_THREAD_NAME}-{i}"))
+        .build()
+        .unwrap();
+
+    // Each operation checks that it is executed by one of the pool's threads
+    let add = |sks: &ServerKey, lhs: RadixCiphertext, rhs: RadixCiphertext| {
+        let thread = std::thread::current();
+        assert!(
+            thread
+                .name()
+                .is_some_and(|name| name.starts_with(POOL_THREAD_NAME)),
+            "Operation executed outside of the thread pool, in thread {:?}",
+            thread.name()
+        );
+        assert_eq!(rayon::current_num_threads(), POOL_NUM_THREADS);
+        sks.add_parallelized(&lhs, &rhs)
+    };
+
+    let clears = (0..16)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let cts = clears
+        .iter()
+        .map(|clear| RadixCiphertext::from(vec![cks.encrypt_one_block(*clear)]))
+        .collect::<Vec<_>>();
+
+    let result = sks.run_in_pool(&pool, |sks| {
+        sks.reduce_ordered_parallelized(cts, sks.create_trivial_zero_radix(1), add)
+    });
+
+    let decrypted = cks.decrypt_one_block(&result.blocks[0]);
+    let expected = clears.iter().sum::<u64>() % modulus;
+    assert_eq!(decrypted, expected);
+}
@@ -1,17 +1,22 @@
+use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_overflowing_scalar_add_test, default_scalar_add_test, smart_scalar_add_test,
 };
-use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor,
+};
 use crate::integer::tests::create_parameterized_test;
-use crate::integer::ServerKey;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::current_params::*;
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_parameterized_test!(integer_smart_scalar_add);
 create_parameterized_test!(integer_default_scalar_add);
 create_parameterized_test!(integer_default_overflowing_scalar_add);
+create_parameterized_test!(integer_increment_counter);
 
 fn integer_smart_scalar_add<P>(param: P)
 where
@@ -37,3 +42,57 @@ where
         CpuFunctionExecutor::new(&ServerKey::unsigned_overflowing_scalar_add_parallelized);
     default_overflowing_scalar_add_test(param, executor);
 }
+
+fn integer_increment_counter<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    // Each element of the counter holds at least a byte
+    let bits_per_block = cks.parameters().message_modulus().0.ilog2();
+    let num_blocks = 8u32.div_ceil(bits_per_block);
+    let bits_per_element = num_blocks * bits_per_block;
+    let element_mask = (1u64 << bits_per_element) - 1;
+    let cks = RadixClientKey::from((cks, num_blocks as usize));
+
+    let mut rng = rand::thread_rng();
+
+    // Big-endian: the first element holds the most significant bits
+    let encrypt_counter = |clear: u64, num_elements: u32| {
+        (0..num_elements)
+            .rev()
+            .map(|i| cks.encrypt((clear >> (bits_per_element * i)) & element_mask))
+            .collect::<Vec<_>>()
+    };
+    let decrypt_counter = |counter: &[RadixCiphertext]| {
+        counter.iter().fold(0u64, |acc, ct| {
+            let element: u64 = cks.decrypt(ct);
+            (acc << bits_per_element) | element
+        })
+    };
+
+    // Carry propagation across an element boundary (e.g. 0x00FF -> 0x0100 for bytes)
+    let mut counter = encrypt_counter(element_mask, 2);
+    sks.increment_counter_parallelized(&mut counter);
+    assert_eq!(decrypt_counter(&counter), element_mask + 1);
+
+    // Wraparound at all-ones
+    let num_elements = 4;
+    let counter_modulus = 1u64 << (bits_per_element * num_elements);
+    let mut counter = encrypt_counter(counter_modulus - 1, num_elements);
+    sks.increment_counter_parallelized(&mut counter);
+    assert_eq!(decrypt_counter(&counter), 0);
+
+    for _ in 0..nb_tests {
+        let clear = rng.gen::<u64>() % counter_modulus;
+        let mut counter = encrypt_counter(clear, num_elements);
+        sks.increment_counter_parallelized(&mut counter);
+        assert_eq!(
+            decrypt_counter(&counter),
+            (clear + 1) % counter_modulus,
+            "Invalid result when incrementing counter {clear:#x}"
+        );
+    }
+}
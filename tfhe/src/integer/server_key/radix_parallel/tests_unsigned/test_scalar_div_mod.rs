@@ -14,6 +14,8 @@ use rand::prelude::*;
 use std::sync::Arc;
 
 create_parameterized_test!(integer_default_scalar_div_rem);
+create_parameterized_test!(integer_scalar_div_exhaustive_small);
+create_parameterized_test!(integer_scalar_div_constants);
 
 fn integer_default_scalar_div_rem<P>(param: P)
 where
@@ -23,6 +25,91 @@ where
     default_scalar_div_rem_test(param, executor);
 }
 
+/// Checks every numerator against every non-zero divisor on a small number of blocks,
+/// so that all the rounding cases of the magic multiplier are hit
+///
+/// For message moduli above 4, the divisors are sampled to keep the number of divisions bounded
+fn integer_scalar_div_exhaustive_small<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let num_block = 2;
+    let cks = RadixClientKey::from((cks, num_block));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(num_block as u32);
+
+    let divisors: Vec<u64> = if cks.parameters().message_modulus().0 <= 4 {
+        (1..modulus).collect()
+    } else {
+        [1, 2, modulus - 1]
+            .into_iter()
+            .chain((0..nb_tests).map(|_| rng.gen_range(1..modulus)))
+            .collect()
+    };
+
+    for clear in 0..modulus {
+        let ct = cks.encrypt(clear);
+        for &divisor in &divisors {
+            let ct_res = sks.scalar_div_parallelized(&ct, divisor);
+            assert!(ct_res.block_carries_are_empty());
+
+            let dec: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec,
+                clear / divisor,
+                "Invalid result for {clear} / {divisor}, got {dec}"
+            );
+        }
+    }
+}
+
+fn integer_scalar_div_constants<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let num_block =
+        (32f64 / (cks.parameters().message_modulus().0 as f64).log(2.0)).ceil() as usize;
+    let cks = RadixClientKey::from((cks, num_block));
+
+    let mut rng = rand::thread_rng();
+
+    // 3 and 7 need the rounding-up path of the multiplier (7 needs the extra add),
+    // 10 is even and gets pre-shifted
+    for divisor in [3u32, 7, 10] {
+        for clear in [0, 1, divisor - 1, divisor, u32::MAX - 1, u32::MAX]
+            .into_iter()
+            .chain((0..nb_tests).map(|_| rng.gen::<u32>()))
+        {
+            let ct = cks.encrypt(clear);
+
+            let ct_res = sks.scalar_div_parallelized(&ct, divisor);
+            let dec: u32 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec,
+                clear / divisor,
+                "Invalid result for {clear} / {divisor}, got {dec}"
+            );
+        }
+    }
+
+    let result = std::panic::catch_unwind(|| {
+        let ct = sks.create_trivial_radix(1u32, num_block);
+        sks.scalar_div_parallelized(&ct, 0u32)
+    });
+    assert!(result.is_err(), "division by zero should panic");
+}
+
 pub(crate) fn default_scalar_div_rem_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
@@ -1,14 +1,16 @@
+use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_scalar_mul_test, default_scalar_mul_u128_fix_non_reg_test, smart_scalar_mul_test,
     smart_scalar_mul_u128_fix_non_reg_test, unchecked_scalar_mul_corner_cases_test,
 };
-use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{CpuFunctionExecutor, NB_CTXT};
 use crate::integer::tests::create_parameterized_test;
-use crate::integer::ServerKey;
+use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::current_params::*;
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_parameterized_test!(
     integer_smart_scalar_mul_u128_fix_non_reg_test {
@@ -34,6 +36,7 @@ create_parameterized_test!(
 );
 create_parameterized_test!(integer_smart_scalar_mul);
 create_parameterized_test!(integer_default_scalar_mul);
+create_parameterized_test!(integer_scalar_mul_vec);
 
 fn integer_unchecked_scalar_mul_corner_cases<P>(param: P)
 where
@@ -74,3 +77,42 @@ where
     let executor = CpuFunctionExecutor::new(&ServerKey::scalar_mul_parallelized);
     default_scalar_mul_test(param, executor);
 }
+
+fn integer_scalar_mul_vec<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    for len in [0, 1, 5] {
+        let clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let factors = (0..len).map(|_| rng.gen::<u64>()).collect::<Vec<_>>();
+
+        let cts = clears.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+
+        let cts_res = sks.scalar_mul_vec_parallelized(&cts, &factors);
+        assert_eq!(cts_res.len(), len);
+
+        for ((ct_res, clear), factor) in cts_res.iter().zip(clears).zip(factors) {
+            assert!(ct_res.block_carries_are_empty());
+
+            let dec: u64 = cks.decrypt(ct_res);
+            assert_eq!(dec, clear.wrapping_mul(factor) % modulus);
+        }
+    }
+
+    let result = std::panic::catch_unwind(|| {
+        let cts = vec![cks.encrypt(1u64)];
+        sks.scalar_mul_vec_parallelized(&cts, &[1, 2])
+    });
+    assert!(result.is_err(), "length mismatch should panic");
+}
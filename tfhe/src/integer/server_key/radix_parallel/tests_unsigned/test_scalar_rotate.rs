@@ -16,6 +16,65 @@ create_parameterized_test!(integer_unchecked_scalar_rotate_left);
 create_parameterized_test!(integer_default_scalar_rotate_left);
 create_parameterized_test!(integer_unchecked_scalar_rotate_right);
 create_parameterized_test!(integer_default_scalar_rotate_right);
+#[cfg(feature = "pbs-stats")]
+create_parameterized_test!(integer_block_aligned_scalar_rotate_pbs_count);
+
+#[cfg(feature = "pbs-stats")]
+fn integer_block_aligned_scalar_rotate_pbs_count<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    use crate::assert_pbs_count;
+
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = &sks;
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+    let nb_bits = modulus.ilog2();
+    let bits_per_block = cks.parameters().message_modulus().0.ilog2();
+
+    for _ in 0..nb_tests {
+        let clear = rng.gen::<u64>() % modulus;
+        let num_blocks = rng.gen_range(0..2 * NB_CTXT as u32);
+        let scalar = (num_blocks * bits_per_block) as u64;
+
+        let ct = cks.encrypt(clear);
+
+        let ct_res = assert_pbs_count(|| sks.scalar_rotate_left_parallelized(&ct, scalar), 0);
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted_result,
+            rotate_left_helper(clear, scalar as u32, nb_bits)
+        );
+
+        let ct_res = assert_pbs_count(|| sks.scalar_rotate_right_parallelized(&ct, scalar), 0);
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted_result,
+            rotate_right_helper(clear, scalar as u32, nb_bits)
+        );
+
+        let ct_res = sks.rotate_left_blocks(&ct, num_blocks as usize);
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted_result,
+            rotate_left_helper(clear, scalar as u32, nb_bits)
+        );
+
+        let ct_res = sks.rotate_right_blocks(&ct, num_blocks as usize);
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted_result,
+            rotate_right_helper(clear, scalar as u32, nb_bits)
+        );
+    }
+}
 
 fn integer_default_scalar_rotate_left<P>(param: P)
 where
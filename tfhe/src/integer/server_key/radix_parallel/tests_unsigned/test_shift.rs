@@ -1,20 +1,23 @@
+use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_left_shift_test, default_right_shift_test, unchecked_left_shift_test,
     unchecked_right_shift_test,
 };
 use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
 use crate::integer::tests::create_parameterized_test;
-use crate::integer::ServerKey;
+use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::current_params::*;
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_parameterized_test!(integer_unchecked_left_shift);
 
 create_parameterized_test!(integer_unchecked_right_shift);
 create_parameterized_test!(integer_left_shift);
 create_parameterized_test!(integer_right_shift);
+create_parameterized_test!(integer_shift_edge_cases);
 
 fn integer_unchecked_right_shift<P>(param: P)
 where
@@ -47,3 +50,46 @@ where
     let executor = CpuFunctionExecutor::new(&ServerKey::left_shift_parallelized);
     default_left_shift_test(param, executor);
 }
+
+/// Compares shifts of a 32 bits integer to the native wrapping shifts,
+/// including the amounts at and around the bit width
+fn integer_shift_edge_cases<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let bits_per_block = param.message_modulus().0.ilog2();
+    if u32::BITS % bits_per_block != 0 {
+        // The ciphertext would not have exactly 32 bits
+        return;
+    }
+    let num_blocks = (u32::BITS / bits_per_block) as usize;
+    let cks = RadixClientKey::from((cks, num_blocks));
+
+    let mut rng = rand::thread_rng();
+
+    let clear = rng.gen::<u32>();
+    let ct = cks.encrypt(clear);
+
+    for clear_shift in [0u32, 1, 31, 32, 33, 63] {
+        let shift = cks.encrypt(clear_shift);
+
+        let ct_res = sks.left_shift_parallelized(&ct, &shift);
+        let dec: u32 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec,
+            clear.wrapping_shl(clear_shift),
+            "Invalid left shift of {clear} by {clear_shift}"
+        );
+
+        let ct_res = sks.right_shift_parallelized(&ct, &shift);
+        let dec: u32 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec,
+            clear.wrapping_shr(clear_shift),
+            "Invalid right shift of {clear} by {clear_shift}"
+        );
+    }
+}
@@ -20,6 +20,7 @@ use super::MAX_NB_CTXT;
 
 create_parameterized_test!(integer_unchecked_sub);
 create_parameterized_test!(integer_smart_sub);
+create_parameterized_test!(integer_smart_sub_assign);
 create_parameterized_test!(integer_default_sub);
 create_parameterized_test!(integer_extensive_trivial_default_sub);
 create_parameterized_test!(integer_default_overflowing_sub);
@@ -58,6 +59,19 @@ where
     smart_sub_test(param, executor);
 }
 
+fn integer_smart_sub_assign<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey, lhs: &mut RadixCiphertext, rhs: &mut RadixCiphertext| {
+        let mut result = lhs.clone();
+        sks.smart_sub_assign_parallelized(&mut result, rhs);
+        result
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    smart_sub_test(param, executor);
+}
+
 fn integer_default_sub<P>(param: P)
 where
     P: Into<PBSParameters>,
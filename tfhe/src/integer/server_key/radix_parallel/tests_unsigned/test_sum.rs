@@ -15,6 +15,10 @@ use std::sync::Arc;
 create_parameterized_test!(integer_smart_sum_ciphertexts_slice);
 create_parameterized_test!(integer_default_unsigned_overflowing_sum_ciphertexts_vec);
 create_parameterized_test!(integer_default_sum_ciphertexts_vec);
+create_parameterized_test!(integer_sum_with_overflow);
+create_parameterized_test!(integer_moving_average);
+#[cfg(feature = "pbs-stats")]
+create_parameterized_test!(integer_sum_ciphertexts_pbs_count);
 
 fn integer_default_unsigned_overflowing_sum_ciphertexts_vec<P>(param: P)
 where
@@ -218,3 +222,145 @@ where
         }
     }
 }
+
+fn integer_sum_with_overflow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    let cases = [
+        // Does not overflow
+        vec![1, 2, modulus - 4],
+        // Reaches the max without overflowing
+        vec![modulus - 1, 0, 0],
+        // Overflows in the middle of the sum
+        vec![modulus - 1, 1, 0, 0],
+        // Overflows more than once, wrapping back to a small value
+        vec![modulus - 1, modulus - 1, 3],
+        (0..5).map(|_| rng.gen::<u64>() % modulus).collect(),
+    ];
+
+    for clears in cases {
+        let mut ctxts = clears
+            .iter()
+            .copied()
+            .map(|clear| cks.encrypt(clear))
+            .collect::<Vec<_>>();
+
+        // Non-empty carries must be handled
+        let zero = cks.encrypt(0u64);
+        sks.unchecked_add_assign(&mut ctxts[1], &zero);
+        assert!(!ctxts[1].block_carries_are_empty());
+
+        let (ct_res, overflow_res) = sks.sum_with_overflow_parallelized(&ctxts);
+
+        let decrypted_res: u64 = cks.decrypt(&ct_res);
+        let decrypted_overflow = cks.decrypt_bool(&overflow_res);
+
+        let (expected_clear, expected_overflow) =
+            overflowing_sum_slice_under_modulus(&clears, modulus);
+
+        assert_eq!(
+            decrypted_res, expected_clear,
+            "Invalid sum of {clears:?}, expected {expected_clear} got {decrypted_res}"
+        );
+        assert_eq!(
+            decrypted_overflow, expected_overflow,
+            "Invalid overflow flag for sum of {clears:?}, expected {expected_overflow} got {decrypted_overflow}"
+        );
+    }
+}
+
+fn integer_moving_average<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    let clears = (0..8)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let ctxts = clears
+        .iter()
+        .copied()
+        .map(|clear| cks.encrypt(clear))
+        .collect::<Vec<_>>();
+
+    for window in [1, 3, 8, 9] {
+        let averages = sks.moving_average_parallelized(&ctxts, window);
+
+        let expected = clears
+            .windows(window)
+            .map(|values| values.iter().sum::<u64>() / window as u64)
+            .collect::<Vec<_>>();
+
+        assert_eq!(averages.len(), expected.len());
+        for (ct_res, expected) in averages.iter().zip(expected.iter()) {
+            assert_eq!(ct_res.blocks.len(), NB_CTXT);
+            let dec_res: u64 = cks.decrypt(ct_res);
+            assert_eq!(
+                dec_res, *expected,
+                "Invalid moving average over a window of {window}"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "pbs-stats")]
+fn integer_sum_ciphertexts_pbs_count<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    use crate::shortint::server_key::pbs_stats::count_pbs_in_scope;
+
+    const NUM_TERMS: usize = 100;
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    let clears = (0..NUM_TERMS)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let ctxts = clears
+        .iter()
+        .copied()
+        .map(|clear| cks.encrypt(clear))
+        .collect::<Vec<_>>();
+
+    // Baseline: chaining NUM_TERMS - 1 additions, each one propagating its carries
+    let (_, add_pbs_count) = count_pbs_in_scope(|| sks.add_parallelized(&ctxts[0], &ctxts[1]));
+    let naive_pbs_count = add_pbs_count * (NUM_TERMS as u64 - 1);
+
+    let (ct_res, pbs_count): (RadixCiphertext, _) =
+        count_pbs_in_scope(|| sks.sum_ciphertexts_parallelized(&ctxts).unwrap());
+
+    let dec_res: u64 = cks.decrypt(&ct_res);
+    let expected = clears.iter().sum::<u64>() % modulus;
+    assert_eq!(dec_res, expected);
+
+    assert!(
+        pbs_count < naive_pbs_count,
+        "Sum of {NUM_TERMS} ciphertexts used {pbs_count} PBS, \
+        which is not less than the {naive_pbs_count} PBS of chained additions"
+    );
+}
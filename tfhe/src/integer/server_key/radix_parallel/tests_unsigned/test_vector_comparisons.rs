@@ -23,6 +23,94 @@ create_parameterized_test!(integer_default_all_eq_slices_test_case);
 
 create_parameterized_test!(integer_unchecked_contains_slice_test_case);
 
+create_parameterized_test!(integer_default_rank_within_test_case);
+
+create_parameterized_test!(integer_default_sort_test_case);
+
+fn integer_default_rank_within_test_case<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param).min(5);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    // Known group, with duplicates
+    let clear_group = [7u64 % modulus, 0, 7 % modulus, modulus - 1, 3 % modulus];
+    let group = clear_group
+        .iter()
+        .map(|v| cks.encrypt(*v))
+        .collect::<Vec<_>>();
+    for clear in [0, 3 % modulus, 7 % modulus, modulus - 1] {
+        let ct = cks.encrypt(clear);
+        let rank = sks.rank_within_parallelized(&ct, &group);
+        assert!(rank.block_carries_are_empty());
+
+        let dec: u64 = cks.decrypt(&rank);
+        let expected = clear_group.iter().filter(|v| **v < clear).count() as u64;
+        assert_eq!(dec, expected, "Invalid rank of {clear} in {clear_group:?}");
+    }
+
+    for _ in 0..nb_tests {
+        let group_len = rng.gen_range(0..=MAX_VEC_LEN);
+        let clear_group = (0..group_len)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        let group = clear_group
+            .iter()
+            .map(|v| cks.encrypt(*v))
+            .collect::<Vec<_>>();
+
+        let clear = rng.gen_range(0..modulus);
+        let ct = cks.encrypt(clear);
+
+        let rank = sks.rank_within_parallelized(&ct, &group);
+        let dec: u64 = cks.decrypt(&rank);
+        let expected = clear_group.iter().filter(|v| **v < clear).count() as u64;
+        assert_eq!(dec, expected, "Invalid rank of {clear} in {clear_group:?}");
+    }
+}
+
+fn integer_default_sort_test_case<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param).min(3);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        // Sorting networks are costly, keep the length small
+        let len = rng.gen_range(0..=MAX_VEC_LEN.min(9));
+        let clears = (0..len)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        let mut cts = clears.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+        // Some non clean inputs
+        if let Some(ct) = cts.first_mut() {
+            sks.unchecked_add_assign(ct, &cks.encrypt(0u64));
+        }
+
+        sks.sort_parallelized(&mut cts);
+        assert!(cts.iter().all(|ct| ct.block_carries_are_empty()));
+
+        let dec = cts.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+        let mut expected = clears.clone();
+        expected.sort_unstable();
+        assert_eq!(dec, expected, "Invalid sort of {clears:?}");
+    }
+}
+
 fn integer_unchecked_all_eq_slices_test_case<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -41,6 +41,9 @@ create_parameterized_test!(integer_default_index_of);
 create_parameterized_test!(integer_default_index_of_clear);
 create_parameterized_test!(integer_default_first_index_of);
 create_parameterized_test!(integer_default_first_index_of_clear);
+create_parameterized_test!(integer_default_select_clamped);
+create_parameterized_test!(integer_default_match);
+create_parameterized_test!(integer_default_argmin_with_value);
 
 fn integer_unchecked_match_value<P>(param: P)
 where
@@ -222,6 +225,173 @@ where
     default_first_index_of_clear_test_case(param, executor);
 }
 
+fn integer_default_select_clamped<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param).min(5);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..=MAX_VEC_LEN.min(modulus as usize));
+        let clears = (0..num_values)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        let values = clears
+            .iter()
+            .map(|clear| cks.encrypt(*clear))
+            .collect::<Vec<_>>();
+
+        // In range, the last valid index and any index (likely out of range)
+        for clear_index in [
+            rng.gen_range(0..num_values as u64),
+            num_values as u64 - 1,
+            rng.gen_range(0..modulus),
+        ] {
+            let index = cks.encrypt(clear_index);
+            let result = sks.select_clamped_parallelized(&index, &values);
+            panic_if_any_block_is_not_clean_or_trivial(&result, &cks);
+
+            let decrypted: u64 = cks.decrypt(&result);
+            let expected = clears[(clear_index as usize).min(num_values - 1)];
+            assert_eq!(
+                decrypted, expected,
+                "Invalid result for index {clear_index} in a slice of {num_values} values"
+            );
+        }
+    }
+
+    // The index is larger than the length of the slice
+    let clears = [3u64, 1, 2];
+    let values = clears
+        .iter()
+        .map(|clear| cks.encrypt(*clear))
+        .collect::<Vec<_>>();
+    let index = cks.encrypt(modulus - 1);
+    let result = sks.select_clamped_parallelized(&index, &values);
+    let decrypted: u64 = cks.decrypt(&result);
+    assert_eq!(decrypted, 2);
+}
+
+fn integer_default_match<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param).min(5);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let num_arms = rng.gen_range(1..=MAX_VEC_LEN.min(modulus as usize));
+        let mut keys = HashSet::new();
+        while keys.len() < num_arms {
+            keys.insert(rng.gen_range(0..modulus));
+        }
+        let clear_arms = keys
+            .into_iter()
+            .map(|key| (key, rng.gen_range(0..modulus)))
+            .collect::<Vec<_>>();
+        let arms = clear_arms
+            .iter()
+            .map(|(key, value)| (*key, cks.encrypt(*value)))
+            .collect::<Vec<_>>();
+        let clear_default = rng.gen_range(0..modulus);
+        let default = cks.encrypt(clear_default);
+
+        // A selector hitting one of the arms, and a random one
+        let hit = clear_arms[rng.gen_range(0..num_arms)].0;
+        for clear_selector in [hit, rng.gen_range(0..modulus)] {
+            let selector = cks.encrypt(clear_selector);
+            let result = sks.match_parallelized(&selector, &arms, &default).unwrap();
+            panic_if_any_block_is_not_clean_or_trivial(&result, &cks);
+
+            let decrypted: u64 = cks.decrypt(&result);
+            let expected = clear_arms
+                .iter()
+                .find(|(key, _)| *key == clear_selector)
+                .map_or(clear_default, |(_, value)| *value);
+            assert_eq!(
+                decrypted, expected,
+                "Invalid match result for selector {clear_selector}"
+            );
+        }
+    }
+
+    // Selector hitting the default, with a key that cannot be encrypted by the selector
+    let arms = vec![
+        (1u64, cks.encrypt(1u64)),
+        (2u64, cks.encrypt(2u64)),
+        (modulus, cks.encrypt(3u64)),
+    ];
+    let default = cks.encrypt(modulus - 1);
+    let selector = cks.encrypt(0u64);
+    let result = sks.match_parallelized(&selector, &arms, &default).unwrap();
+    let decrypted: u64 = cks.decrypt(&result);
+    assert_eq!(decrypted, modulus - 1);
+
+    // Duplicated keys are rejected
+    let arms = vec![(1u64, cks.encrypt(1u64)), (1u64, cks.encrypt(2u64))];
+    assert!(sks.match_parallelized(&selector, &arms, &default).is_err());
+}
+
+fn integer_default_argmin_with_value<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param).min(5);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..=MAX_VEC_LEN);
+        // Use a small range of values half of the time, so that ties are likely
+        let max_value = if rng.gen_bool(0.5) { modulus } else { 4 };
+        let mut clears = (0..num_values)
+            .map(|_| rng.gen_range(0..max_value))
+            .collect::<Vec<_>>();
+        let mut cts = clears
+            .iter()
+            .map(|clear| cks.encrypt(*clear))
+            .collect::<Vec<_>>();
+        // No value is out of range, so there are no values to avoid
+        make_one_ciphertext_have_carries(&mut clears, &mut cts, &mut rng, &sks, modulus, modulus);
+
+        let (index, value) = sks.argmin_with_value_parallelized(&cts);
+        panic_if_any_block_is_not_clean_or_trivial(&index, &cks);
+        panic_if_any_block_is_not_clean_or_trivial(&value, &cks);
+
+        let decrypted_index: u64 = cks.decrypt(&index);
+        let decrypted_value: u64 = cks.decrypt(&value);
+
+        let expected_value = *clears.iter().min().unwrap();
+        let expected_index = clears.iter().position(|v| *v == expected_value).unwrap();
+        assert_eq!(
+            decrypted_value, expected_value,
+            "Invalid minimum value for {clears:?}"
+        );
+        assert_eq!(
+            decrypted_index, expected_index as u64,
+            "Invalid minimum index for {clears:?}"
+        );
+    }
+}
+
 /// This function takes a list of ciphertexts and their corresponding clear values
 /// and picks one ciphertext at random to make it so it has carries (via unchecked_add)
 ///
@@ -1,4 +1,4 @@
-use crate::integer::{BooleanBlock, IntegerRadixCiphertext, ServerKey};
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey};
 
 use rayon::prelude::*;
 
@@ -179,4 +179,192 @@ impl ServerKey {
 
         self.unchecked_contains_sub_slice_parallelized(lhs, rhs)
     }
+
+    /// Returns the rank of `ct` within `group`, that is, the number of elements
+    /// of `group` that are strictly smaller than `ct`
+    ///
+    /// - The result is in `0..=group.len()`, and has just enough blocks to encrypt `group.len()`
+    /// - Expects all ciphertexts to have empty carries
+    pub fn unchecked_rank_within_parallelized<T>(&self, ct: &T, group: &[T]) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = self
+            .num_blocks_to_represent_unsigned_value(group.len() as u64)
+            .max(1);
+
+        let is_greater = group
+            .par_iter()
+            .map(|other| {
+                self.unchecked_gt_parallelized(ct, other)
+                    .into_radix(num_blocks, self)
+            })
+            .collect::<Vec<RadixCiphertext>>();
+
+        self.unchecked_sum_ciphertexts_vec_parallelized(is_greater)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks))
+    }
+
+    /// Returns the rank of `ct` within `group`, that is, the number of elements
+    /// of `group` that are strictly smaller than `ct`
+    ///
+    /// - The result is in `0..=group.len()`, and has just enough blocks to encrypt `group.len()`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let group = [12u64, 200, 45, 3]
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let ct = cks.encrypt(45u64);
+    /// let rank = sks.rank_within_parallelized(&ct, &group);
+    ///
+    /// // 45 exceeds 12 and 3
+    /// let dec: u64 = cks.decrypt(&rank);
+    /// assert_eq!(dec, 2);
+    /// ```
+    pub fn rank_within_parallelized<T>(&self, ct: &T, group: &[T]) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_ct;
+        let mut tmp_group;
+
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        let group = if group.iter().all(T::block_carries_are_empty) {
+            group
+        } else {
+            tmp_group = group.to_vec();
+            tmp_group
+                .par_iter_mut()
+                .filter(|radix| !radix.block_carries_are_empty())
+                .for_each(|radix| self.full_propagate_parallelized(radix));
+            tmp_group.as_slice()
+        };
+
+        self.unchecked_rank_within_parallelized(ct, group)
+    }
+
+    /// Sorts the ciphertexts in ascending order using a bitonic sorting network
+    ///
+    /// - Non power of two lengths are handled by padding internally with trivial max values
+    /// - Expects all ciphertexts to have empty carries
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not all have the same number of blocks
+    ///
+    /// See [Self::sort_parallelized] for an example
+    pub fn unchecked_sort_parallelized<T>(&self, cts: &mut [T])
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let Some(num_blocks) = cts.first().map(|ct| ct.blocks().len()) else {
+            return;
+        };
+        assert!(
+            cts.iter().all(|ct| ct.blocks().len() == num_blocks),
+            "All ciphertexts must have the same number of blocks"
+        );
+
+        if cts.len().is_power_of_two() {
+            self.unchecked_bitonic_sort_parallelized(cts);
+        } else {
+            // Padded max values end up at the end, past the input values
+            let mut padded = cts.to_vec();
+            padded.resize(
+                cts.len().next_power_of_two(),
+                self.create_trivial_max_radix(num_blocks),
+            );
+            self.unchecked_bitonic_sort_parallelized(&mut padded);
+            cts.clone_from_slice(&padded[..cts.len()]);
+        }
+    }
+
+    /// Expects the number of ciphertexts to be a power of two
+    fn unchecked_bitonic_sort_parallelized<T>(&self, values: &mut [T])
+    where
+        T: IntegerRadixCiphertext,
+    {
+        debug_assert!(values.len().is_power_of_two());
+
+        // Each stage compares pairs of elements at distance `j` within chunks of size `2j`,
+        // the direction of a pair depends on which block of size `k` it belongs to.
+        // Pairs of a stage are disjoint, so they are all processed in parallel.
+        let mut k = 2;
+        while k <= values.len() {
+            let mut j = k / 2;
+            while j > 0 {
+                values
+                    .par_chunks_exact_mut(2 * j)
+                    .enumerate()
+                    .for_each(|(chunk_index, chunk)| {
+                        let is_ascending = (chunk_index * 2 * j) & k == 0;
+                        let (lo, hi) = chunk.split_at_mut(j);
+                        lo.par_iter_mut().zip(hi.par_iter_mut()).for_each(|(a, b)| {
+                            if is_ascending {
+                                self.unchecked_compare_and_swap_parallelized(a, b);
+                            } else {
+                                self.unchecked_compare_and_swap_parallelized(b, a);
+                            }
+                        });
+                    });
+                j /= 2;
+            }
+            k *= 2;
+        }
+    }
+
+    /// Sorts the ciphertexts in ascending order using a bitonic sorting network
+    ///
+    /// - Non power of two lengths are handled by padding internally with trivial max values
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clears = [12u64, 200, 45, 3, 45];
+    /// let mut cts = clears.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    ///
+    /// sks.sort_parallelized(&mut cts);
+    ///
+    /// let dec = cts.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+    /// assert_eq!(dec, vec![3, 12, 45, 45, 200]);
+    /// ```
+    pub fn sort_parallelized<T>(&self, cts: &mut [T])
+    where
+        T: IntegerRadixCiphertext,
+    {
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        self.unchecked_sort_parallelized(cts);
+    }
 }
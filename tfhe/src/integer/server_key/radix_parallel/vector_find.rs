@@ -1038,6 +1038,397 @@ impl ServerKey {
         self.unchecked_first_index_of_parallelized(cts, value)
     }
 
+    /// Returns the element of `values` at the encrypted `index`, where the index is clamped
+    /// to the last valid position, i.e. this returns `values[min(index, values.len() - 1)]`
+    ///
+    /// Expects all ciphertexts to have empty carries
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty
+    pub fn unchecked_select_clamped_parallelized<T>(
+        &self,
+        index: &RadixCiphertext,
+        values: &[T],
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!values.is_empty(), "Cannot select from an empty slice");
+
+        // Values whose position cannot be encrypted by the index can never be selected
+        let num_index_bits = index.blocks.len() as u32 * self.message_modulus().0.ilog2();
+        let max_index = if num_index_bits >= usize::BITS {
+            usize::MAX
+        } else {
+            (1usize << num_index_bits) - 1
+        };
+        let last_index = (values.len() - 1).min(max_index);
+
+        let (mut selectors, is_at_least_last_index) = rayon::join(
+            || self.compute_equality_selectors(index, (0..last_index as u64).into_par_iter()),
+            || self.unchecked_scalar_ge_parallelized(index, last_index as u64),
+        );
+        selectors.push(is_at_least_last_index);
+
+        let one_hot_vector = values
+            .par_iter()
+            .zip(selectors.par_iter())
+            .map(|(value, selector)| {
+                let mut value = value.clone();
+                self.zero_out_if_condition_is_false(&mut value, &selector.0);
+                value
+            })
+            .collect::<Vec<_>>();
+
+        self.aggregate_unpacked_one_hot_vector(one_hot_vector)
+    }
+
+    /// Returns the element of `values` at the encrypted `index`, where the index is clamped
+    /// to the last valid position, i.e. this returns `values[min(index, values.len() - 1)]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clears = [10u64, 20, 30];
+    /// let values = clears.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    ///
+    /// let index = cks.encrypt(1u64);
+    /// let result = sks.select_clamped_parallelized(&index, &values);
+    /// let dec: u64 = cks.decrypt(&result);
+    /// assert_eq!(dec, 20);
+    ///
+    /// // Out of range index, the last element is returned
+    /// let index = cks.encrypt(200u64);
+    /// let result = sks.select_clamped_parallelized(&index, &values);
+    /// let dec: u64 = cks.decrypt(&result);
+    /// assert_eq!(dec, 30);
+    /// ```
+    pub fn select_clamped_parallelized<T>(&self, index: &RadixCiphertext, values: &[T]) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_values;
+        let mut tmp_index;
+
+        let values = if values.iter().any(|ct| !ct.block_carries_are_empty()) {
+            tmp_values = values.to_vec();
+            tmp_values
+                .par_iter_mut()
+                .filter(|ct| !ct.block_carries_are_empty())
+                .for_each(|ct| self.full_propagate_parallelized(ct));
+            &tmp_values
+        } else {
+            values
+        };
+
+        let index = if index.block_carries_are_empty() {
+            index
+        } else {
+            tmp_index = index.clone();
+            self.full_propagate_parallelized(&mut tmp_index);
+            &tmp_index
+        };
+
+        self.unchecked_select_clamped_parallelized(index, values)
+    }
+
+    /// Returns the value of the arm whose clear key equals the encrypted `selector`,
+    /// or `default` if no key matches, i.e. this is an encrypted `match` statement.
+    ///
+    /// Keys that are too big to be encrypted by `selector` can never match.
+    ///
+    /// Expects all ciphertexts to have empty carries
+    ///
+    /// Returns an error if the same clear key appears in more than one arm
+    ///
+    /// # Panics
+    ///
+    /// Panics if the values of the arms and `default` do not all have the same number of blocks
+    pub fn unchecked_match_parallelized<T, Clear>(
+        &self,
+        selector: &RadixCiphertext,
+        arms: &[(Clear, T)],
+        default: &T,
+    ) -> crate::Result<T>
+    where
+        T: IntegerRadixCiphertext,
+        Clear: UnsignedInteger + DecomposableInto<u64> + CastInto<usize> + Hash,
+    {
+        let mut keys = HashSet::with_capacity(arms.len());
+        for (key, value) in arms {
+            if !keys.insert(key) {
+                return Err(crate::Error::new(
+                    "Keys of the match arms must be unique".to_string(),
+                ));
+            }
+            assert_eq!(
+                value.blocks().len(),
+                default.blocks().len(),
+                "All match arms must have the same number of blocks as the default value"
+            );
+        }
+
+        let reachable_arms = arms
+            .iter()
+            .filter(|(key, _)| {
+                self.num_blocks_to_represent_unsigned_value(*key) <= selector.blocks.len()
+            })
+            .collect::<Vec<_>>();
+
+        if reachable_arms.is_empty() {
+            return Ok(default.clone());
+        }
+
+        let selectors = self
+            .compute_equality_selectors(selector, reachable_arms.par_iter().map(|(key, _)| *key));
+        let is_any_arm_selected = self.is_at_least_one_comparisons_block_true(
+            selectors.iter().map(|s| s.0.clone()).collect(),
+        );
+
+        // Exactly one of the arms or the default keeps its value, all the others are zeroed
+        let (mut one_hot_vector, default) = rayon::join(
+            || {
+                reachable_arms
+                    .par_iter()
+                    .zip(selectors.par_iter())
+                    .map(|((_, value), selector)| {
+                        let mut value = value.clone();
+                        self.zero_out_if_condition_is_false(&mut value, &selector.0);
+                        value
+                    })
+                    .collect::<Vec<_>>()
+            },
+            || {
+                let mut default = default.clone();
+                self.zero_out_if_condition_equals(&mut default, &is_any_arm_selected, 1);
+                default
+            },
+        );
+        one_hot_vector.push(default);
+
+        Ok(self.aggregate_unpacked_one_hot_vector(one_hot_vector))
+    }
+
+    /// Returns the value of the arm whose clear key equals the encrypted `selector`,
+    /// or `default` if no key matches, i.e. this is an encrypted `match` statement.
+    ///
+    /// Keys that are too big to be encrypted by `selector` can never match.
+    ///
+    /// Returns an error if the same clear key appears in more than one arm
+    ///
+    /// # Panics
+    ///
+    /// Panics if the values of the arms and `default` do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let arms = vec![
+    ///     (1u64, cks.encrypt(10u64)),
+    ///     (5u64, cks.encrypt(50u64)),
+    ///     (7u64, cks.encrypt(70u64)),
+    /// ];
+    /// let default = cks.encrypt(255u64);
+    ///
+    /// let selector = cks.encrypt(5u64);
+    /// let result = sks.match_parallelized(&selector, &arms, &default).unwrap();
+    /// let dec: u64 = cks.decrypt(&result);
+    /// assert_eq!(dec, 50);
+    ///
+    /// // No arm matches, the default is returned
+    /// let selector = cks.encrypt(3u64);
+    /// let result = sks.match_parallelized(&selector, &arms, &default).unwrap();
+    /// let dec: u64 = cks.decrypt(&result);
+    /// assert_eq!(dec, 255);
+    /// ```
+    pub fn match_parallelized<T, Clear>(
+        &self,
+        selector: &RadixCiphertext,
+        arms: &[(Clear, T)],
+        default: &T,
+    ) -> crate::Result<T>
+    where
+        T: IntegerRadixCiphertext,
+        Clear: UnsignedInteger + DecomposableInto<u64> + CastInto<usize> + Hash,
+    {
+        let mut tmp_arms;
+        let mut tmp_selector;
+        let mut tmp_default;
+
+        let arms = if arms.iter().any(|(_, ct)| !ct.block_carries_are_empty()) {
+            tmp_arms = arms.to_vec();
+            tmp_arms
+                .par_iter_mut()
+                .filter(|(_, ct)| !ct.block_carries_are_empty())
+                .for_each(|(_, ct)| self.full_propagate_parallelized(ct));
+            &tmp_arms
+        } else {
+            arms
+        };
+
+        let selector = if selector.block_carries_are_empty() {
+            selector
+        } else {
+            tmp_selector = selector.clone();
+            self.full_propagate_parallelized(&mut tmp_selector);
+            &tmp_selector
+        };
+
+        let default = if default.block_carries_are_empty() {
+            default
+        } else {
+            tmp_default = default.clone();
+            self.full_propagate_parallelized(&mut tmp_default);
+            &tmp_default
+        };
+
+        self.unchecked_match_parallelized(selector, arms, default)
+    }
+
+    /// Returns the encrypted index and the encrypted value of the minimum of the slice
+    ///
+    /// Both are computed in a single tree reduction: at each level, pairs of
+    /// (index, value) candidates are compared and the smallest is kept.
+    ///
+    /// # Notes
+    ///
+    /// - If the minimum value appears multiple times, the lowest index is returned
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` is empty
+    pub fn unchecked_argmin_with_value_parallelized<T>(&self, cts: &[T]) -> (RadixCiphertext, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(
+            !cts.is_empty(),
+            "Cannot compute the argmin of an empty slice"
+        );
+
+        let num_blocks_index =
+            (cts.len().ilog2() + 1).div_ceil(self.message_modulus().0.ilog2()) as usize;
+
+        let mut candidates = cts
+            .par_iter()
+            .enumerate()
+            .map(|(i, ct)| {
+                (
+                    self.create_trivial_radix(i as u64, num_blocks_index),
+                    ct.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        while candidates.len() > 1 {
+            candidates = candidates
+                .into_par_iter()
+                .chunks(2)
+                .map(|mut chunk| {
+                    let (rhs_index, rhs_value) = chunk.pop().unwrap();
+                    let Some((lhs_index, lhs_value)) = chunk.pop() else {
+                        return (rhs_index, rhs_value);
+                    };
+
+                    // The lhs always has the lowest index, so it is kept on ties
+                    let rhs_is_smaller = self.unchecked_lt_parallelized(&rhs_value, &lhs_value);
+                    rayon::join(
+                        || {
+                            self.unchecked_if_then_else_parallelized(
+                                &rhs_is_smaller,
+                                &rhs_index,
+                                &lhs_index,
+                            )
+                        },
+                        || {
+                            self.unchecked_if_then_else_parallelized(
+                                &rhs_is_smaller,
+                                &rhs_value,
+                                &lhs_value,
+                            )
+                        },
+                    )
+                })
+                .collect();
+        }
+
+        candidates.pop().unwrap()
+    }
+
+    /// Returns the encrypted index and the encrypted value of the minimum of the slice
+    ///
+    /// Both are computed in a single tree reduction: at each level, pairs of
+    /// (index, value) candidates are compared and the smallest is kept.
+    ///
+    /// # Notes
+    ///
+    /// - If the minimum value appears multiple times, the lowest index is returned
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` is empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let distances = [42u64, 17, 200, 17, 33];
+    /// let cts = distances
+    ///     .iter()
+    ///     .map(|d| cks.encrypt(*d))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let (index, value) = sks.argmin_with_value_parallelized(&cts);
+    ///
+    /// let index: u64 = cks.decrypt(&index);
+    /// let value: u64 = cks.decrypt(&value);
+    /// assert_eq!(index, 1);
+    /// assert_eq!(value, 17);
+    /// ```
+    pub fn argmin_with_value_parallelized<T>(&self, cts: &[T]) -> (RadixCiphertext, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_cts;
+
+        let cts = if cts.iter().any(|ct| !ct.block_carries_are_empty()) {
+            tmp_cts = cts.to_vec();
+            tmp_cts
+                .par_iter_mut()
+                .filter(|ct| !ct.block_carries_are_empty())
+                .for_each(|ct| self.full_propagate_parallelized(ct));
+            &tmp_cts
+        } else {
+            cts
+        };
+
+        self.unchecked_argmin_with_value_parallelized(cts)
+    }
+
     fn compute_final_index_from_selectors(
         &self,
         selectors: Vec<BooleanBlock>,
@@ -1274,6 +1665,51 @@ impl ServerKey {
         T::from_blocks(unpacked_blocks)
     }
 
+    /// Aggregates a one-hot vector of radix ciphertexts
+    /// (i.e. at most one of the vector element is non-zero) into single ciphertext
+    /// containing the non-zero value.
+    ///
+    /// Unlike [Self::aggregate_one_hot_vector], the blocks are not packed: as at most one
+    /// value is non-zero, additions cannot create carries and the limiting factor is the
+    /// noise level, so values are summed by chunks and cleaned with one PBS per block.
+    ///
+    /// Expects all ciphertexts to have empty carries
+    ///
+    /// # Panics
+    ///
+    /// Panics if `one_hot_vector` is empty
+    pub(crate) fn aggregate_unpacked_one_hot_vector<T>(&self, mut one_hot_vector: Vec<T>) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(
+            !one_hot_vector.is_empty(),
+            "Cannot aggregate an empty one-hot vector"
+        );
+
+        let chunk_size = self.key.max_noise_level.get() as usize;
+        while one_hot_vector.len() > 1 {
+            one_hot_vector = one_hot_vector
+                .par_chunks(chunk_size)
+                .map(|chunk| {
+                    let mut result = chunk[0].clone();
+                    for value in &chunk[1..] {
+                        self.unchecked_add_assign(&mut result, value);
+                    }
+                    if chunk.len() > 1 {
+                        result
+                            .blocks_mut()
+                            .par_iter_mut()
+                            .for_each(|block| self.key.message_extract_assign(block));
+                    }
+                    result
+                })
+                .collect();
+        }
+
+        one_hot_vector.pop().unwrap()
+    }
+
     /// Only keeps at most one Ciphertext that encrypts 1
     ///
     /// Given a Vec of Ciphertexts where each Ciphertext encrypts 0 or 1
@@ -282,6 +282,18 @@ pub struct DeserializationConfig {
     validate_header: bool,
 }
 
+/// Builds the error returned when a versioned object cannot be read.
+///
+/// This happens when the data is corrupted, but also when it holds a version of the type that
+/// is unknown to this version of TFHE-rs, so the error mentions it.
+fn versioned_deserialization_error<T: Named>(err: &bincode::Error) -> String {
+    format!(
+        "Failed to deserialize versioned {}: {err}. If the data is valid, it may have been \
+         serialized with a more recent version of TFHE-rs than this one (v{CRATE_VERSION}).",
+        T::NAME
+    )
+}
+
 /// A configuration used to Serialize *TFHE-rs* objects. This is similar to
 /// [`DeserializationConfig`] but it will not require conformance parameters.
 ///
@@ -343,7 +355,7 @@ impl NonConformantDeserializationConfig {
                 SerializationVersioningMode::Versioned { .. } => {
                     let deser_versioned = options
                         .deserialize_from(&mut reader)
-                        .map_err(|err| err.to_string())?;
+                        .map_err(|err| versioned_deserialization_error::<T>(&err))?;
 
                     T::unversionize(deser_versioned).map_err(|e| e.to_string())
                 }
@@ -357,7 +369,7 @@ impl NonConformantDeserializationConfig {
                 SerializationVersioningMode::Versioned { .. } => {
                     let deser_versioned = options
                         .deserialize_from(&mut reader)
-                        .map_err(|err| err.to_string())?;
+                        .map_err(|err| versioned_deserialization_error::<T>(&err))?;
 
                     T::unversionize(deser_versioned).map_err(|e| e.to_string())
                 }
@@ -471,9 +483,13 @@ pub fn safe_serialized_size<T: Serialize + Versionize + Named>(object: &T) -> bi
     SerializationConfig::new_with_unlimited_size().serialized_size(object)
 }
 
-/// Serialize an object with the default configuration (with size limit, header check and
+/// Deserialize an object with the default configuration (with size limit, header check and
 /// versioning). This is an alias for
 /// `DeserializationConfig::new(serialized_size_limit).disable_conformance().deserialize_from`
+///
+/// The header, which holds the name of the serialized type, is checked before the object itself
+/// is read, and no more than `serialized_size_limit` bytes are read from `reader`. Blobs
+/// declaring lengths that would exceed the limit are rejected before anything is allocated.
 pub fn safe_deserialize<T: DeserializeOwned + Unversionize + Named>(
     reader: impl std::io::Read,
     serialized_size_limit: u64,
@@ -680,16 +696,22 @@ mod test_shortint {
 mod test_integer {
     use crate::conformance::ListSizeConstraint;
     use crate::high_level_api::{generate_keys, ConfigBuilder};
+    use crate::named::Named;
     use crate::prelude::*;
-    use crate::safe_serialization::{DeserializationConfig, SerializationConfig};
+    use crate::safe_serialization::{
+        safe_deserialize, safe_serialize, DeserializationConfig, SerializationConfig,
+    };
     use crate::shortint::parameters::{
         PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
         PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
     };
     use crate::{
-        set_server_key, CompactCiphertextList, CompactCiphertextListConformanceParams,
-        CompactPublicKey, FheUint8,
+        set_server_key, ClientKey, CompactCiphertextList, CompactCiphertextListConformanceParams,
+        CompactPublicKey, CompressedServerKey, FheBool, FheInt8, FheUint8,
     };
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use tfhe_versionable::{Unversionize, Versionize};
 
     #[test]
     fn safe_deserialization_ct_list() {
@@ -856,4 +878,65 @@ mod test_integer {
 
         assert_eq!(&msg[..], &dec);
     }
+
+    /// Checks that `safe_deserialize` accepts the valid serialization of `object` and rejects
+    /// malformed or oversized versions of it
+    fn check_safe_deserialize_rejects_invalid_blobs<T>(object: &T) -> T
+    where
+        T: Serialize + Versionize + Named + DeserializeOwned + Unversionize,
+    {
+        let limit = 1 << 30;
+
+        let mut buffer = vec![];
+        safe_serialize(object, &mut buffer, limit).unwrap();
+
+        // Truncated blob
+        for len in [0, 8, buffer.len() / 2, buffer.len() - 1] {
+            assert!(
+                safe_deserialize::<T>(&buffer[..len], limit).is_err(),
+                "A {} blob truncated to {len} bytes should not deserialize",
+                T::NAME
+            );
+        }
+
+        // Blob that is bigger than the allowed size
+        assert!(safe_deserialize::<T>(buffer.as_slice(), buffer.len() as u64 - 1).is_err());
+
+        // Blob declaring a length bigger than the allowed size, the first field of the
+        // header is a length prefixed string
+        let mut oversized = buffer.clone();
+        oversized[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(safe_deserialize::<T>(oversized.as_slice(), limit).is_err());
+
+        // Valid blob
+        safe_deserialize::<T>(buffer.as_slice(), buffer.len() as u64).unwrap()
+    }
+
+    #[test]
+    fn safe_deserialization_rejects_invalid_blobs() {
+        let client_key = ClientKey::generate(ConfigBuilder::default());
+
+        let a = FheUint8::encrypt(213u8, &client_key);
+        let a = check_safe_deserialize_rejects_invalid_blobs(&a);
+        let a: u8 = a.decrypt(&client_key);
+        assert_eq!(a, 213);
+
+        let b = FheInt8::encrypt(-42i8, &client_key);
+        let b = check_safe_deserialize_rejects_invalid_blobs(&b);
+        let b: i8 = b.decrypt(&client_key);
+        assert_eq!(b, -42);
+
+        let c = FheBool::encrypt(true, &client_key);
+        let c = check_safe_deserialize_rejects_invalid_blobs(&c);
+        assert!(c.decrypt(&client_key));
+
+        let compressed_server_key = CompressedServerKey::new(&client_key);
+        let _ = check_safe_deserialize_rejects_invalid_blobs(&compressed_server_key);
+
+        // The type is checked before reading the object
+        let mut buffer = vec![];
+        safe_serialize(&FheUint8::encrypt(1u8, &client_key), &mut buffer, 1 << 20).unwrap();
+        assert!(safe_deserialize::<FheInt8>(buffer.as_slice(), 1 << 20).is_err());
+        assert!(safe_deserialize::<FheBool>(buffer.as_slice(), 1 << 20).is_err());
+    }
 }
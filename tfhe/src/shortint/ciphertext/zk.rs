@@ -33,6 +33,45 @@ impl CompactPkeCrs {
         crate::Error: From<E>,
     {
         let params: CompactPublicKeyEncryptionParameters = params.try_into()?;
+        let encryption_modulus = params.message_modulus.0 * params.carry_modulus.0;
+        Self::from_shortint_params_with_encryption_modulus::<_, std::convert::Infallible>(
+            params,
+            max_num_message,
+            encryption_modulus,
+        )
+    }
+
+    /// Construct the CRS that corresponds to the given parameters, for lists encrypted with the
+    /// given `encryption_modulus`
+    ///
+    /// Proofs made with this CRS also prove that the messages are smaller than
+    /// `encryption_modulus`, by proving that the upper bits of the plaintext are zero.
+    /// [Self::from_shortint_params] uses the full message and carry space, which is the range of
+    /// packed lists: lists that are not packed need a CRS built with the message modulus to be
+    /// accepted by [ProvenCompactCiphertextList::verify].
+    ///
+    /// `encryption_modulus` must be a power of two dividing the product of the message and carry
+    /// moduli.
+    pub fn from_shortint_params_with_encryption_modulus<P, E>(
+        params: P,
+        max_num_message: LweCiphertextCount,
+        encryption_modulus: u64,
+    ) -> crate::Result<Self>
+    where
+        P: TryInto<CompactPublicKeyEncryptionParameters, Error = E>,
+        crate::Error: From<E>,
+    {
+        let params: CompactPublicKeyEncryptionParameters = params.try_into()?;
+        let full_message_modulus = params.message_modulus.0 * params.carry_modulus.0;
+        if encryption_modulus < 2
+            || !encryption_modulus.is_power_of_two()
+            || full_message_modulus % encryption_modulus != 0
+        {
+            return Err(crate::Error::new(format!(
+                "The encryption modulus ({encryption_modulus}) must be a power of two \
+                dividing the message and carry modulus ({full_message_modulus})"
+            )));
+        }
         let (size, noise_distribution) = (
             params.encryption_lwe_dimension,
             params.encryption_noise_distribution,
@@ -42,8 +81,10 @@ impl CompactPkeCrs {
         // Our plaintext modulus does not take into account the bit of padding
         plaintext_modulus *= 2;
 
-        // 1 padding bit for the PBS
-        // Note that if we want to we can prove carry bits are 0 should we need it
+        // 1 padding bit for the PBS, and the bits above the encryption modulus are proven to be 0
+        let msbs_zero_padding_bit_count = ZkMSBZeroPaddingBitCount(
+            1 + (full_message_modulus / encryption_modulus).ilog2() as u64,
+        );
         crate::shortint::engine::ShortintEngine::with_thread_local_mut(|engine| {
             match params.zk_scheme {
                 SupportedCompactPkeZkScheme::V1 => Self::new_legacy_v1(
@@ -52,7 +93,7 @@ impl CompactPkeCrs {
                     noise_distribution,
                     params.ciphertext_modulus,
                     plaintext_modulus,
-                    ZkMSBZeroPaddingBitCount(1),
+                    msbs_zero_padding_bit_count,
                     &mut engine.random_generator,
                 ),
                 SupportedCompactPkeZkScheme::V2 => Self::new(
@@ -61,7 +102,7 @@ impl CompactPkeCrs {
                     noise_distribution,
                     params.ciphertext_modulus,
                     plaintext_modulus,
-                    ZkMSBZeroPaddingBitCount(1),
+                    msbs_zero_padding_bit_count,
                     &mut engine.random_generator,
                 ),
                 SupportedCompactPkeZkScheme::ZkNotSupported => {
@@ -96,18 +137,7 @@ impl ProvenCompactCiphertextList {
         metadata: &[u8],
         casting_mode: ShortintCompactCiphertextListCastingMode<'_>,
     ) -> crate::Result<Vec<Ciphertext>> {
-        let not_all_valid = self.proved_lists.par_iter().any(|(ct_list, proof)| {
-            verify_lwe_compact_ciphertext_list(
-                &ct_list.ct_list,
-                &public_key.key,
-                proof,
-                crs,
-                metadata,
-            )
-            .is_invalid()
-        });
-
-        if not_all_valid {
+        if self.verify(crs, public_key, metadata).is_invalid() {
             return Err(crate::ErrorKind::InvalidZkProof.into());
         }
 
@@ -189,15 +219,19 @@ impl ProvenCompactCiphertextList {
         public_key: &CompactPublicKey,
         metadata: &[u8],
     ) -> ZkVerificationOutcome {
+        // The proofs only guarantee that the messages are in the range proven by the crs,
+        // so the degree of the lists must cover this whole range
+        let proven_message_modulus = crs.proven_message_modulus();
         let all_valid = self.proved_lists.par_iter().all(|(ct_list, proof)| {
-            verify_lwe_compact_ciphertext_list(
-                &ct_list.ct_list,
-                &public_key.key,
-                proof,
-                crs,
-                metadata,
-            )
-            .is_valid()
+            ct_list.degree.get() >= proven_message_modulus - 1
+                && verify_lwe_compact_ciphertext_list(
+                    &ct_list.ct_list,
+                    &public_key.key,
+                    proof,
+                    crs,
+                    metadata,
+                )
+                .is_valid()
         });
 
         if all_valid {
@@ -297,6 +331,7 @@ impl ParameterSetConformant for ProvenCompactCiphertextList {
 #[cfg(test)]
 mod tests {
     use crate::core_crypto::prelude::LweCiphertextCount;
+    use crate::shortint::ciphertext::Degree;
     use crate::shortint::parameters::*;
     use crate::shortint::{
         ClientKey, CompactPrivateKey, CompactPublicKey, KeySwitchingKey, ServerKey,
@@ -310,7 +345,13 @@ mod tests {
         let pke_params = PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
         let ksk_params = PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
 
-        let crs = CompactPkeCrs::from_shortint_params(pke_params, LweCiphertextCount(4)).unwrap();
+        // No packing, the proofs must show that the messages fit in the message modulus
+        let crs = CompactPkeCrs::from_shortint_params_with_encryption_modulus(
+            pke_params,
+            LweCiphertextCount(4),
+            pke_params.message_modulus.0,
+        )
+        .unwrap();
         let priv_key = CompactPrivateKey::new(pke_params);
         let pub_key = CompactPublicKey::new(&priv_key);
         let ck = ClientKey::new(params);
@@ -372,7 +413,13 @@ mod tests {
         let pke_params = PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
         let ksk_params = PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
 
-        let crs = CompactPkeCrs::from_shortint_params(pke_params, LweCiphertextCount(4)).unwrap();
+        // No packing, the proofs must show that the messages fit in the message modulus
+        let crs = CompactPkeCrs::from_shortint_params_with_encryption_modulus(
+            pke_params,
+            LweCiphertextCount(4),
+            pke_params.message_modulus.0,
+        )
+        .unwrap();
         let priv_key = CompactPrivateKey::new(pke_params);
         let pub_key = CompactPublicKey::new(&priv_key);
         let ck = ClientKey::new(params);
@@ -418,4 +465,90 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(msgs, decrypted);
     }
+
+    #[test]
+    fn test_zk_compact_ciphertext_list_out_of_range_message_ci_run_filter() {
+        let pke_params = PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+        let message_modulus = pke_params.message_modulus.0;
+        let full_message_modulus = message_modulus * pke_params.carry_modulus.0;
+
+        let priv_key = CompactPrivateKey::new(pke_params);
+        let pub_key = CompactPublicKey::new(&priv_key);
+
+        let metadata = [b's', b'h', b'o', b'r', b't', b'i', b'n', b't'];
+
+        // The second message is in the carries
+        let msgs = [0, message_modulus];
+
+        let full_range_crs =
+            CompactPkeCrs::from_shortint_params(pke_params, LweCiphertextCount(4)).unwrap();
+        let message_range_crs = CompactPkeCrs::from_shortint_params_with_encryption_modulus(
+            pke_params,
+            LweCiphertextCount(4),
+            message_modulus,
+        )
+        .unwrap();
+
+        // The prover refuses to build such lists
+        assert!(pub_key
+            .encrypt_and_prove_slice(
+                &msgs,
+                &message_range_crs,
+                &metadata,
+                ZkComputeLoad::Proof,
+                message_modulus,
+            )
+            .is_err());
+        // The full range crs cannot prove that the messages are in the message range
+        assert!(pub_key
+            .encrypt_and_prove_slice(
+                &[0, 1],
+                &full_range_crs,
+                &metadata,
+                ZkComputeLoad::Proof,
+                message_modulus,
+            )
+            .is_err());
+
+        // A dishonest prover encrypts the messages with the full range and then declares that the
+        // list is in the message range
+        for crs in [&full_range_crs, &message_range_crs] {
+            let mut proven_ct = pub_key
+                .encrypt_and_prove_slice(
+                    &msgs,
+                    crs,
+                    &metadata,
+                    ZkComputeLoad::Proof,
+                    full_message_modulus,
+                )
+                .unwrap();
+            for (ct_list, _) in proven_ct.proved_lists.iter_mut() {
+                ct_list.degree = Degree::new(message_modulus - 1);
+            }
+
+            assert!(proven_ct.verify(crs, &pub_key, &metadata).is_invalid());
+            assert!(proven_ct
+                .verify_and_expand(
+                    crs,
+                    &pub_key,
+                    &metadata,
+                    ShortintCompactCiphertextListCastingMode::NoCasting,
+                )
+                .is_err());
+        }
+
+        // Honest lists are accepted
+        let proven_ct = pub_key
+            .encrypt_and_prove_slice(
+                &[0, message_modulus - 1],
+                &message_range_crs,
+                &metadata,
+                ZkComputeLoad::Proof,
+                message_modulus,
+            )
+            .unwrap();
+        assert!(proven_ct
+            .verify(&message_range_crs, &pub_key, &metadata)
+            .is_valid());
+    }
 }
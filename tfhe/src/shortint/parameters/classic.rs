@@ -1,5 +1,11 @@
+use crate::core_crypto::commons::noise_formulas::secure_noise::{
+    minimal_lwe_variance_for_132_bits_security_gaussian,
+    minimal_lwe_variance_for_132_bits_security_tuniform,
+};
 use crate::core_crypto::prelude::MsDecompressionType;
+use crate::named::Named;
 use crate::shortint::backward_compatibility::parameters::ClassicPBSParametersVersions;
+use crate::shortint::parameters::current_params::VEC_ALL_CLASSIC_PBS_PARAMETERS;
 use crate::shortint::parameters::{
     CarryModulus, CiphertextConformanceParams, CiphertextModulus, DecompositionBaseLog,
     DecompositionLevelCount, Degree, DynamicDistribution, EncryptionKeyChoice, GlweDimension,
@@ -8,6 +14,7 @@ use crate::shortint::parameters::{
 };
 
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 use tfhe_versionable::Versionize;
 
 /// A structure defining the set of cryptographic parameters for homomorphic integer circuit
@@ -49,6 +56,10 @@ pub struct ClassicPBSParameters {
     pub modulus_switch_noise_reduction_params: Option<ModulusSwitchNoiseReductionParams>,
 }
 
+impl Named for ClassicPBSParameters {
+    const NAME: &'static str = "shortint::ClassicPBSParameters";
+}
+
 impl ClassicPBSParameters {
     /// Constructs a new set of parameters for integer circuit evaluation.
     ///
@@ -96,6 +107,170 @@ impl ClassicPBSParameters {
         }
     }
 
+    /// Returns whether ciphertexts encrypted under `self` can be keyswitched to ciphertexts
+    /// encrypted under `other`, e.g. using a
+    /// [`KeySwitchingKey`](crate::shortint::key_switching_key::KeySwitchingKey).
+    ///
+    /// Any pair of LWE dimensions can be keyswitched, however:
+    /// - both parameter sets must use the same ciphertext modulus,
+    /// - both full message moduli (message and carry) must be powers of 2,
+    /// - the message space of `self` must fit in the message space of `other`, otherwise the
+    ///   message would be truncated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::v1_0::{
+    ///     V1_0_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M128,
+    ///     V1_0_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    /// };
+    /// use tfhe::shortint::parameters::CiphertextModulus;
+    ///
+    /// let small = V1_0_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M128;
+    /// let large = V1_0_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    ///
+    /// assert!(small.compatible_for_keyswitch(&large));
+    /// assert!(large.compatible_for_keyswitch(&large));
+    /// // 2 bits of message cannot fit in 1 bit
+    /// assert!(!large.compatible_for_keyswitch(&small));
+    ///
+    /// let mut other_modulus = large;
+    /// other_modulus.ciphertext_modulus = CiphertextModulus::try_new_power_of_2(62).unwrap();
+    /// assert!(!large.compatible_for_keyswitch(&other_modulus));
+    /// ```
+    pub fn compatible_for_keyswitch(&self, other: &Self) -> bool {
+        let full_message_modulus_input = self.message_modulus.0 * self.carry_modulus.0;
+        let full_message_modulus_output = other.message_modulus.0 * other.carry_modulus.0;
+
+        self.ciphertext_modulus == other.ciphertext_modulus
+            && full_message_modulus_input.is_power_of_two()
+            && full_message_modulus_output.is_power_of_two()
+            && self.message_modulus.0 <= other.message_modulus.0
+    }
+
+    /// Checks that the parameters are consistent and that the noise distributions
+    /// are large enough for the 132 bits security target used to generate the parameters
+    /// of this crate (which gives a margin over the 128 bits security level).
+    ///
+    /// The minimal noise is computed using formulas fitted on the lattice estimator,
+    /// for binary secret keys.
+    ///
+    /// See [Self::security_level_bits] to estimate the security level of the parameters instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{LweDimension, PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128};
+    ///
+    /// assert!(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128
+    ///     .validate()
+    ///     .is_ok());
+    ///
+    /// // Reducing the lwe dimension without increasing the noise makes them insecure
+    /// let mut params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// params.lwe_dimension = LweDimension(params.lwe_dimension.0 / 2);
+    /// assert!(params.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), ParameterError> {
+        let full_message_modulus = self.message_modulus.0 * self.carry_modulus.0;
+        if !full_message_modulus.is_power_of_two() || full_message_modulus < 2 {
+            return Err(ParameterError::InvalidMessageSpace {
+                full_message_modulus,
+            });
+        }
+
+        if !self.ciphertext_modulus.is_compatible_with_native_modulus() {
+            return Err(ParameterError::UnsupportedCiphertextModulus {
+                ciphertext_modulus: self.ciphertext_modulus,
+            });
+        }
+
+        let modulus_bits = self.ciphertext_modulus.raw_modulus_float().log2() as usize;
+        // One bit of padding is needed on top of the message and carry bits
+        let message_bits = full_message_modulus.ilog2() as usize + 1;
+        if message_bits >= modulus_bits {
+            return Err(ParameterError::CiphertextModulusTooSmall {
+                ciphertext_modulus: self.ciphertext_modulus,
+                message_bits,
+            });
+        }
+
+        if self.lwe_dimension.0 == 0 || self.glwe_dimension.0 == 0 {
+            return Err(ParameterError::ZeroDimension);
+        }
+
+        if !self.polynomial_size.0.is_power_of_two() {
+            return Err(ParameterError::InvalidPolynomialSize {
+                polynomial_size: self.polynomial_size,
+            });
+        }
+
+        for (name, base_log, level) in [
+            ("bootstrapping", self.pbs_base_log, self.pbs_level),
+            ("keyswitch", self.ks_base_log, self.ks_level),
+        ] {
+            if base_log.0 == 0 || level.0 == 0 || base_log.0 * level.0 > modulus_bits {
+                return Err(ParameterError::InvalidDecomposition {
+                    name,
+                    base_log,
+                    level,
+                    modulus_bits,
+                });
+            }
+        }
+
+        check_noise_distribution_is_secure(
+            "lwe",
+            self.lwe_noise_distribution,
+            self.lwe_dimension,
+            self.ciphertext_modulus,
+        )?;
+        check_noise_distribution_is_secure(
+            "glwe",
+            self.glwe_noise_distribution,
+            self.glwe_dimension
+                .to_equivalent_lwe_dimension(self.polynomial_size),
+            self.ciphertext_modulus,
+        )
+    }
+
+    /// Estimates the security level in bits of the parameters, i.e. the lowest security level
+    /// of the lwe secret key and of the glwe secret key.
+    ///
+    /// The estimate extrapolates the formulas used by [Self::validate], which give the minimal
+    /// noise for 132 bits of security: for a given noise, the dimension `n_132` reaching 132 bits
+    /// of security is computed and the security is assumed to grow linearly with the dimension,
+    /// giving `132 * n / n_132` bits. It is meant to compare parameters against a target
+    /// (e.g. reject parameters below 128 bits), not as a replacement for the lattice estimator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{LweDimension, PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128};
+    ///
+    /// assert!(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128.security_level_bits() >= 128);
+    ///
+    /// let mut params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    /// params.lwe_dimension = LweDimension(params.lwe_dimension.0 / 2);
+    /// assert!(params.security_level_bits() < 128);
+    /// ```
+    pub fn security_level_bits(&self) -> u32 {
+        let lwe_security = estimate_security_level_bits(
+            self.lwe_noise_distribution,
+            self.lwe_dimension,
+            self.ciphertext_modulus,
+        );
+        let glwe_security = estimate_security_level_bits(
+            self.glwe_noise_distribution,
+            self.glwe_dimension
+                .to_equivalent_lwe_dimension(self.polynomial_size),
+            self.ciphertext_modulus,
+        );
+
+        // Float to int casts saturate, which maps an unbounded estimate to u32::MAX
+        lwe_security.min(glwe_security).floor() as u32
+    }
+
     pub fn to_shortint_conformance_param(&self) -> CiphertextConformanceParams {
         let (pbs_order, expected_dim) = match self.encryption_key_choice {
             EncryptionKeyChoice::Big => (
@@ -128,3 +303,1047 @@ impl ClassicPBSParameters {
         }
     }
 }
+
+/// A builder for [`ClassicPBSParameters`]
+///
+/// All the fields must be set, except:
+/// - `max_noise_level` which defaults to the value derived from the message and carry moduli,
+/// - `ciphertext_modulus` which defaults to the native modulus,
+/// - `encryption_key_choice` which defaults to [`EncryptionKeyChoice::Big`],
+/// - `modulus_switch_noise_reduction_params` which defaults to `None`.
+///
+/// [`Self::build`] only checks that the parameters are consistent,
+/// use [`ClassicPBSParameters::validate`] to also check their security.
+///
+/// # Warning
+///
+/// Failing to fix the parameters properly would yield incorrect and unsecure computation.
+/// Unless you are a cryptographer who really knows the impact of each of those parameters, you
+/// __must__ stick with the provided parameters.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::{
+///     ClassicPBSParametersBuilder, PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+/// };
+/// use tfhe::shortint::{CarryModulus, MessageModulus};
+///
+/// // Start from existing parameters and only change the message and carry moduli
+/// let params = ClassicPBSParametersBuilder::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128)
+///     .message_modulus(MessageModulus(2))
+///     .carry_modulus(CarryModulus(2))
+///     .max_noise_level(None)
+///     .build()
+///     .unwrap();
+/// assert_eq!(params.max_noise_level.get(), 3);
+///
+/// // Missing fields are reported
+/// let err = ClassicPBSParametersBuilder::new().build().unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "Missing required parameter field: lwe_dimension"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ClassicPBSParametersBuilder {
+    lwe_dimension: Option<LweDimension>,
+    glwe_dimension: Option<GlweDimension>,
+    polynomial_size: Option<PolynomialSize>,
+    lwe_noise_distribution: Option<DynamicDistribution<u64>>,
+    glwe_noise_distribution: Option<DynamicDistribution<u64>>,
+    pbs_base_log: Option<DecompositionBaseLog>,
+    pbs_level: Option<DecompositionLevelCount>,
+    ks_base_log: Option<DecompositionBaseLog>,
+    ks_level: Option<DecompositionLevelCount>,
+    message_modulus: Option<MessageModulus>,
+    carry_modulus: Option<CarryModulus>,
+    max_noise_level: Option<MaxNoiseLevel>,
+    log2_p_fail: Option<f64>,
+    ciphertext_modulus: Option<CiphertextModulus>,
+    encryption_key_choice: Option<EncryptionKeyChoice>,
+    modulus_switch_noise_reduction_params: Option<ModulusSwitchNoiseReductionParams>,
+}
+
+impl ClassicPBSParametersBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn lwe_dimension(mut self, lwe_dimension: LweDimension) -> Self {
+        self.lwe_dimension = Some(lwe_dimension);
+        self
+    }
+
+    pub fn glwe_dimension(mut self, glwe_dimension: GlweDimension) -> Self {
+        self.glwe_dimension = Some(glwe_dimension);
+        self
+    }
+
+    pub fn polynomial_size(mut self, polynomial_size: PolynomialSize) -> Self {
+        self.polynomial_size = Some(polynomial_size);
+        self
+    }
+
+    pub fn lwe_noise_distribution(mut self, distribution: DynamicDistribution<u64>) -> Self {
+        self.lwe_noise_distribution = Some(distribution);
+        self
+    }
+
+    pub fn glwe_noise_distribution(mut self, distribution: DynamicDistribution<u64>) -> Self {
+        self.glwe_noise_distribution = Some(distribution);
+        self
+    }
+
+    pub fn pbs_base_log(mut self, pbs_base_log: DecompositionBaseLog) -> Self {
+        self.pbs_base_log = Some(pbs_base_log);
+        self
+    }
+
+    pub fn pbs_level(mut self, pbs_level: DecompositionLevelCount) -> Self {
+        self.pbs_level = Some(pbs_level);
+        self
+    }
+
+    pub fn ks_base_log(mut self, ks_base_log: DecompositionBaseLog) -> Self {
+        self.ks_base_log = Some(ks_base_log);
+        self
+    }
+
+    pub fn ks_level(mut self, ks_level: DecompositionLevelCount) -> Self {
+        self.ks_level = Some(ks_level);
+        self
+    }
+
+    pub fn message_modulus(mut self, message_modulus: MessageModulus) -> Self {
+        self.message_modulus = Some(message_modulus);
+        self
+    }
+
+    pub fn carry_modulus(mut self, carry_modulus: CarryModulus) -> Self {
+        self.carry_modulus = Some(carry_modulus);
+        self
+    }
+
+    /// Sets the max noise level, `None` means it will be derived from the message and
+    /// carry moduli
+    pub fn max_noise_level(mut self, max_noise_level: Option<MaxNoiseLevel>) -> Self {
+        self.max_noise_level = max_noise_level;
+        self
+    }
+
+    pub fn log2_p_fail(mut self, log2_p_fail: f64) -> Self {
+        self.log2_p_fail = Some(log2_p_fail);
+        self
+    }
+
+    pub fn ciphertext_modulus(mut self, ciphertext_modulus: CiphertextModulus) -> Self {
+        self.ciphertext_modulus = Some(ciphertext_modulus);
+        self
+    }
+
+    pub fn encryption_key_choice(mut self, encryption_key_choice: EncryptionKeyChoice) -> Self {
+        self.encryption_key_choice = Some(encryption_key_choice);
+        self
+    }
+
+    pub fn modulus_switch_noise_reduction_params(
+        mut self,
+        params: Option<ModulusSwitchNoiseReductionParams>,
+    ) -> Self {
+        self.modulus_switch_noise_reduction_params = params;
+        self
+    }
+
+    pub fn build(self) -> Result<ClassicPBSParameters, ParameterError> {
+        fn required<T>(value: Option<T>, name: &'static str) -> Result<T, ParameterError> {
+            value.ok_or(ParameterError::MissingField { name })
+        }
+
+        let lwe_dimension = required(self.lwe_dimension, "lwe_dimension")?;
+        let glwe_dimension = required(self.glwe_dimension, "glwe_dimension")?;
+        let polynomial_size = required(self.polynomial_size, "polynomial_size")?;
+        let lwe_noise_distribution =
+            required(self.lwe_noise_distribution, "lwe_noise_distribution")?;
+        let glwe_noise_distribution =
+            required(self.glwe_noise_distribution, "glwe_noise_distribution")?;
+        let pbs_base_log = required(self.pbs_base_log, "pbs_base_log")?;
+        let pbs_level = required(self.pbs_level, "pbs_level")?;
+        let ks_base_log = required(self.ks_base_log, "ks_base_log")?;
+        let ks_level = required(self.ks_level, "ks_level")?;
+        let message_modulus = required(self.message_modulus, "message_modulus")?;
+        let carry_modulus = required(self.carry_modulus, "carry_modulus")?;
+        let log2_p_fail = self.log2_p_fail.ok_or(ParameterError::MissingLog2PFail)?;
+        let ciphertext_modulus = self
+            .ciphertext_modulus
+            .unwrap_or_else(CiphertextModulus::new_native);
+        let encryption_key_choice = self
+            .encryption_key_choice
+            .unwrap_or(EncryptionKeyChoice::Big);
+
+        if message_modulus.0 < 2 || !message_modulus.0.is_power_of_two() {
+            return Err(ParameterError::InvalidModulus {
+                name: "message",
+                modulus: message_modulus.0,
+            });
+        }
+
+        if carry_modulus.0 == 0 || !carry_modulus.0.is_power_of_two() {
+            return Err(ParameterError::InvalidModulus {
+                name: "carry",
+                modulus: carry_modulus.0,
+            });
+        }
+
+        let modulus_bits = ciphertext_modulus.raw_modulus_float().log2() as u32;
+        // One bit of padding is needed on top of the message and carry bits
+        let plaintext_bits = (message_modulus.0 * carry_modulus.0).ilog2() + 1;
+        if plaintext_bits >= modulus_bits {
+            return Err(ParameterError::ModulusOverflow {
+                message_modulus,
+                carry_modulus,
+                ciphertext_modulus,
+            });
+        }
+
+        let max_consistent_noise_level =
+            MaxNoiseLevel::from_msg_carry_modulus(message_modulus, carry_modulus);
+        let max_noise_level = self.max_noise_level.unwrap_or(max_consistent_noise_level);
+        if max_noise_level.get() == 0 || max_noise_level.get() > max_consistent_noise_level.get() {
+            return Err(ParameterError::InconsistentMaxNoiseLevel {
+                max_noise_level,
+                max_consistent_noise_level,
+            });
+        }
+
+        if !(log2_p_fail.is_finite() && log2_p_fail < 0.0) {
+            return Err(ParameterError::InvalidLog2PFail { log2_p_fail });
+        }
+
+        Ok(ClassicPBSParameters {
+            lwe_dimension,
+            glwe_dimension,
+            polynomial_size,
+            lwe_noise_distribution,
+            glwe_noise_distribution,
+            pbs_base_log,
+            pbs_level,
+            ks_base_log,
+            ks_level,
+            message_modulus,
+            carry_modulus,
+            max_noise_level,
+            log2_p_fail,
+            ciphertext_modulus,
+            encryption_key_choice,
+            modulus_switch_noise_reduction_params: self.modulus_switch_noise_reduction_params,
+        })
+    }
+}
+
+impl From<ClassicPBSParameters> for ClassicPBSParametersBuilder {
+    fn from(params: ClassicPBSParameters) -> Self {
+        Self {
+            lwe_dimension: Some(params.lwe_dimension),
+            glwe_dimension: Some(params.glwe_dimension),
+            polynomial_size: Some(params.polynomial_size),
+            lwe_noise_distribution: Some(params.lwe_noise_distribution),
+            glwe_noise_distribution: Some(params.glwe_noise_distribution),
+            pbs_base_log: Some(params.pbs_base_log),
+            pbs_level: Some(params.pbs_level),
+            ks_base_log: Some(params.ks_base_log),
+            ks_level: Some(params.ks_level),
+            message_modulus: Some(params.message_modulus),
+            carry_modulus: Some(params.carry_modulus),
+            max_noise_level: Some(params.max_noise_level),
+            log2_p_fail: Some(params.log2_p_fail),
+            ciphertext_modulus: Some(params.ciphertext_modulus),
+            encryption_key_choice: Some(params.encryption_key_choice),
+            modulus_switch_noise_reduction_params: params.modulus_switch_noise_reduction_params,
+        }
+    }
+}
+
+/// Error returned by [`ClassicPBSParameters::validate`] and [`ClassicPBSParametersBuilder::build`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParameterError {
+    /// A field without default value was not set on the builder
+    MissingField { name: &'static str },
+    /// The `log2_p_fail` was not set on the builder
+    MissingLog2PFail,
+    /// The message modulus is not a power of two greater than 1,
+    /// or the carry modulus is not a power of two
+    InvalidModulus { name: &'static str, modulus: u64 },
+    /// The message, carry and padding bits overflow the ciphertext modulus
+    ModulusOverflow {
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+        ciphertext_modulus: CiphertextModulus,
+    },
+    /// The max noise level is 0 or greater than what the carry space allows
+    InconsistentMaxNoiseLevel {
+        max_noise_level: MaxNoiseLevel,
+        max_consistent_noise_level: MaxNoiseLevel,
+    },
+    /// The `log2_p_fail` is not a finite negative number
+    InvalidLog2PFail { log2_p_fail: f64 },
+    /// The product of the message and carry moduli is not a power of two greater than 1
+    InvalidMessageSpace { full_message_modulus: u64 },
+    /// The ciphertext modulus is neither native nor a power of two
+    UnsupportedCiphertextModulus {
+        ciphertext_modulus: CiphertextModulus,
+    },
+    /// The ciphertext modulus cannot hold the message, carry and padding bits
+    CiphertextModulusTooSmall {
+        ciphertext_modulus: CiphertextModulus,
+        message_bits: usize,
+    },
+    /// The lwe or the glwe dimension is 0
+    ZeroDimension,
+    /// The polynomial size is not a power of two
+    InvalidPolynomialSize { polynomial_size: PolynomialSize },
+    /// The bootstrapping or keyswitch decomposition does not fit in the ciphertext modulus
+    InvalidDecomposition {
+        name: &'static str,
+        base_log: DecompositionBaseLog,
+        level: DecompositionLevelCount,
+        modulus_bits: usize,
+    },
+    /// The lwe or glwe noise is too small for the dimension to be secure
+    InsecureNoise {
+        name: &'static str,
+        noise_distribution: DynamicDistribution<u64>,
+        lwe_dimension: LweDimension,
+        variance: f64,
+        minimal_variance: f64,
+    },
+}
+
+impl Display for ParameterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingField { name } => {
+                write!(f, "Missing required parameter field: {name}")
+            }
+            Self::MissingLog2PFail => write!(
+                f,
+                "Missing required parameter field: log2_p_fail, \
+                it can be computed with compute_log2_p_fail"
+            ),
+            Self::InvalidModulus { name, modulus } => {
+                let minimum = if *name == "message" { 2 } else { 1 };
+                write!(
+                    f,
+                    "The {name} modulus must be a power of two greater or equal to {minimum}, \
+                    got {modulus}"
+                )
+            }
+            Self::ModulusOverflow {
+                message_modulus,
+                carry_modulus,
+                ciphertext_modulus,
+            } => write!(
+                f,
+                "The plaintext space ({} bits of message, {} bits of carry and 1 bit of padding) \
+                does not fit in the ciphertext modulus ({ciphertext_modulus})",
+                message_modulus.0.ilog2(),
+                carry_modulus.0.ilog2(),
+            ),
+            Self::InconsistentMaxNoiseLevel {
+                max_noise_level,
+                max_consistent_noise_level,
+            } => write!(
+                f,
+                "The max noise level must be in [1, {}] for the message and carry moduli, got {}",
+                max_consistent_noise_level.get(),
+                max_noise_level.get()
+            ),
+            Self::InvalidLog2PFail { log2_p_fail } => write!(
+                f,
+                "log2_p_fail must be a finite negative number, got {log2_p_fail}"
+            ),
+            Self::InvalidMessageSpace {
+                full_message_modulus,
+            } => write!(
+                f,
+                "The product of the message modulus and the carry modulus \
+                ({full_message_modulus}) must be a power of two greater than 1"
+            ),
+            Self::UnsupportedCiphertextModulus { ciphertext_modulus } => write!(
+                f,
+                "Only native and power of two ciphertext moduli are supported, \
+                got {ciphertext_modulus}"
+            ),
+            Self::CiphertextModulusTooSmall {
+                ciphertext_modulus,
+                message_bits,
+            } => write!(
+                f,
+                "The ciphertext modulus ({ciphertext_modulus}) is too small for {message_bits} \
+                bits of message, carry and padding"
+            ),
+            Self::ZeroDimension => {
+                write!(f, "The lwe and glwe dimensions must be greater than 0")
+            }
+            Self::InvalidPolynomialSize { polynomial_size } => write!(
+                f,
+                "The polynomial size must be a power of two, got {}",
+                polynomial_size.0
+            ),
+            Self::InvalidDecomposition {
+                name,
+                base_log,
+                level,
+                modulus_bits,
+            } => write!(
+                f,
+                "Invalid {name} decomposition: base_log ({}) and level ({}) must be non zero \
+                and their product must not exceed the ciphertext modulus bits ({modulus_bits})",
+                base_log.0, level.0
+            ),
+            Self::InsecureNoise {
+                name,
+                noise_distribution,
+                lwe_dimension,
+                variance,
+                minimal_variance,
+            } => write!(
+                f,
+                "The {name} noise distribution ({noise_distribution}) is too small for \
+                an equivalent lwe dimension of {}: got variance {variance:e}, \
+                minimal secure variance is {minimal_variance:e}",
+                lwe_dimension.0
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParameterError {}
+
+impl From<ParameterError> for crate::Error {
+    fn from(value: ParameterError) -> Self {
+        Self::new(value.to_string())
+    }
+}
+
+fn check_noise_distribution_is_secure(
+    name: &'static str,
+    noise_distribution: DynamicDistribution<u64>,
+    lwe_dimension: LweDimension,
+    ciphertext_modulus: CiphertextModulus,
+) -> Result<(), ParameterError> {
+    let modulus = ciphertext_modulus.raw_modulus_float();
+
+    let variance = normalized_variance(noise_distribution, modulus);
+    let minimal_variance = match noise_distribution {
+        DynamicDistribution::Gaussian(_) => {
+            minimal_lwe_variance_for_132_bits_security_gaussian(lwe_dimension, modulus).0
+        }
+        DynamicDistribution::TUniform(_) => {
+            minimal_lwe_variance_for_132_bits_security_tuniform(lwe_dimension, modulus).0
+        }
+    };
+
+    // The noise of the shipped parameters is rounded when they are generated,
+    // so allow for a small relative error
+    if variance < minimal_variance * (1.0 - 1e-3) {
+        return Err(ParameterError::InsecureNoise {
+            name,
+            noise_distribution,
+            lwe_dimension,
+            variance,
+            minimal_variance,
+        });
+    }
+
+    Ok(())
+}
+
+/// Extrapolates the security level of an lwe secret key of dimension `lwe_dimension`
+/// with the given noise, see [`ClassicPBSParameters::security_level_bits`]
+fn estimate_security_level_bits(
+    noise_distribution: DynamicDistribution<u64>,
+    lwe_dimension: LweDimension,
+    ciphertext_modulus: CiphertextModulus,
+) -> f64 {
+    let modulus = ciphertext_modulus.raw_modulus_float();
+
+    // Both minimal secure noise formulas have a 16 / q^2 variance floor
+    // that no dimension allows to go below
+    let variance = match noise_distribution {
+        DynamicDistribution::Gaussian(_) => noise_distribution.gaussian_variance().0,
+        DynamicDistribution::TUniform(tuniform) => {
+            let bound_log2 = tuniform.bound_log2() as i32;
+            (2f64.powi(2 * bound_log2 + 1) + 1.0) / 6.0 / modulus.powi(2)
+        }
+    };
+    if variance < 16.0 * modulus.powi(-2) * (1.0 - 1e-3) {
+        return 0.0;
+    }
+
+    // Invert the minimal secure noise formulas to get the dimension reaching 132 bits of security
+    let dimension_for_132_bits = match noise_distribution {
+        DynamicDistribution::Gaussian(_) => {
+            // minimal variance = 2^(5.31469187675068 - 0.0497829131652661 * n) + 16 / q^2
+            (5.31469187675068 - variance.log2()) / 0.0497829131652661
+        }
+        DynamicDistribution::TUniform(tuniform) => {
+            // minimal bound_log2 = ceil(-0.025167785 * n + log2(q) + 4.10067100000001)
+            (modulus.log2() + 4.10067100000001 - tuniform.bound_log2() as f64) / 0.025167785
+        }
+    };
+
+    if dimension_for_132_bits <= 0.0 {
+        return f64::INFINITY;
+    }
+
+    132.0 * lwe_dimension.0 as f64 / dimension_for_132_bits
+}
+
+/// Returns a shipped set of [`ClassicPBSParameters`] meeting the given constraints, or `None` if
+/// no such set exists.
+///
+/// The candidates are taken from the current parameter sets, a candidate is valid if it has
+/// exactly `message_bits` bits of message, `carry_bits` bits of carry and a failure probability
+/// such that `log2_p_fail <= max_log2_p_fail`.
+///
+/// When several sets are valid, TUniform noise and the keyswitch-bootstrap order are preferred
+/// (as they are the defaults), then the set with the failure probability closest to the
+/// requested bound, as it is the cheapest to evaluate.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::{
+///     select_parameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+/// };
+///
+/// let params = select_parameters(2, 2, -128.0).unwrap();
+/// assert_eq!(params, PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128);
+///
+/// // No shipped parameters offer such a low failure probability
+/// assert!(select_parameters(2, 2, -1024.0).is_none());
+/// ```
+pub fn select_parameters(
+    message_bits: u32,
+    carry_bits: u32,
+    max_log2_p_fail: f64,
+) -> Option<ClassicPBSParameters> {
+    let message_modulus = MessageModulus(1u64.checked_shl(message_bits)?);
+    let carry_modulus = CarryModulus(1u64.checked_shl(carry_bits)?);
+
+    VEC_ALL_CLASSIC_PBS_PARAMETERS
+        .iter()
+        .map(|(params, _)| **params)
+        .filter(|params| {
+            params.message_modulus == message_modulus
+                && params.carry_modulus == carry_modulus
+                && params.log2_p_fail <= max_log2_p_fail
+        })
+        .min_by(|a, b| {
+            let preference = |params: &ClassicPBSParameters| {
+                (
+                    !matches!(
+                        params.lwe_noise_distribution,
+                        DynamicDistribution::TUniform(_)
+                    ),
+                    params.encryption_key_choice != EncryptionKeyChoice::Big,
+                )
+            };
+
+            preference(a)
+                .cmp(&preference(b))
+                .then_with(|| b.log2_p_fail.total_cmp(&a.log2_p_fail))
+                .then_with(|| a.lwe_dimension.0.cmp(&b.lwe_dimension.0))
+        })
+}
+
+/// Computes the log2 of the failure probability of a bootstrap for the given parameters,
+/// i.e. the probability that the noise overflows the padding bit when a ciphertext at the
+/// `max_noise_level` is bootstrapped.
+///
+/// The noise of the keyswitch, of the modulus switch and of the bootstrap are derived from the
+/// dimensions, noise distributions, decomposition parameters and moduli, so this can be used to
+/// check that the `log2_p_fail` of custom parameters is consistent with their other settings.
+///
+/// # Notes
+///
+/// - The keyswitch and bootstrap noise come from the [noise
+///   formulas](crate::core_crypto::commons::noise_formulas), with the noise of the keys taken from
+///   the `lwe_noise_distribution` and `glwe_noise_distribution` instead of the minimal secure one
+/// - The bootstrap noise formula assumes a 64 bits FFT is used for the external products
+/// - The modulus switch noise reduction is not taken into account, parameters using it have a
+///   smaller failure probability than the one returned
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::compute_log2_p_fail;
+/// use tfhe::shortint::parameters::current_params::V1_0_PARAM_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M64;
+///
+/// let params = V1_0_PARAM_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M64;
+/// let log2_p_fail = compute_log2_p_fail(&params);
+/// assert!((log2_p_fail - params.log2_p_fail).abs() < 0.5);
+/// ```
+pub fn compute_log2_p_fail(params: &ClassicPBSParameters) -> f64 {
+    let modulus = params.ciphertext_modulus.raw_modulus_float();
+    let big_lwe_dimension = params
+        .glwe_dimension
+        .to_equivalent_lwe_dimension(params.polynomial_size);
+
+    // The bootstrapping key is encrypted under the glwe key, the keyswitching key under the lwe key
+    let pbs_variance = pbs_variance(
+        params.lwe_dimension.0 as f64,
+        params.glwe_dimension.0 as f64,
+        params.polynomial_size.0 as f64,
+        2f64.powi(params.pbs_base_log.0 as i32),
+        params.pbs_level.0 as f64,
+        normalized_variance(params.glwe_noise_distribution, modulus),
+        modulus,
+    );
+    let keyswitch_variance = keyswitch_additive_variance(
+        big_lwe_dimension.0 as f64,
+        2f64.powi(params.ks_base_log.0 as i32),
+        params.ks_level.0 as f64,
+        normalized_variance(params.lwe_noise_distribution, modulus),
+        modulus,
+    );
+    let modulus_switch_variance = modulus_switch_additive_variance(
+        params.lwe_dimension.0 as f64,
+        params.polynomial_size.0 as f64,
+        modulus,
+    );
+
+    let max_noise_level = params.max_noise_level.get() as f64;
+    let norm2_squared = max_noise_level * max_noise_level;
+    let variance = match params.encryption_key_choice {
+        // Linear operations are done on the output of the PBS, then the KS is done
+        EncryptionKeyChoice::Big => {
+            norm2_squared * pbs_variance + keyswitch_variance + modulus_switch_variance
+        }
+        // Linear operations are done on the output of the KS that follows the PBS
+        EncryptionKeyChoice::Small => {
+            norm2_squared * (pbs_variance + keyswitch_variance) + modulus_switch_variance
+        }
+    };
+
+    // The bootstrap fails if the noise reaches the padding bit,
+    // i.e. if its absolute value is greater than half of delta
+    let full_message_modulus = (params.message_modulus.0 * params.carry_modulus.0) as f64;
+    let half_delta = 1.0 / (4.0 * full_message_modulus);
+
+    log2_erfc(half_delta / (2.0 * variance).sqrt())
+}
+
+/// Variance of the noise distribution, normalized by the modulus as in the noise formulas
+fn normalized_variance(noise_distribution: DynamicDistribution<u64>, modulus: f64) -> f64 {
+    match noise_distribution {
+        DynamicDistribution::Gaussian(_) => noise_distribution.gaussian_variance().0,
+        DynamicDistribution::TUniform(tuniform) => {
+            let bound_log2 = tuniform.bound_log2() as i32;
+            (2f64.powi(2 * bound_log2 + 1) + 1.0) / 6.0 / modulus.powi(2)
+        }
+    }
+}
+
+/// Variance added by a keyswitch, the same formula as
+/// [keyswitch_additive_variance_132_bits_security_gaussian_impl](crate::core_crypto::commons::noise_formulas::lwe_keyswitch::keyswitch_additive_variance_132_bits_security_gaussian_impl)
+/// with the variance of the key noise as a parameter
+fn keyswitch_additive_variance(
+    input_lwe_dimension: f64,
+    decomposition_base: f64,
+    decomposition_level_count: f64,
+    key_variance: f64,
+    modulus: f64,
+) -> f64 {
+    (1_f64 / 3.0)
+        * decomposition_level_count
+        * input_lwe_dimension
+        * key_variance
+        * ((1_f64 / 4.0) * decomposition_base.powf(2.0) + 0.5)
+        + input_lwe_dimension
+            * (0.0208333333333333 * modulus.powf(-2.0)
+                + 0.0416666666666667 * decomposition_base.powf(-2.0 * decomposition_level_count))
+}
+
+/// Variance of the output of a bootstrap, the same formula as
+/// [pbs_variance_132_bits_security_gaussian_impl](crate::core_crypto::commons::noise_formulas::lwe_programmable_bootstrap::pbs_variance_132_bits_security_gaussian_impl)
+/// with the variance of the key noise as a parameter
+fn pbs_variance(
+    input_lwe_dimension: f64,
+    output_glwe_dimension: f64,
+    output_polynomial_size: f64,
+    decomposition_base: f64,
+    decomposition_level_count: f64,
+    key_variance: f64,
+    modulus: f64,
+) -> f64 {
+    input_lwe_dimension
+        * (2.06537277069845e-33
+            * decomposition_base.powf(2.0)
+            * decomposition_level_count
+            * output_polynomial_size.powf(2.0)
+            * (output_glwe_dimension + 1.0)
+            + (1_f64 / 3.0)
+                * decomposition_level_count
+                * output_polynomial_size
+                * key_variance
+                * ((1_f64 / 4.0) * decomposition_base.powf(2.0) + 0.5)
+                * (output_glwe_dimension + 1.0)
+            + (1_f64 / 12.0) * modulus.powf(-2.0)
+            + (1_f64 / 2.0)
+                * output_glwe_dimension
+                * output_polynomial_size
+                * (0.0208333333333333 * modulus.powf(-2.0)
+                    + 0.0416666666666667
+                        * decomposition_base.powf(-2.0 * decomposition_level_count))
+            + (1_f64 / 24.0) * decomposition_base.powf(-2.0 * decomposition_level_count))
+}
+
+/// Variance added when switching the modulus to 2 * polynomial_size before the blind rotation,
+/// for a binary secret key
+fn modulus_switch_additive_variance(lwe_dimension: f64, polynomial_size: f64, modulus: f64) -> f64 {
+    let switched_modulus = 2.0 * polynomial_size;
+    (1.0 / 12.0 + lwe_dimension / 24.0) * switched_modulus.powf(-2.0)
+        + (-1.0 / 12.0 + lwe_dimension / 48.0) * modulus.powf(-2.0)
+}
+
+/// Computes log2(erfc(x)) without underflowing for large x, using the continued fraction
+/// erfc(x) = exp(-x^2) / sqrt(pi) * 1 / (x + (1/2) / (x + 1 / (x + (3/2) / (x + ...))))
+fn log2_erfc(x: f64) -> f64 {
+    if x <= 0.0 {
+        // erfc(x) >= 1 and the failure probability is capped to 1
+        return 0.0;
+    }
+
+    let mut continued_fraction = x;
+    for k in (1..=400).rev() {
+        continued_fraction = x + (k as f64 / 2.0) / continued_fraction;
+    }
+
+    (-x * x - (std::f64::consts::PI.sqrt() * continued_fraction).ln()) / std::f64::consts::LN_2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::parameters::StandardDev;
+
+    #[test]
+    fn test_shipped_parameters_are_valid() {
+        for (params, name) in VEC_ALL_CLASSIC_PBS_PARAMETERS {
+            if let Err(err) = params.validate() {
+                panic!("{name} should be valid: {err}");
+            }
+            let security_level = params.security_level_bits();
+            assert!(
+                security_level >= 128,
+                "{name} should have at least 128 bits of security, got {security_level}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_insecure_parameters_are_rejected() {
+        for (params, name) in VEC_ALL_CLASSIC_PBS_PARAMETERS {
+            // Same kind of small dimensions as the parameters used to speed up tests
+            let mut insecure = *params;
+            insecure.lwe_dimension = LweDimension(1);
+            insecure.polynomial_size = PolynomialSize(256);
+            assert!(
+                matches!(
+                    insecure.validate(),
+                    Err(ParameterError::InsecureNoise { name: "lwe", .. })
+                ),
+                "{name}"
+            );
+            assert!(insecure.security_level_bits() < 128, "{name}");
+
+            // Below the noise floor, whatever the dimension
+            let mut insecure = *params;
+            insecure.lwe_noise_distribution =
+                DynamicDistribution::new_gaussian_from_std_dev(StandardDev(1e-20));
+            assert!(
+                matches!(
+                    insecure.validate(),
+                    Err(ParameterError::InsecureNoise { name: "lwe", .. })
+                ),
+                "{name}"
+            );
+            assert!(insecure.security_level_bits() < 128, "{name}");
+
+            let mut insecure = *params;
+            insecure.glwe_noise_distribution = DynamicDistribution::new_t_uniform(1);
+            assert!(
+                matches!(
+                    insecure.validate(),
+                    Err(ParameterError::InsecureNoise { name: "glwe", .. })
+                ),
+                "{name}"
+            );
+            assert!(insecure.security_level_bits() < 128, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_inconsistent_parameters_are_rejected() {
+        let (params, _) = VEC_ALL_CLASSIC_PBS_PARAMETERS[0];
+
+        let mut invalid = *params;
+        invalid.carry_modulus = CarryModulus(3);
+        assert!(matches!(
+            invalid.validate(),
+            Err(ParameterError::InvalidMessageSpace { .. })
+        ));
+
+        let mut invalid = *params;
+        invalid.polynomial_size = PolynomialSize(1000);
+        assert!(matches!(
+            invalid.validate(),
+            Err(ParameterError::InvalidPolynomialSize { .. })
+        ));
+
+        let mut invalid = *params;
+        invalid.pbs_level = DecompositionLevelCount(4);
+        invalid.pbs_base_log = DecompositionBaseLog(20);
+        assert!(matches!(
+            invalid.validate(),
+            Err(ParameterError::InvalidDecomposition {
+                name: "bootstrapping",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_compute_log2_p_fail() {
+        use crate::shortint::parameters::current_params::{
+            V1_0_PARAM_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M64,
+            V1_0_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M64,
+            V1_0_PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+            V1_0_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64,
+            V1_0_PARAM_MESSAGE_3_CARRY_3_KS_PBS_TUNIFORM_2M64,
+        };
+
+        for params in [
+            V1_0_PARAM_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M64,
+            V1_0_PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+            V1_0_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64,
+            V1_0_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M64,
+            V1_0_PARAM_MESSAGE_3_CARRY_3_KS_PBS_TUNIFORM_2M64,
+        ] {
+            let log2_p_fail = compute_log2_p_fail(&params);
+            assert!(
+                (log2_p_fail - params.log2_p_fail).abs() < 0.5,
+                "Computed log2_p_fail {log2_p_fail} is too far from the expected {}",
+                params.log2_p_fail
+            );
+        }
+
+        // The noise of the keys is taken into account
+        let params = V1_0_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M64;
+        let noisier_lwe = ClassicPBSParameters {
+            lwe_noise_distribution: DynamicDistribution::new_t_uniform(49),
+            ..params
+        };
+        assert!(compute_log2_p_fail(&noisier_lwe) > compute_log2_p_fail(&params));
+        let noisier_glwe = ClassicPBSParameters {
+            glwe_noise_distribution: DynamicDistribution::new_t_uniform(25),
+            ..params
+        };
+        assert!(compute_log2_p_fail(&noisier_glwe) > compute_log2_p_fail(&params));
+
+        // More noise means a greater failure probability
+        let mut params = V1_0_PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+        params.ks_level.0 -= 1;
+        assert!(compute_log2_p_fail(&params) > params.log2_p_fail);
+
+        assert!((log2_erfc(1.0) - 0.157_299_207_050_285_13f64.log2()).abs() < 1e-9);
+        assert_eq!(log2_erfc(-1.0), 0.0);
+    }
+
+    #[test]
+    fn test_builder_success() {
+        for (params, name) in VEC_ALL_CLASSIC_PBS_PARAMETERS {
+            let built = ClassicPBSParametersBuilder::from(*params).build().unwrap();
+            assert_eq!(built, *params, "{name}");
+        }
+
+        let (params, _) = VEC_ALL_CLASSIC_PBS_PARAMETERS[0];
+        let built = ClassicPBSParametersBuilder::new()
+            .lwe_dimension(params.lwe_dimension)
+            .glwe_dimension(params.glwe_dimension)
+            .polynomial_size(params.polynomial_size)
+            .lwe_noise_distribution(params.lwe_noise_distribution)
+            .glwe_noise_distribution(params.glwe_noise_distribution)
+            .pbs_base_log(params.pbs_base_log)
+            .pbs_level(params.pbs_level)
+            .ks_base_log(params.ks_base_log)
+            .ks_level(params.ks_level)
+            .message_modulus(MessageModulus(4))
+            .carry_modulus(CarryModulus(4))
+            .log2_p_fail(-64.0)
+            .build()
+            .unwrap();
+        assert_eq!(built.max_noise_level, MaxNoiseLevel::new(5));
+        assert_eq!(built.ciphertext_modulus, CiphertextModulus::new_native());
+        assert_eq!(built.encryption_key_choice, EncryptionKeyChoice::Big);
+        assert_eq!(built.modulus_switch_noise_reduction_params, None);
+    }
+
+    #[test]
+    fn test_builder_missing_fields() {
+        let (params, _) = VEC_ALL_CLASSIC_PBS_PARAMETERS[0];
+        let complete = ClassicPBSParametersBuilder::from(*params);
+
+        let cases = [
+            (
+                "lwe_dimension",
+                ClassicPBSParametersBuilder {
+                    lwe_dimension: None,
+                    ..complete
+                },
+            ),
+            (
+                "glwe_dimension",
+                ClassicPBSParametersBuilder {
+                    glwe_dimension: None,
+                    ..complete
+                },
+            ),
+            (
+                "polynomial_size",
+                ClassicPBSParametersBuilder {
+                    polynomial_size: None,
+                    ..complete
+                },
+            ),
+            (
+                "lwe_noise_distribution",
+                ClassicPBSParametersBuilder {
+                    lwe_noise_distribution: None,
+                    ..complete
+                },
+            ),
+            (
+                "glwe_noise_distribution",
+                ClassicPBSParametersBuilder {
+                    glwe_noise_distribution: None,
+                    ..complete
+                },
+            ),
+            (
+                "pbs_base_log",
+                ClassicPBSParametersBuilder {
+                    pbs_base_log: None,
+                    ..complete
+                },
+            ),
+            (
+                "pbs_level",
+                ClassicPBSParametersBuilder {
+                    pbs_level: None,
+                    ..complete
+                },
+            ),
+            (
+                "ks_base_log",
+                ClassicPBSParametersBuilder {
+                    ks_base_log: None,
+                    ..complete
+                },
+            ),
+            (
+                "ks_level",
+                ClassicPBSParametersBuilder {
+                    ks_level: None,
+                    ..complete
+                },
+            ),
+            (
+                "message_modulus",
+                ClassicPBSParametersBuilder {
+                    message_modulus: None,
+                    ..complete
+                },
+            ),
+            (
+                "carry_modulus",
+                ClassicPBSParametersBuilder {
+                    carry_modulus: None,
+                    ..complete
+                },
+            ),
+        ];
+
+        for (field, builder) in cases {
+            assert_eq!(
+                builder.build(),
+                Err(ParameterError::MissingField { name: field })
+            );
+        }
+
+        let builder = ClassicPBSParametersBuilder {
+            log2_p_fail: None,
+            ..complete
+        };
+        assert_eq!(builder.build(), Err(ParameterError::MissingLog2PFail));
+    }
+
+    #[test]
+    fn test_builder_inconsistent_fields() {
+        let (params, _) = VEC_ALL_CLASSIC_PBS_PARAMETERS[0];
+        let complete = ClassicPBSParametersBuilder::from(*params);
+
+        // Invalid message and carry moduli
+        for (builder, name, modulus) in [
+            (complete.message_modulus(MessageModulus(1)), "message", 1),
+            (complete.message_modulus(MessageModulus(3)), "message", 3),
+            (complete.carry_modulus(CarryModulus(0)), "carry", 0),
+            (complete.carry_modulus(CarryModulus(6)), "carry", 6),
+        ] {
+            assert_eq!(
+                builder.build(),
+                Err(ParameterError::InvalidModulus { name, modulus })
+            );
+        }
+
+        // Plaintext space does not fit in the ciphertext modulus
+        assert!(matches!(
+            complete
+                .message_modulus(MessageModulus(1 << 31))
+                .carry_modulus(CarryModulus(1 << 32))
+                .build(),
+            Err(ParameterError::ModulusOverflow { .. })
+        ));
+
+        // Max noise level inconsistent with the moduli
+        assert!(matches!(
+            complete
+                .max_noise_level(Some(MaxNoiseLevel::new(0)))
+                .build(),
+            Err(ParameterError::InconsistentMaxNoiseLevel { .. })
+        ));
+        assert_eq!(
+            complete
+                .message_modulus(MessageModulus(4))
+                .carry_modulus(CarryModulus(4))
+                .max_noise_level(Some(MaxNoiseLevel::new(6)))
+                .build(),
+            Err(ParameterError::InconsistentMaxNoiseLevel {
+                max_noise_level: MaxNoiseLevel::new(6),
+                max_consistent_noise_level: MaxNoiseLevel::new(5),
+            })
+        );
+
+        // Invalid log2_p_fail
+        assert_eq!(
+            complete.log2_p_fail(1.0).build(),
+            Err(ParameterError::InvalidLog2PFail { log2_p_fail: 1.0 })
+        );
+        assert!(matches!(
+            complete.log2_p_fail(f64::NAN).build(),
+            Err(ParameterError::InvalidLog2PFail { .. })
+        ));
+    }
+}
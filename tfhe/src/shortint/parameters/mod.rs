@@ -18,12 +18,15 @@ use crate::core_crypto::prelude::{
     GlweCiphertextConformanceParams, LweCiphertextConformanceParams,
     LweCiphertextListConformanceParams, LweKeyswitchKeyConformanceParams,
 };
+use crate::named::Named;
+use crate::safe_serialization::{safe_deserialize, safe_serialize};
 use crate::shortint::backward_compatibility::parameters::*;
 #[cfg(feature = "zk-pok")]
 use crate::zk::CompactPkeZkScheme;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
-use tfhe_versionable::Versionize;
+use tfhe_versionable::{Unversionize, Versionize};
 
 pub mod aliases;
 pub mod classic;
@@ -50,7 +53,10 @@ use super::server_key::PBSConformanceParams;
 pub use super::PBSOrder;
 use crate::shortint::ciphertext::MaxDegree;
 pub use crate::shortint::parameters::list_compression::CompressionParameters;
-pub use classic::ClassicPBSParameters;
+pub use classic::{
+    compute_log2_p_fail, select_parameters, ClassicPBSParameters, ClassicPBSParametersBuilder,
+    ParameterError,
+};
 pub use compact_public_key_only::{
     CastingFunctionsOwned, CastingFunctionsView, CompactCiphertextListExpansionKind,
     CompactPublicKeyEncryptionParameters, ShortintCompactCiphertextListCastingMode,
@@ -305,6 +311,53 @@ impl PBSParameters {
     }
 }
 
+impl Named for PBSParameters {
+    const NAME: &'static str = "shortint::PBSParameters";
+}
+
+/// Upper bound on the serialized size of parameter sets, used by [load_parameters]
+pub const PARAMETERS_SERIALIZED_SIZE_LIMIT: u64 = 1 << 16;
+
+/// Saves a parameter set (e.g. [ClassicPBSParameters], [MultiBitPBSParameters] or
+/// [PBSParameters]) to `writer`
+///
+/// The parameters are serialized with [safe_serialize], so they are versioned and can be
+/// loaded by later versions of this crate using [load_parameters].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::{
+///     load_parameters, save_parameters, ClassicPBSParameters,
+///     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+/// };
+///
+/// let mut buffer = vec![];
+/// save_parameters(&PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, &mut buffer).unwrap();
+///
+/// let params: ClassicPBSParameters = load_parameters(buffer.as_slice()).unwrap();
+/// assert_eq!(params, PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+/// ```
+pub fn save_parameters<T>(params: &T, writer: impl std::io::Write) -> Result<(), crate::Error>
+where
+    T: Serialize + Versionize + Named,
+{
+    safe_serialize(params, writer, PARAMETERS_SERIALIZED_SIZE_LIMIT)
+        .map_err(|err| crate::Error::new(format!("Failed to save {}: {err}", T::NAME)))
+}
+
+/// Loads a parameter set saved with [save_parameters] from `reader`
+///
+/// An error is returned if the data does not hold the requested kind of parameters,
+/// or if it was saved with an incompatible serialization format.
+pub fn load_parameters<T>(reader: impl std::io::Read) -> Result<T, crate::Error>
+where
+    T: DeserializeOwned + Unversionize + Named,
+{
+    safe_deserialize(reader, PARAMETERS_SERIALIZED_SIZE_LIMIT)
+        .map_err(|err| crate::Error::new(format!("Failed to load {}: {err}", T::NAME)))
+}
+
 #[derive(Serialize, Copy, Clone, Deserialize, Debug, PartialEq, Versionize)]
 #[versionize(ShortintParameterSetInnerVersions)]
 #[allow(clippy::large_enum_variant)]
@@ -589,3 +642,54 @@ pub struct ModulusSwitchNoiseReductionParams {
     pub ms_r_sigma_factor: RSigmaFactor,
     pub ms_input_variance: Variance,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parameters_save_load_round_trip() {
+        let mut buffer = vec![];
+        save_parameters(&PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, &mut buffer).unwrap();
+        let classic: ClassicPBSParameters = load_parameters(buffer.as_slice()).unwrap();
+        assert_eq!(classic, PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+
+        for params in [
+            PBSParameters::PBS(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64),
+            PBSParameters::MultiBitPBS(
+                PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+            ),
+        ] {
+            let mut buffer = vec![];
+            save_parameters(&params, &mut buffer).unwrap();
+            let loaded: PBSParameters = load_parameters(buffer.as_slice()).unwrap();
+            assert_eq!(loaded, params);
+        }
+    }
+
+    #[test]
+    fn test_parameters_load_incompatible() {
+        let mut buffer = vec![];
+        save_parameters(&PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, &mut buffer).unwrap();
+
+        // Loading another kind of parameters fails
+        let err = load_parameters::<MultiBitPBSParameters>(buffer.as_slice()).unwrap_err();
+        assert!(err.to_string().contains("expected type"), "{err}");
+
+        // Simulate parameters saved with an unknown versioning scheme
+        let mut versioning_version = 3u64.to_le_bytes().to_vec();
+        versioning_version.extend_from_slice(b"0.1");
+        let position = buffer
+            .windows(versioning_version.len())
+            .position(|window| window == versioning_version)
+            .unwrap();
+        let start = position + 8;
+        buffer[start..start + 3].copy_from_slice(b"9.9");
+
+        let err = load_parameters::<ClassicPBSParameters>(buffer.as_slice()).unwrap_err();
+        assert!(
+            err.to_string().contains("versioning scheme version"),
+            "{err}"
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::core_crypto::entities::{
     LweCiphertextConformanceParams, MsDecompressionType, MultiBitBootstrapKeyConformanceParams,
 };
+use crate::named::Named;
 use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::parameters::{
     CarryModulus, CiphertextConformanceParams, CiphertextModulus, DecompositionBaseLog,
@@ -38,6 +39,10 @@ pub struct MultiBitPBSParameters {
     pub deterministic_execution: bool,
 }
 
+impl Named for MultiBitPBSParameters {
+    const NAME: &'static str = "shortint::MultiBitPBSParameters";
+}
+
 impl MultiBitPBSParameters {
     pub const fn with_deterministic_execution(self) -> Self {
         Self {
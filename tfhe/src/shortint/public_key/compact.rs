@@ -369,6 +369,21 @@ impl CompactPublicKey {
     ) -> crate::Result<ProvenCompactCiphertextList> {
         let plaintext_modulus = self.parameters.message_modulus.0 * self.parameters.carry_modulus.0;
         assert!(encryption_modulus <= plaintext_modulus);
+        // The degree of the list is declared from the encryption modulus, verifiers only accept
+        // it if the proof guarantees that the messages are in this range
+        let proven_message_modulus = crs.proven_message_modulus();
+        if proven_message_modulus > encryption_modulus {
+            return Err(crate::Error::new(format!(
+                "The CRS proves messages in [0, {proven_message_modulus}), which does not \
+                guarantee the encryption range [0, {encryption_modulus}), \
+                use a CRS built for this encryption modulus"
+            )));
+        }
+        if let Some(message) = messages.iter().find(|&&m| m >= encryption_modulus) {
+            return Err(crate::Error::new(format!(
+                "Message {message} is out of the encryption range [0, {encryption_modulus})"
+            )));
+        }
         let delta = self.encoding().delta();
 
         // This is the maximum number of lwe that can share the same mask in lwe compact pk
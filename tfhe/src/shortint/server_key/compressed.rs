@@ -6,6 +6,7 @@ use super::{
     PbsTypeConformanceParams,
 };
 use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::commons::math::random::CompressionSeed;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::LweBootstrapKeyConformanceParams;
 use crate::core_crypto::prelude::*;
 use crate::shortint::backward_compatibility::server_key::{
@@ -17,6 +18,7 @@ use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulu
 use crate::shortint::server_key::ShortintBootstrappingKey;
 use crate::shortint::{ClientKey, PBSParameters, ServerKey};
 use serde::{Deserialize, Serialize};
+use tfhe_csprng::seeders::Seed;
 use tfhe_versionable::Versionize;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
@@ -154,8 +156,94 @@ impl CompressedServerKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.new_compressed_server_key(client_key))
     }
 
+    /// Generate a compressed server key without keyswitching and bootstrapping material.
+    ///
+    /// The keys it contains do not encrypt anything, they only have the dimensions needed to
+    /// handle the ciphertexts of the client key, so the resulting server key is very small.
+    /// Such a key can only be used for operations that do not need a PBS, e.g. additions
+    /// while the carry and noise budgets of the blocks allow it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters of the client key do not encrypt under the big key (KS_PBS).
+    pub(crate) fn new_without_bootstrapping_material(client_key: &ClientKey) -> Self {
+        let params = &client_key.parameters;
+
+        assert_eq!(
+            params.encryption_key_choice(),
+            EncryptionKeyChoice::Big,
+            "Server keys without bootstrapping material require parameters \
+            that encrypt under the big key (KS_PBS)"
+        );
+
+        let compression_seed = CompressionSeed { seed: Seed(0) };
+
+        // The keyswitch targets an empty LWE key, so the bootstrapping key has no GGSW
+        let key_switching_key = SeededLweKeyswitchKeyOwned::new(
+            0u64,
+            params.ks_base_log(),
+            DecompositionLevelCount(1),
+            params
+                .glwe_dimension()
+                .to_equivalent_lwe_dimension(params.polynomial_size()),
+            LweDimension(0),
+            compression_seed,
+            params.ciphertext_modulus(),
+        );
+
+        let bootstrapping_key = match params.pbs_parameters().unwrap() {
+            PBSParameters::PBS(pbs_params) => ShortintCompressedBootstrappingKey::Classic {
+                bsk: SeededLweBootstrapKeyOwned::new(
+                    0u64,
+                    pbs_params.glwe_dimension.to_glwe_size(),
+                    pbs_params.polynomial_size,
+                    pbs_params.pbs_base_log,
+                    pbs_params.pbs_level,
+                    LweDimension(0),
+                    compression_seed,
+                    pbs_params.ciphertext_modulus,
+                ),
+                modulus_switch_noise_reduction_key: None,
+            },
+            PBSParameters::MultiBitPBS(pbs_params) => {
+                ShortintCompressedBootstrappingKey::MultiBit {
+                    seeded_bsk: SeededLweMultiBitBootstrapKeyOwned::new(
+                        0u64,
+                        pbs_params.glwe_dimension.to_glwe_size(),
+                        pbs_params.polynomial_size,
+                        pbs_params.pbs_base_log,
+                        pbs_params.pbs_level,
+                        LweDimension(0),
+                        pbs_params.grouping_factor,
+                        compression_seed,
+                        pbs_params.ciphertext_modulus,
+                    ),
+                    deterministic_execution: pbs_params.deterministic_execution,
+                }
+            }
+        };
+
+        Self {
+            key_switching_key,
+            bootstrapping_key,
+            message_modulus: params.message_modulus(),
+            carry_modulus: params.carry_modulus(),
+            max_degree: MaxDegree::from_msg_carry_modulus(
+                params.message_modulus(),
+                params.carry_modulus(),
+            ),
+            max_noise_level: params.max_noise_level(),
+            ciphertext_modulus: params.ciphertext_modulus(),
+            pbs_order: PBSOrder::KeyswitchBootstrap,
+        }
+    }
+
     /// Decompress a [`CompressedServerKey`] into a [`ServerKey`].
     pub fn decompress(&self) -> ServerKey {
+        if self.bootstrapping_key.input_lwe_dimension().0 == 0 {
+            return self.decompress_without_bootstrapping_material();
+        }
+
         let Self {
             key_switching_key: compressed_key_switching_key,
             bootstrapping_key: compressed_bootstrapping_key,
@@ -274,6 +362,60 @@ impl CompressedServerKey {
         }
     }
 
+    /// Keys generated by [`Self::new_without_bootstrapping_material`] do not encrypt anything,
+    /// so instead of being decompressed, empty keys with the same dimensions are allocated.
+    fn decompress_without_bootstrapping_material(&self) -> ServerKey {
+        let key_switching_key = LweKeyswitchKeyOwned::new(
+            0u64,
+            self.key_switching_key.decomposition_base_log(),
+            self.key_switching_key.decomposition_level_count(),
+            self.key_switching_key.input_key_lwe_dimension(),
+            self.key_switching_key.output_key_lwe_dimension(),
+            self.ciphertext_modulus,
+        );
+
+        let bootstrapping_key = match &self.bootstrapping_key {
+            ShortintCompressedBootstrappingKey::Classic { bsk, .. } => {
+                ShortintBootstrappingKey::Classic {
+                    bsk: FourierLweBootstrapKeyOwned::new(
+                        bsk.input_lwe_dimension(),
+                        bsk.glwe_size(),
+                        bsk.polynomial_size(),
+                        bsk.decomposition_base_log(),
+                        bsk.decomposition_level_count(),
+                    ),
+                    modulus_switch_noise_reduction_key: None,
+                }
+            }
+            ShortintCompressedBootstrappingKey::MultiBit {
+                seeded_bsk,
+                deterministic_execution,
+            } => ShortintBootstrappingKey::MultiBit {
+                fourier_bsk: FourierLweMultiBitBootstrapKeyOwned::new(
+                    seeded_bsk.input_lwe_dimension(),
+                    seeded_bsk.glwe_size(),
+                    seeded_bsk.polynomial_size(),
+                    seeded_bsk.decomposition_base_log(),
+                    seeded_bsk.decomposition_level_count(),
+                    seeded_bsk.grouping_factor(),
+                ),
+                thread_count: ThreadCount(1),
+                deterministic_execution: *deterministic_execution,
+            },
+        };
+
+        ServerKey {
+            key_switching_key,
+            bootstrapping_key,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            max_degree: self.max_degree,
+            max_noise_level: self.max_noise_level,
+            ciphertext_modulus: self.ciphertext_modulus,
+            pbs_order: self.pbs_order,
+        }
+    }
+
     /// Deconstruct a [`CompressedServerKey`] into its constituents.
     pub fn into_raw_parts(
         self,
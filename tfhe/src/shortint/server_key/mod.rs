@@ -59,10 +59,18 @@ use tfhe_versionable::Versionize;
 
 #[cfg(feature = "pbs-stats")]
 pub mod pbs_stats {
+    use std::cell::RefCell;
+    use std::fmt::{Display, Formatter};
     use std::sync::atomic::AtomicU64;
     pub use std::sync::atomic::Ordering;
+    use std::sync::Arc;
     pub static PBS_COUNT: AtomicU64 = AtomicU64::new(0);
 
+    thread_local! {
+        /// Counter of the [with_pbs_budget] scope the current thread works for, if any
+        static SCOPE_PBS_COUNT: RefCell<Option<Arc<AtomicU64>>> = const { RefCell::new(None) };
+    }
+
     pub fn get_pbs_count() -> u64 {
         PBS_COUNT.load(Ordering::Relaxed)
     }
@@ -70,6 +78,143 @@ pub mod pbs_stats {
     pub fn reset_pbs_count() {
         PBS_COUNT.store(0, Ordering::Relaxed);
     }
+
+    pub(crate) fn increment_pbs_count() {
+        let _ = PBS_COUNT.fetch_add(1, Ordering::Relaxed);
+        SCOPE_PBS_COUNT.with(|scope_count| {
+            if let Some(scope_count) = scope_count.borrow().as_ref() {
+                let _ = scope_count.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+    }
+
+    /// Error returned by [with_pbs_budget] when the computation did more PBS than allowed
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct PbsBudgetExceeded {
+        pub budget: u64,
+        pub pbs_count: u64,
+    }
+
+    impl Display for PbsBudgetExceeded {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "The computation used {} PBS, exceeding its budget of {} PBS",
+                self.pbs_count, self.budget
+            )
+        }
+    }
+
+    impl std::error::Error for PbsBudgetExceeded {}
+
+    /// Runs `f` and returns an error if it did more than `budget` PBS
+    ///
+    /// `f` is run inside a dedicated rayon thread pool (with the same number of threads as
+    /// the current one), so that the PBS done by parallel operations are counted as well,
+    /// while PBS done concurrently by computations outside of `f` are not.
+    ///
+    /// As `f` runs in other threads, thread-local state such as the key set with
+    /// `set_server_key` must be set again inside `f` if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    /// use tfhe::with_pbs_budget;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let ct1 = cks.encrypt(10u64);
+    /// let ct2 = cks.encrypt(20u64);
+    ///
+    /// let ct_res = with_pbs_budget(64, || sks.add_parallelized(&ct1, &ct2)).unwrap();
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 30);
+    ///
+    /// // An addition cannot be done without any PBS
+    /// assert!(with_pbs_budget(0, || sks.add_parallelized(&ct1, &ct2)).is_err());
+    /// ```
+    pub fn with_pbs_budget<R, F>(budget: u64, f: F) -> Result<R, PbsBudgetExceeded>
+    where
+        F: FnOnce() -> R + Send,
+        R: Send,
+    {
+        let (result, pbs_count) = count_pbs_in_scope(f);
+
+        if pbs_count > budget {
+            Err(PbsBudgetExceeded { budget, pbs_count })
+        } else {
+            Ok(result)
+        }
+    }
+
+    /// Runs `op` and panics if the number of PBS it did is not exactly `expected`
+    ///
+    /// This is meant to write regression tests on the PBS count of operations, as the
+    /// count does not depend on the encrypted values it catches changes making an
+    /// operation more (or less) expensive.
+    ///
+    /// PBS are counted the same way as in [with_pbs_budget].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::assert_pbs_count;
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let ct1 = cks.encrypt(10u64);
+    /// let ct2 = cks.encrypt(20u64);
+    ///
+    /// // One bivariate PBS per block
+    /// let ct_res = assert_pbs_count(|| sks.unchecked_bitand_parallelized(&ct1, &ct2), 4);
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 10 & 20);
+    /// ```
+    #[track_caller]
+    pub fn assert_pbs_count<R, F>(op: F, expected: u64) -> R
+    where
+        F: FnOnce() -> R + Send,
+        R: Send,
+    {
+        let (result, pbs_count) = count_pbs_in_scope(op);
+
+        assert_eq!(
+            pbs_count, expected,
+            "Expected the operation to use {expected} PBS, it used {pbs_count} PBS"
+        );
+
+        result
+    }
+
+    /// Runs `f` in a dedicated thread pool, returning its result and the number of PBS it did
+    pub(crate) fn count_pbs_in_scope<R, F>(f: F) -> (R, u64)
+    where
+        F: FnOnce() -> R + Send,
+        R: Send,
+    {
+        let scope_count = Arc::new(AtomicU64::new(0));
+
+        let pool = {
+            let scope_count = scope_count.clone();
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(rayon::current_num_threads())
+                .start_handler(move |_| {
+                    SCOPE_PBS_COUNT.with(|count| *count.borrow_mut() = Some(scope_count.clone()));
+                })
+                .build()
+                .expect("Failed to build the thread pool of the PBS counting scope")
+        };
+
+        let result = pool.install(f);
+
+        (result, scope_count.load(Ordering::Relaxed))
+    }
 }
 #[cfg(feature = "pbs-stats")]
 pub use pbs_stats::*;
@@ -486,6 +631,13 @@ impl ServerKey {
         })
     }
 
+    /// Returns whether the key holds the material needed to compute programmable bootstrappings
+    ///
+    /// See [`CompressedServerKey::new_without_bootstrapping_material`]
+    pub(crate) fn has_bootstrapping_material(&self) -> bool {
+        self.bootstrapping_key.input_lwe_dimension().0 != 0
+    }
+
     pub fn ciphertext_lwe_dimension(&self) -> LweDimension {
         match self.pbs_order {
             PBSOrder::KeyswitchBootstrap => self.key_switching_key.input_key_lwe_dimension(),
@@ -1186,7 +1338,7 @@ impl ServerKey {
         #[cfg(feature = "pbs-stats")]
         // We want to count trivial PBS in simulator mode
         // In the non trivial case, this increment is done in the `apply_blind_rotate` function
-        let _ = PBS_COUNT.fetch_add(1, Ordering::Relaxed);
+        increment_pbs_count();
 
         assert_eq!(ct.noise_level(), NoiseLevel::ZERO);
         let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
@@ -1211,7 +1363,7 @@ impl ServerKey {
 
     fn trivial_pbs_many_lut(&self, ct: &Ciphertext, lut: &ManyLookupTableOwned) -> Vec<Ciphertext> {
         #[cfg(feature = "pbs-stats")]
-        let _ = PBS_COUNT.fetch_add(1, Ordering::Relaxed);
+        increment_pbs_count();
 
         assert_eq!(ct.noise_level(), NoiseLevel::ZERO);
         let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
@@ -1515,8 +1667,15 @@ pub(crate) fn apply_blind_rotate_no_ms_noise_reduction<InputCont, OutputCont>(
     InputCont: Container<Element = u64>,
     OutputCont: ContainerMut<Element = u64>,
 {
+    assert_ne!(
+        bootstrapping_key.input_lwe_dimension().0,
+        0,
+        "This server key has no bootstrapping material, \
+        it can only be used for operations that do not require a PBS"
+    );
+
     #[cfg(feature = "pbs-stats")]
-    let _ = PBS_COUNT.fetch_add(1, Ordering::Relaxed);
+    increment_pbs_count();
 
     match bootstrapping_key {
         ShortintBootstrappingKey::Classic {
@@ -1,4 +1,4 @@
-use crate::integer::ServerKey as IntegerServerKey;
+use crate::integer::{BooleanBlock, ServerKey as IntegerServerKey};
 use crate::strings::ciphertext::{FheString, UIntArg};
 use crate::strings::server_key::{FheStringIsEmpty, FheStringLen, ServerKey};
 use rayon::prelude::*;
@@ -168,18 +168,8 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
             })
             .collect();
 
-        // Subtraction by 32 makes the character uppercase
-        uppercase
-            .chars_mut()
-            .par_iter_mut()
-            .zip(lowercase_chars.into_par_iter())
-            .for_each(|(char, is_lowercase)| {
-                let mut subtract = sk.create_trivial_radix(32, self.num_ascii_blocks());
-
-                sk.mul_assign_parallelized(&mut subtract, &is_lowercase.into_radix(1, sk));
-
-                sk.sub_assign_parallelized(char.ciphertext_mut(), &subtract);
-            });
+        // Clearing the bit of value 32 makes the character uppercase
+        self.update_case_bit(&mut uppercase, lowercase_chars, false);
 
         uppercase
     }
@@ -225,20 +215,48 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
             })
             .collect();
 
-        // Addition by 32 makes the character lowercase
+        // Setting the bit of value 32 makes the character lowercase
+        self.update_case_bit(&mut lowercase, uppercase_chars, true);
+
         lowercase
-            .chars_mut()
-            .par_iter_mut()
-            .zip(uppercase_chars)
-            .for_each(|(char, is_uppercase)| {
-                let mut add = sk.create_trivial_radix(32, self.num_ascii_blocks());
+    }
 
-                sk.mul_assign_parallelized(&mut add, &is_uppercase.into_radix(1, sk));
+    /// Sets (or clears, if `set` is false) the bit of value 32 of the chars whose flag is true
+    ///
+    /// The upper and lower case of an ASCII letter only differ by this bit, each char is updated
+    /// with a single bivariate PBS on the block holding it, the other blocks are left untouched.
+    fn update_case_bit(&self, str: &mut FheString, flags: Vec<BooleanBlock>, set: bool) {
+        const CASE_BIT: u32 = 5;
 
-                sk.add_assign_parallelized(char.ciphertext_mut(), &add);
-            });
+        let sk = self.inner();
+        let bits_in_block = sk.message_modulus().0.ilog2();
+        let block_index = (CASE_BIT / bits_in_block) as usize;
+        let mask = 1u64 << (CASE_BIT % bits_in_block);
+
+        let lut = sk.key.generate_lookup_table_bivariate(|block, flag| {
+            if flag == 0 {
+                block
+            } else if set {
+                block | mask
+            } else {
+                block & !mask
+            }
+        });
 
-        lowercase
+        str.chars_mut()
+            .par_iter_mut()
+            .zip(flags)
+            .for_each(|(char, flag)| {
+                let ct = char.ciphertext_mut();
+                if !ct.block_carries_are_empty() {
+                    sk.full_propagate_parallelized(ct);
+                }
+                sk.key.unchecked_apply_lookup_table_bivariate_assign(
+                    &mut ct.blocks[block_index],
+                    flag.as_ref(),
+                    &lut,
+                );
+            });
     }
 
     /// Concatenates two encrypted strings and returns the result as a new encrypted string.
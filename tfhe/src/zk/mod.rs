@@ -446,6 +446,21 @@ impl CompactPkeCrs {
         }
     }
 
+    /// Exclusive upper bound of the messages proven by this CRS
+    ///
+    /// This is the plaintext modulus without the padding bits that proofs show to be zero,
+    /// e.g. the message and carry space for a CRS built from shortint parameters.
+    pub fn proven_message_modulus(&self) -> u64 {
+        match self {
+            Self::PkeV1(public_params) => {
+                public_params.t >> public_params.msbs_zero_padding_bit_count
+            }
+            Self::PkeV2(public_params) => {
+                public_params.t >> public_params.msbs_zero_padding_bit_count
+            }
+        }
+    }
+
     /// Upper bound on the noise accepted by this CRS
     pub fn exclusive_max_noise(&self) -> u64 {
         match self {